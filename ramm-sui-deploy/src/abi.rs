@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+
+use sui_json_rpc_types::{
+    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedType, SuiMoveVisibility,
+};
+use sui_sdk::SuiClient;
+use sui_types::base_types::ObjectID;
+
+use crate::{
+    error::RAMMDeploymentError,
+    types::{NetworkConfig, RAMMDeploymentConfig},
+    with_timeout, RAMM_MODULE_NAME,
+};
+
+/// A function of the RAMM module that the deployment calls, and the signature it calls it with.
+struct ExpectedFunction {
+    name: &'static str,
+    type_parameter_count: usize,
+    /// The function's parameters, as rendered by `render_type`.
+    parameters: &'static [&'static str],
+}
+
+/// The functions every deployment calls: to create a RAMM, add Switchboard-priced assets to it, and
+/// initialize it.
+///
+/// Struct types are named without their package's address, which, for the RAMM's own types, is
+/// that of the package's first version, rather than the one being checked.
+const EXPECTED_FUNCTIONS: [ExpectedFunction; 3] = [
+    ExpectedFunction {
+        name: "new_ramm",
+        type_parameter_count: 0,
+        parameters: &["address", "&mut tx_context::TxContext"],
+    },
+    ExpectedFunction {
+        name: "add_asset_to_ramm",
+        type_parameter_count: 1,
+        parameters: &[
+            "&mut ramm::RAMM",
+            "&aggregator::Aggregator",
+            "u64",
+            "u8",
+            "&ramm::RAMMAdminCap",
+            "&ramm::RAMMNewAssetCap",
+        ],
+    },
+    ExpectedFunction {
+        name: "initialize_ramm",
+        type_parameter_count: 0,
+        parameters: &[
+            "&mut ramm::RAMM",
+            "&ramm::RAMMAdminCap",
+            "ramm::RAMMNewAssetCap",
+        ],
+    },
+];
+
+/// Check that the published package with the given ID exposes each function of its RAMM module
+/// that the deployment calls, with the signature it is called with.
+///
+/// This is meant for packages the deployment did not publish itself, e.g. one whose ID is read
+/// from the config, which may be of an older, incompatible version of the library: such a package
+/// is reported as an `AbiMismatch` before any tx is sent, rather than once the RAMM is created and
+/// its population PTB fails.
+///
+/// Every mismatch is logged, so that all of them are reported at once, before the first is
+/// returned.
+pub async fn check_package_abi(
+    sui_client: &SuiClient,
+    package_id: ObjectID,
+    dplymt_cfg: &RAMMDeploymentConfig,
+) -> Result<(), RAMMDeploymentError> {
+    let modules = fetch_normalized_modules(sui_client, package_id, &dplymt_cfg.network).await?;
+    let Some(ramm_module) = modules.get(RAMM_MODULE_NAME.as_str()) else {
+        return Err(RAMMDeploymentError::AbiMismatch {
+            package: package_id,
+            function: RAMM_MODULE_NAME.to_string(),
+            reason: "the package has no such module".to_string(),
+        });
+    };
+
+    let mut first_error = None;
+    for expected in EXPECTED_FUNCTIONS.iter() {
+        if let Err(reason) = check_function(ramm_module, expected) {
+            let error = RAMMDeploymentError::AbiMismatch {
+                package: package_id,
+                function: format!("{RAMM_MODULE_NAME}::{}", expected.name),
+                reason,
+            };
+            log::error!("{error}");
+            first_error.get_or_insert(error);
+        }
+    }
+
+    match first_error {
+        Some(error) => Err(error),
+        None => {
+            log::info!("Package {package_id} exposes the RAMM functions the deployment calls.");
+            Ok(())
+        }
+    }
+}
+
+/// Query the normalized form of each of a published package's modules, by module name.
+async fn fetch_normalized_modules(
+    sui_client: &SuiClient,
+    package_id: ObjectID,
+    network_cfg: &NetworkConfig,
+) -> Result<BTreeMap<String, SuiMoveNormalizedModule>, RAMMDeploymentError> {
+    with_timeout(
+        network_cfg,
+        &format!("fetching the modules of package {package_id}"),
+        sui_client
+            .read_api()
+            .get_normalized_move_modules_by_package(package_id),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::AbiQueryError)
+}
+
+/// Check a single function of the RAMM module against its expected signature, returning why it
+/// does not match, if it does not.
+fn check_function(
+    ramm_module: &SuiMoveNormalizedModule,
+    expected: &ExpectedFunction,
+) -> Result<(), String> {
+    let Some(function) = ramm_module.exposed_functions.get(expected.name) else {
+        return Err("the module has no such function".to_string());
+    };
+    let SuiMoveNormalizedFunction {
+        visibility,
+        is_entry,
+        type_parameters,
+        parameters,
+        ..
+    } = function;
+
+    // A PTB can only call public functions, or entry functions.
+    if !matches!(visibility, SuiMoveVisibility::Public) && !is_entry {
+        return Err("it can not be called from a transaction".to_string());
+    }
+    if type_parameters.len() != expected.type_parameter_count {
+        return Err(format!(
+            "it has {} type parameters, instead of {}",
+            type_parameters.len(),
+            expected.type_parameter_count
+        ));
+    }
+    let parameters: Vec<String> = parameters.iter().map(render_type).collect();
+    if parameters != expected.parameters {
+        return Err(format!(
+            "its parameters are ({}), instead of ({})",
+            parameters.join(", "),
+            expected.parameters.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Render a normalized Move type as it would be written in Move source, with struct types named
+/// by their module and name only.
+fn render_type(move_type: &SuiMoveNormalizedType) -> String {
+    match move_type {
+        SuiMoveNormalizedType::Bool => "bool".to_string(),
+        SuiMoveNormalizedType::U8 => "u8".to_string(),
+        SuiMoveNormalizedType::U16 => "u16".to_string(),
+        SuiMoveNormalizedType::U32 => "u32".to_string(),
+        SuiMoveNormalizedType::U64 => "u64".to_string(),
+        SuiMoveNormalizedType::U128 => "u128".to_string(),
+        SuiMoveNormalizedType::U256 => "u256".to_string(),
+        SuiMoveNormalizedType::Address => "address".to_string(),
+        SuiMoveNormalizedType::Signer => "signer".to_string(),
+        SuiMoveNormalizedType::Struct {
+            module,
+            name,
+            type_arguments,
+            ..
+        } => match type_arguments.as_slice() {
+            [] => format!("{module}::{name}"),
+            type_arguments => format!(
+                "{module}::{name}<{}>",
+                type_arguments
+                    .iter()
+                    .map(render_type)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        },
+        SuiMoveNormalizedType::Vector(element) => format!("vector<{}>", render_type(element)),
+        SuiMoveNormalizedType::TypeParameter(ix) => format!("T{ix}"),
+        SuiMoveNormalizedType::Reference(referent) => format!("&{}", render_type(referent)),
+        SuiMoveNormalizedType::MutableReference(referent) => {
+            format!("&mut {}", render_type(referent))
+        }
+    }
+}
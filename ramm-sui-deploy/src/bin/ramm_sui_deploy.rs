@@ -1,141 +1,147 @@
 use std::{env, path::PathBuf};
 
-use sui_types::base_types::{ObjectID, SuiAddress};
+use ramm_sui_deploy::{self, deployment, types::DeploymentStage, util, UserAssent};
 
-use ramm_sui_deploy::{self, error::RAMMDeploymentError, types::{RAMMPkgAddrSrc, RAMMDeploymentConfig}, util, UserAssent, RAMMObjectIDs};
-
-async fn ramm_deployment(dplymt_cfg: RAMMDeploymentConfig) -> Result<RAMMObjectIDs, RAMMDeploymentError> {
+#[tokio::main]
+async fn main() {
     /*
-    Sui client creation, with the help of `suibase` for network selection
+    CLI argument parsing, done first so that logging honors the requested verbosity
     */
-    let (suibase, sui_client) =
-        ramm_sui_deploy::get_suibase_and_sui_client(&dplymt_cfg.target_env).await?;
-
-    // Fetch the sui client's active address, to use it for publishing
-    let client_address: SuiAddress = suibase
-        .client_sui_address("active")
-        .map_err(RAMMDeploymentError::SuiClientActiveAddressError)?;
-    log::info!(
-        "Using address {} for publishing and deployment.",
-        client_address
-    );
-
-    let keystore = ramm_sui_deploy::get_keystore(&suibase)?;
+    let args = &mut env::args_os();
+    let exec_name: PathBuf = PathBuf::from(args.next().unwrap());
 
-    /*
-    Obtaining the RAMM package ID, either from the TOML config or from publishing the package.
-    */
-    let ramm_package_id = match &dplymt_cfg.ramm_pkg_addr_or_path {
-        // RAMM package address provided in TOML
-        RAMMPkgAddrSrc::FromTomlConfig(addr) => {
-            log::info!("RAMM library package ID read from TOML config.");
-            *addr
-        }
-        // RAMM package must be published to get a new package ID
-        RAMMPkgAddrSrc::FromPkgPublication(path) => {
-            log::info!(
-                "RAMM library package ID to be obtained from publication of package at path {:?}",
-                path.as_os_str()
-            );
-            let response = ramm_sui_deploy::publish_ramm_pkg_runner(
-                &sui_client,
-                &keystore,
-                path.to_path_buf(),
-                &client_address,
-            )
-            .await?;
-
-            log::info!(
-                "Status of RAMM library publication tx: {:?}",
-                response.status_ok()
-            );
-
-            // Get the package's ID from the tx response.
-            let ramm_package_id: ObjectID = ramm_sui_deploy::get_ramm_id_from_tx_response(response);
-            ramm_package_id
+    let deployer_m = match ramm_sui_deploy::parse_cli_args(args) {
+        Ok(deployer_m) => deployer_m,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ();
         }
     };
-    log::info!("RAMM package ID: {ramm_package_id}");
-
-    // The response from the tx that creates the RAMM.
-    let new_ramm_tx_response = ramm_sui_deploy::new_ramm_tx_runner(
-        &sui_client,
-        &dplymt_cfg,
-        &keystore,
-        &client_address,
-        ramm_package_id,
-    )
-    .await?;
-    log::info!(
-        "Status of RAMM creation tx: {:?}",
-        new_ramm_tx_response.status_ok()
-    );
-
-    /*
-    The RAMM and its capabilities, extracted from the tx response, and represented as
-    ObjectArg`s, which is the SDK's representation of Move objects.
-
-    Also returned are the IDs of those objects, to display to the user at the end of the program.
-    */
-    let (ramm_obj_args, ramm_obj_ids) =
-        ramm_sui_deploy::build_ramm_obj_args(&sui_client, new_ramm_tx_response, client_address)
-            .await?;
 
-    /*
-    For each asset's aggregator address read from the TOML, use the `SuiClient`'s `ReadApi`
-    to query its `SuiObjectData`, and then use that to build an `ObjectArg` for use in the PTB.
-    */
-    let aggr_obj_args = ramm_sui_deploy::build_aggr_obj_args(&sui_client, &dplymt_cfg).await?;
+    // Completion scripts are printed as is, with nothing logged around them.
+    if let Some(shell) = ramm_sui_deploy::completions_shell(&deployer_m) {
+        let bin_name = exec_name.file_name().unwrap_or_default().to_string_lossy();
+        ramm_sui_deploy::write_completions(shell, &bin_name, &mut std::io::stdout());
+        return ();
+    }
 
-    /*
-    Construct the PTB that will populate and initialize the RAMM.
-    Note that a PTB requires a coin and the network's current gas price, which have to be obtained
-    as part of the process.
-    */
-    let ptb_response = ramm_sui_deploy::add_assets_and_init_ramm_runner(
-        &sui_client,
-        &keystore,
-        &dplymt_cfg,
-        client_address,
-        ramm_package_id,
-        ramm_obj_args,
-        aggr_obj_args,
-    )
-    .await?;
-
-    log::info!("PTB response status: {:?}", ptb_response.status_ok());
-
-    Ok(ramm_obj_ids)
-}
+    // The config's JSON Schema is printed likewise.
+    if ramm_sui_deploy::schema_requested(&deployer_m) {
+        match ramm_sui_deploy::config_schema::config_schema() {
+            Ok(schema) => println!("{}", schema),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return ();
+    }
 
-#[tokio::main]
-async fn main() {
     /*
     Logging infrastructure initialization
     */
-    if let Err(err) = util::init_logging_infrastructure(None,log::LevelFilter::Info) {
+    let log_level = ramm_sui_deploy::log_level_from_args(&deployer_m);
+    let log_backend = ramm_sui_deploy::log_backend_from_args(&deployer_m);
+    if let Err(err) = util::init_logging_infrastructure(None, log_level, log_backend) {
         eprintln!("Failed to initialize logging infrastructure: {}", err);
         return ();
     }
+    log::info!("Process name: {}", exec_name.display());
+
+    // Validating a config only requires reading it: no Sui client is ever created.
+    if let Some(validate_m) = ramm_sui_deploy::validate_args(&deployer_m) {
+        match ramm_sui_deploy::validate_cfg_from_args(validate_m) {
+            Ok((dplymt_cfg, valid)) => {
+                println!("{}", dplymt_cfg);
+                if !valid {
+                    println!("The configuration is invalid: see the errors logged above.");
+                    std::process::exit(1);
+                }
+                println!("The configuration is valid.");
+            }
+            Err(e) => {
+                log::error!("Error reading the config: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return ();
+    }
+
+    // Generating a config only reaches the network to scaffold assets from their coin types.
+    if let Some(generate_opts) = ramm_sui_deploy::generate_config_opts(&deployer_m) {
+        match ramm_sui_deploy::generate_config::generate_config(generate_opts).await {
+            Ok(out_path) => println!("Wrote the config to {}.", out_path.display()),
+            Err(e) => {
+                log::error!("Failed to generate a config: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return ();
+    }
+
+    // Printing the config only requires parsing it, as validating it does.
+    if let Some(print_m) = ramm_sui_deploy::print_config_args(&deployer_m) {
+        match ramm_sui_deploy::config_toml_from_args(print_m) {
+            Ok(toml) => print!("{}", toml),
+            Err(e) => {
+                log::error!("Failed to print the config: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return ();
+    }
 
     /*
     RAMM deployment config parsing
     */
-    let args = &mut env::args_os();
-    let exec_name: PathBuf = PathBuf::from(args.next().unwrap());
-    log::info!("Process name: {}", exec_name.display());
-
-    let dplymt_cfg = match ramm_sui_deploy::deployment_cfg_from_args(args) {
-        Ok(dplymt_cfg) => dplymt_cfg,
+    let (mut dplymt_cfg, dplymt_opts) = match ramm_sui_deploy::deployment_cfg_from_args(&deployer_m)
+    {
+        Ok(cfg_and_opts) => cfg_and_opts,
         Err(e) => {
             log::error!("Error reading the TOML config file into a `String`: {}", e);
             return ();
         }
     };
 
-    // Show deployment cfg to user, and ask them to confirm information.
+    // Every stage reaches the network: its Suibase workdir is checked to be set up before any does,
+    // unless `--rpc-url` bypasses Suibase.
+    if dplymt_cfg.network.rpc_url.is_none() {
+        if let Err(e) = ramm_sui_deploy::check_suibase_workdir(&dplymt_cfg.target_env) {
+            log::error!("{}", e);
+            std::process::exit(1);
+        }
+    }
+
+    // Submitting an offline-signed tx: the config is only used to select the network.
+    if let Some((tx_path, sig_path)) = &dplymt_opts.submit_signed {
+        if let Err(e) = deployment::submit_signed_tx(&dplymt_cfg, tx_path, sig_path).await {
+            log::error!("Failed to submit the signed transaction: {}", e);
+            std::process::exit(1);
+        }
+        return ();
+    }
+
+    // Inspecting a RAMM is read-only, so there is nothing for the user to confirm.
+    if let DeploymentStage::Inspect(ramm_id) = dplymt_opts.stage {
+        if let Err(e) = deployment::inspect_stage(&dplymt_cfg, ramm_id).await {
+            log::error!("Failed to inspect RAMM {}: {}", ramm_id, e);
+            std::process::exit(1);
+        }
+        return ();
+    }
+
+    // The assets' decimal places and price feeds are read before the config is shown to the user,
+    // so that they can spot a wrong or dead one; with `--require-fresh-oracles`, a dead feed is not
+    // left for the user to notice.
+    if let Err(e) = deployment::prepare_deployment(&mut dplymt_cfg, &dplymt_opts).await {
+        log::error!("Failed to prepare the deployment: {}", e);
+        std::process::exit(1);
+    }
+
+    // Show deployment cfg to user, and ask them to confirm information - unless `--yes` was
+    // passed, in which case the cfg is only shown.
     // If user rejects, end the program.
-    match ramm_sui_deploy::user_assent_interaction(&dplymt_cfg) {
+    match ramm_sui_deploy::user_assent_interaction(&dplymt_cfg, dplymt_opts.assume_yes) {
         UserAssent::Rejected => {
             log::info!("User rejected the parsed configuration. Exiting.");
             return ();
@@ -146,14 +152,71 @@ async fn main() {
         
     }
 
-    let ramm_ids = ramm_deployment(dplymt_cfg).await;
-    match ramm_ids {
-        Ok(ramm_ids) => {
-            println!("Success!");
-            println!("These are the IDs of the generated objects:\n{}", ramm_ids);
-        },
+    // The stages other than a full deployment are run on their own.
+    let stage_result = match &dplymt_opts.stage {
+        DeploymentStage::Full | DeploymentStage::Inspect(_) => None,
+        DeploymentStage::Publish => Some(
+            deployment::publish_stage(&dplymt_cfg, &dplymt_opts)
+                .await
+                .map(|package_id| {
+                    println!("Published RAMM package ID: {package_id}");
+                    println!(
+                        "Set it as `ramm_pkg_addr_or_path`, or pass it with `--package-id`, to \
+                        create RAMMs from it."
+                    );
+                }),
+        ),
+        DeploymentStage::Create { populate } => Some(
+            deployment::create_stage(&dplymt_cfg, &dplymt_opts, *populate)
+                .await
+                .map(|ramm_ids| {
+                    for (ix, ids) in ramm_ids.iter().enumerate() {
+                        println!(
+                            "These are the IDs of the objects created for RAMM #{}:\n{}",
+                            ix + 1,
+                            ids
+                        );
+                    }
+                }),
+        ),
+        DeploymentStage::Populate {
+            ramm_index,
+            ramm_ids,
+        } => Some(
+            deployment::populate_stage(&dplymt_cfg, &dplymt_opts, *ramm_index, ramm_ids)
+                .await
+                .map(|()| println!("RAMM {} populated and initialized.", ramm_ids.ramm)),
+        ),
+    };
+    if let Some(stage_result) = stage_result {
+        if let Err(e) = stage_result {
+            log::error!("RAMM deployment error: {}", e);
+            std::process::exit(1);
+        }
+        return ();
+    }
+
+    let report = deployment::ramm_deployment(dplymt_cfg, &dplymt_opts, true).await;
+    match report {
+        Ok(Some(report)) => {
+            match ramm_sui_deploy::report_format::render_report(&report, dplymt_opts.output_format)
+            {
+                Ok(rendered) => println!("{}", rendered),
+                Err(e) => {
+                    log::error!("Failed to render the deployment report: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Ok(None) if dplymt_opts.dry_run => {
+            println!("Dry run complete: no transactions were submitted.");
+        }
+        Ok(None) => {
+            println!("Unsigned transactions exported: no transactions were submitted.");
+        }
         Err(e) => {
             log::error!("RAMM deployment error: {}", e);
+            std::process::exit(1);
         }
     }
 }
\ No newline at end of file
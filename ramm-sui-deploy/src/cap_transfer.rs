@@ -0,0 +1,116 @@
+use sui_json_rpc_types::SuiTransactionBlockEffectsAPI;
+use sui_keys::keystore::Keystore;
+use sui_sdk::SuiClient;
+use sui_types::{
+    base_types::{ObjectID, SuiAddress},
+    digests::TransactionDigest,
+    object::Owner,
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    transaction::{ObjectArg, TransactionData},
+};
+
+use crate::{
+    error::RAMMDeploymentError, estimate_gas_budget, get_cap_obj_arg, get_coin_and_gas,
+    sign_and_execute_tx, sponsor_tx, tx_status, types::RAMMDeploymentConfig,
+};
+
+/// Gas budget for the tx that transfers a RAMM's capabilities to their recipients.
+const CAP_TRANSFER_GAS_BUDGET: u64 = 10_000_000;
+
+/// Transfer a RAMM's capabilities to the recipients named in the config - `admin_cap_recipient`
+/// for its admin cap, and `new_asset_cap_recipient` for its new asset cap - in a single PTB.
+///
+/// `new_asset_cap` should only be given if the RAMM has not been populated, as populating it
+/// destroys the cap. Capabilities without a configured recipient are kept by the signer; if no
+/// capability is to be transferred, no tx is sent, and `None` is returned.
+///
+/// The tx's effects are checked to show each capability as owned by its recipient;
+/// `CapTransferNotVerified` is returned otherwise.
+pub async fn transfer_caps(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    client_address: SuiAddress,
+    admin_cap: ObjectID,
+    new_asset_cap: Option<ObjectID>,
+    gas_coin: Option<ObjectID>,
+) -> Result<Option<TransactionDigest>, RAMMDeploymentError> {
+    let transfers: Vec<(ObjectID, SuiAddress)> = [
+        dplymt_cfg
+            .admin_cap_recipient
+            .map(|recipient| (admin_cap, recipient)),
+        new_asset_cap.zip(dplymt_cfg.new_asset_cap_recipient),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if transfers.is_empty() {
+        return Ok(None);
+    }
+
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    for (cap_id, recipient) in &transfers {
+        let cap_ref = match get_cap_obj_arg(sui_client, *cap_id).await? {
+            Some(ObjectArg::ImmOrOwnedObject(cap_ref)) => cap_ref,
+            _ => return Err(RAMMDeploymentError::ObjectNotFound(*cap_id)),
+        };
+        ptb.transfer_object(*recipient, cap_ref)
+            .map_err(|err| RAMMDeploymentError::PtbBuildError(format!("cap transfer: {err}")))?;
+    }
+
+    let (coin, gas_price) = get_coin_and_gas(
+        sui_client,
+        keystore,
+        dplymt_cfg.gas_owner(client_address),
+        CAP_TRANSFER_GAS_BUDGET,
+        None,
+        gas_coin,
+        &dplymt_cfg.gas,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await?;
+    let transfer_tx = sponsor_tx(
+        TransactionData::new_programmable(
+            client_address,
+            vec![coin.object_ref()],
+            ptb.finish(),
+            CAP_TRANSFER_GAS_BUDGET,
+            gas_price,
+        ),
+        dplymt_cfg.sponsor.as_ref(),
+    );
+    let transfer_tx =
+        estimate_gas_budget(sui_client, transfer_tx, dplymt_cfg.gas.safety_margin()).await?;
+
+    let response = sign_and_execute_tx(
+        sui_client,
+        keystore,
+        transfer_tx,
+        &client_address,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await?;
+    tx_status::check_tx_status(&response)?;
+
+    let mutated = response
+        .effects
+        .as_ref()
+        .map(|effects| effects.mutated())
+        .unwrap_or_default();
+    for (cap_id, recipient) in transfers {
+        let transferred = mutated.iter().any(|oor| {
+            oor.reference.object_id == cap_id && oor.owner == Owner::AddressOwner(recipient)
+        });
+        if !transferred {
+            return Err(RAMMDeploymentError::CapTransferNotVerified {
+                cap: cap_id,
+                recipient,
+            });
+        }
+        log::info!("Transferred capability {cap_id} to {recipient}.");
+    }
+
+    Ok(Some(response.digest))
+}
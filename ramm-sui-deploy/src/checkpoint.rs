@@ -0,0 +1,88 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use sui_types::{base_types::ObjectID, digests::TransactionDigest};
+
+use crate::{error::RAMMDeploymentError, RAMMObjectArgs, RAMMObjectIDs};
+
+/// Progress of a single RAMM's deployment, as recorded in a `DeploymentCheckpoint`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RAMMCheckpoint {
+    /// `ObjectArg`s of the RAMM and its capabilities, as of the RAMM's creation.
+    pub obj_args: RAMMObjectArgs,
+    pub obj_ids: RAMMObjectIDs,
+    /// Digest of the RAMM creation tx.
+    pub create_tx_digest: TransactionDigest,
+    /// Digest of the successful population PTB; `None` if the RAMM has yet to be populated.
+    pub populate_tx_digest: Option<TransactionDigest>,
+}
+
+/// Record of the stages of a deployment that have been completed, written to a JSON file after
+/// each stage so that a deployment that fails midway can be resumed, without publishing the
+/// package or creating RAMMs a second time.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeploymentCheckpoint {
+    /// The network - i.e. the Suibase workdir, with `active` resolved - the deployment targets.
+    pub network: String,
+    /// ID of the RAMM package, once it has been published, upgraded or read from the config.
+    pub package_id: Option<ObjectID>,
+    /// Digest of the package publication or upgrade tx, if one was performed.
+    pub publish_tx_digest: Option<TransactionDigest>,
+    /// If the package was upgraded, the ID of the package it upgraded.
+    pub upgraded_from: Option<ObjectID>,
+    /// Progress of each RAMM that has been created, in the order in which they appear in the
+    /// config.
+    pub ramms: Vec<RAMMCheckpoint>,
+}
+
+impl DeploymentCheckpoint {
+    /// Create a checkpoint, with no completed stages, for a deployment to the given network.
+    pub fn new(network: String) -> Self {
+        DeploymentCheckpoint {
+            network,
+            package_id: None,
+            publish_tx_digest: None,
+            upgraded_from: None,
+            ramms: Vec::new(),
+        }
+    }
+
+    /// Read a checkpoint previously written with `write_to_file`.
+    pub fn read_from_file(path: &Path) -> Result<Self, RAMMDeploymentError> {
+        let json = fs::read_to_string(path).map_err(RAMMDeploymentError::CheckpointReadError)?;
+
+        serde_json::from_str(&json).map_err(RAMMDeploymentError::CheckpointParseError)
+    }
+
+    /// Write the checkpoint, as pretty-printed JSON, to the file at the given path.
+    ///
+    /// If the file already exists, it is overwritten.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), RAMMDeploymentError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(RAMMDeploymentError::CheckpointSerializationError)?;
+
+        fs::write(path, json).map_err(RAMMDeploymentError::CheckpointWriteError)
+    }
+
+    /// Check that a checkpoint being resumed was written for a deployment to the same network, and
+    /// of no more RAMMs than the config specifies.
+    pub fn check_resumable(
+        &self,
+        network: &str,
+        ramm_count: usize,
+    ) -> Result<(), RAMMDeploymentError> {
+        if self.network != network {
+            return Err(RAMMDeploymentError::CheckpointNetworkMismatch {
+                checkpoint: self.network.clone(),
+                current: network.to_string(),
+            });
+        }
+        if self.ramms.len() > ramm_count {
+            return Err(RAMMDeploymentError::CheckpointConfigMismatch {
+                checkpoint: self.ramms.len(),
+                config: ramm_count,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+use async_trait::async_trait;
+use sui_json_rpc_types::{
+    CoinPage, SuiObjectDataOptions, SuiObjectResponse, SuiTransactionBlockResponse,
+    SuiTransactionBlockResponseOptions,
+};
+use sui_sdk::{error::SuiRpcResult, SuiClient};
+use sui_types::{
+    base_types::{ObjectID, SuiAddress},
+    digests::TransactionDigest,
+    quorum_driver_types::ExecuteTransactionRequestType,
+    transaction::Transaction,
+};
+
+/// The calls to a Sui full node that the deployment's object resolution, gas coin selection and
+/// tx execution are made through.
+///
+/// `SuiClient` implements it by forwarding each call to the API it belongs to; abstracting them
+/// lets those functions be exercised against a stand-in node, e.g. one returning canned responses,
+/// without a live network.
+///
+/// Each method has the signature of the `SuiClient` method of the same name.
+#[async_trait]
+pub trait DeploymentClient: Sync {
+    async fn get_object_with_options(
+        &self,
+        object_id: ObjectID,
+        options: SuiObjectDataOptions,
+    ) -> SuiRpcResult<SuiObjectResponse>;
+
+    async fn multi_get_object_with_options(
+        &self,
+        object_ids: Vec<ObjectID>,
+        options: SuiObjectDataOptions,
+    ) -> SuiRpcResult<Vec<SuiObjectResponse>>;
+
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> SuiRpcResult<CoinPage>;
+
+    async fn get_reference_gas_price(&self) -> SuiRpcResult<u64>;
+
+    async fn get_transaction_with_options(
+        &self,
+        digest: TransactionDigest,
+        options: SuiTransactionBlockResponseOptions,
+    ) -> SuiRpcResult<SuiTransactionBlockResponse>;
+
+    async fn execute_transaction_block(
+        &self,
+        tx: Transaction,
+        options: SuiTransactionBlockResponseOptions,
+        request_type: Option<ExecuteTransactionRequestType>,
+    ) -> SuiRpcResult<SuiTransactionBlockResponse>;
+}
+
+#[async_trait]
+impl DeploymentClient for SuiClient {
+    async fn get_object_with_options(
+        &self,
+        object_id: ObjectID,
+        options: SuiObjectDataOptions,
+    ) -> SuiRpcResult<SuiObjectResponse> {
+        self.read_api()
+            .get_object_with_options(object_id, options)
+            .await
+    }
+
+    async fn multi_get_object_with_options(
+        &self,
+        object_ids: Vec<ObjectID>,
+        options: SuiObjectDataOptions,
+    ) -> SuiRpcResult<Vec<SuiObjectResponse>> {
+        self.read_api()
+            .multi_get_object_with_options(object_ids, options)
+            .await
+    }
+
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> SuiRpcResult<CoinPage> {
+        self.coin_read_api()
+            .get_coins(owner, coin_type, cursor, limit)
+            .await
+    }
+
+    async fn get_reference_gas_price(&self) -> SuiRpcResult<u64> {
+        self.read_api().get_reference_gas_price().await
+    }
+
+    async fn get_transaction_with_options(
+        &self,
+        digest: TransactionDigest,
+        options: SuiTransactionBlockResponseOptions,
+    ) -> SuiRpcResult<SuiTransactionBlockResponse> {
+        self.read_api()
+            .get_transaction_with_options(digest, options)
+            .await
+    }
+
+    async fn execute_transaction_block(
+        &self,
+        tx: Transaction,
+        options: SuiTransactionBlockResponseOptions,
+        request_type: Option<ExecuteTransactionRequestType>,
+    ) -> SuiRpcResult<SuiTransactionBlockResponse> {
+        self.quorum_driver_api()
+            .execute_transaction_block(tx, options, request_type)
+            .await
+    }
+}
+
+/// A stand-in node for tests, answering each `DeploymentClient` call from canned data rather
+/// than a network.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::collections::BTreeMap;
+
+    use async_trait::async_trait;
+    use sui_json_rpc_types::{
+        Coin, CoinPage, SuiObjectData, SuiObjectDataOptions, SuiObjectResponse,
+        SuiObjectResponseError, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+    };
+    use sui_sdk::error::{Error, SuiRpcResult};
+    use sui_types::{
+        base_types::{ObjectID, ObjectType, SequenceNumber, SuiAddress},
+        digests::{ObjectDigest, TransactionDigest},
+        object::Owner,
+        quorum_driver_types::ExecuteTransactionRequestType,
+        transaction::Transaction,
+    };
+
+    use super::DeploymentClient;
+
+    /// Number of coins in each page `MockClient::get_coins` returns, if no limit is given; small,
+    /// so that callers' pagination is exercised.
+    const COIN_PAGE_SIZE: usize = 2;
+
+    /// A `DeploymentClient` whose node holds the given objects, coins and txs.
+    ///
+    /// The options of each query are ignored: every field of an object is always returned.
+    #[derive(Default)]
+    pub(crate) struct MockClient {
+        /// Objects the node holds, by ID; any other object is reported not to exist.
+        pub objects: BTreeMap<ObjectID, SuiObjectData>,
+        /// SUI coins of the queried address, whichever it is, in the order they are paged in.
+        pub coins: Vec<Coin>,
+        pub reference_gas_price: u64,
+        /// Responses to txs, by digest, returned both when they are queried and when they are
+        /// executed; executing any other tx fails.
+        pub transactions: BTreeMap<TransactionDigest, SuiTransactionBlockResponse>,
+    }
+
+    impl MockClient {
+        fn object_response(&self, object_id: ObjectID) -> SuiObjectResponse {
+            match self.objects.get(&object_id) {
+                Some(data) => SuiObjectResponse::new_with_data(data.clone()),
+                None => SuiObjectResponse::new_with_error(SuiObjectResponseError::NotExists {
+                    object_id,
+                }),
+            }
+        }
+
+        fn transaction(
+            &self,
+            digest: TransactionDigest,
+        ) -> SuiRpcResult<SuiTransactionBlockResponse> {
+            self.transactions
+                .get(&digest)
+                .cloned()
+                .ok_or_else(|| Error::DataError(format!("no tx with digest {digest}")))
+        }
+    }
+
+    /// A SUI coin with the given balance, and a random ID.
+    pub(crate) fn coin(balance: u64) -> Coin {
+        Coin {
+            coin_type: "0x2::sui::SUI".to_string(),
+            coin_object_id: ObjectID::random(),
+            version: SequenceNumber::from_u64(1),
+            digest: ObjectDigest::random(),
+            balance,
+            previous_transaction: TransactionDigest::random(),
+        }
+    }
+
+    /// The data of an object of the given type and owner.
+    pub(crate) fn object_data(
+        object_id: ObjectID,
+        type_: ObjectType,
+        owner: Owner,
+    ) -> SuiObjectData {
+        SuiObjectData {
+            object_id,
+            version: SequenceNumber::from_u64(1),
+            digest: ObjectDigest::random(),
+            type_: Some(type_),
+            owner: Some(owner),
+            previous_transaction: None,
+            storage_rebate: None,
+            display: None,
+            content: None,
+            bcs: None,
+        }
+    }
+
+    #[async_trait]
+    impl DeploymentClient for MockClient {
+        async fn get_object_with_options(
+            &self,
+            object_id: ObjectID,
+            _options: SuiObjectDataOptions,
+        ) -> SuiRpcResult<SuiObjectResponse> {
+            Ok(self.object_response(object_id))
+        }
+
+        async fn multi_get_object_with_options(
+            &self,
+            object_ids: Vec<ObjectID>,
+            _options: SuiObjectDataOptions,
+        ) -> SuiRpcResult<Vec<SuiObjectResponse>> {
+            Ok(object_ids
+                .into_iter()
+                .map(|object_id| self.object_response(object_id))
+                .collect())
+        }
+
+        async fn get_coins(
+            &self,
+            _owner: SuiAddress,
+            _coin_type: Option<String>,
+            cursor: Option<ObjectID>,
+            limit: Option<usize>,
+        ) -> SuiRpcResult<CoinPage> {
+            // A page starts right after the coin its cursor names.
+            let start = match cursor {
+                None => 0,
+                Some(cursor) => self
+                    .coins
+                    .iter()
+                    .position(|coin| coin.coin_object_id == cursor)
+                    .map_or(self.coins.len(), |ix| ix + 1),
+            };
+            let end = (start + limit.unwrap_or(COIN_PAGE_SIZE)).min(self.coins.len());
+            let data = self.coins[start..end].to_vec();
+
+            Ok(CoinPage {
+                next_cursor: data.last().map(|coin| coin.coin_object_id),
+                has_next_page: end < self.coins.len(),
+                data,
+            })
+        }
+
+        async fn get_reference_gas_price(&self) -> SuiRpcResult<u64> {
+            Ok(self.reference_gas_price)
+        }
+
+        async fn get_transaction_with_options(
+            &self,
+            digest: TransactionDigest,
+            _options: SuiTransactionBlockResponseOptions,
+        ) -> SuiRpcResult<SuiTransactionBlockResponse> {
+            self.transaction(digest)
+        }
+
+        async fn execute_transaction_block(
+            &self,
+            tx: Transaction,
+            _options: SuiTransactionBlockResponseOptions,
+            _request_type: Option<ExecuteTransactionRequestType>,
+        ) -> SuiRpcResult<SuiTransactionBlockResponse> {
+            self.transaction(*tx.digest())
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use futures::{stream, StreamExt, TryStreamExt};
+use sui_json_rpc_types::SuiCoinMetadata;
+use sui_sdk::SuiClient;
+
+use crate::{
+    error::RAMMDeploymentError,
+    types::{NetworkConfig, RAMMDeploymentConfig},
+    with_timeout,
+};
+
+/// Resolve each asset's decimal places against its on-chain `CoinMetadata`, from which its coin
+/// name and symbol are also recorded, to be shown to the user.
+///
+/// * An asset whose config omits `decimal_places` takes them from its metadata; if it has none,
+///   `CoinMetadataNotFound` is returned.
+/// * An asset whose config sets `decimal_places` keeps them, but should they differ from its
+///   metadata's, a warning is logged - or, if `strict` is set, an error, with
+///   `DecimalPlacesMismatch` being returned once every asset has been checked.
+///
+/// The metadata of all assets are fetched concurrently, up to the network config's
+/// `max_concurrency`.
+///
+/// Decimal places obtained from the chain are then validated like those set in the config; if any
+/// are out of range, `InvalidConfigData` is returned.
+pub async fn resolve_decimal_places(
+    sui_client: &SuiClient,
+    dplymt_cfg: &mut RAMMDeploymentConfig,
+    strict: bool,
+) -> Result<(), RAMMDeploymentError> {
+    let asset_types: Vec<String> = dplymt_cfg
+        .ramms
+        .iter()
+        .flat_map(|ramm_spec| ramm_spec.assets.iter())
+        .map(|asset| asset.asset_type.to_string())
+        .collect();
+    let network_cfg = &dplymt_cfg.network;
+    let metadatas: Vec<Option<SuiCoinMetadata>> = stream::iter(asset_types.iter())
+        .map(|asset_type| fetch_metadata(sui_client, asset_type, network_cfg))
+        .buffered(network_cfg.max_concurrency)
+        .try_collect()
+        .await?;
+
+    let mut mismatches: usize = 0;
+    let assets = dplymt_cfg
+        .ramms
+        .iter_mut()
+        .flat_map(|ramm_spec| ramm_spec.assets.iter_mut());
+    for ((asset, asset_type), metadata) in assets.zip(asset_types).zip(metadatas) {
+        asset.coin_name_and_symbol = metadata
+            .as_ref()
+            .map(|metadata| (metadata.name.clone(), metadata.symbol.clone()));
+        let on_chain_decimals = metadata.map(|metadata| metadata.decimals);
+
+        match (asset.decimal_places, on_chain_decimals) {
+            (None, Some(decimals)) => {
+                log::info!(
+                    "Asset {} has {decimals} decimal places, per its CoinMetadata.",
+                    asset.label()
+                );
+                asset.decimal_places = Some(decimals);
+            }
+            (None, None) => {
+                return Err(RAMMDeploymentError::CoinMetadataNotFound(asset_type));
+            }
+            (Some(declared), Some(decimals)) if declared != decimals => {
+                mismatches += 1;
+                let message = format!(
+                    "DECIMAL PLACES MISMATCH: asset {} is configured with {declared} decimal \
+                    places, but its CoinMetadata has {decimals}. Amounts of it would be scaled \
+                    wrongly by the RAMM.",
+                    asset.label()
+                );
+                if strict {
+                    log::error!("{message}");
+                } else {
+                    log::warn!("{message}");
+                }
+            }
+            (Some(_), _) => {}
+        }
+    }
+
+    if strict && mismatches > 0 {
+        return Err(RAMMDeploymentError::DecimalPlacesMismatch(mismatches));
+    }
+    if !dplymt_cfg.validate_ramm_cfg() {
+        return Err(RAMMDeploymentError::InvalidConfigData);
+    }
+
+    Ok(())
+}
+
+/// Query the `CoinMetadata` of the given coin type, if it has one.
+pub(crate) async fn fetch_metadata(
+    sui_client: &SuiClient,
+    asset_type: &str,
+    network_cfg: &NetworkConfig,
+) -> Result<Option<SuiCoinMetadata>, RAMMDeploymentError> {
+    with_timeout(
+        network_cfg,
+        "fetching an asset's coin metadata",
+        sui_client
+            .coin_read_api()
+            .get_coin_metadata(asset_type.to_string()),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::CoinMetadataQueryError)
+}
@@ -0,0 +1,268 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use sui_types::base_types::{ObjectID, SuiAddress};
+
+use crate::{
+    error::ConfigValidationError,
+    types::{
+        parse_coin_type, AssetConfig, GasConfig, NetworkConfig, OracleConfig, OracleProvider,
+        RAMMDeploymentConfig, RAMMPkgAddrSrc, RAMMSpec, RetryPolicy,
+    },
+};
+
+/// A RAMM being specified with a `RAMMDeploymentConfigBuilder`.
+#[derive(Default)]
+struct RAMMSpecDraft {
+    fee_collection_address: Option<SuiAddress>,
+    assets: Vec<AssetConfig>,
+    /// Problems found while adding assets, e.g. an asset type that does not parse, reported by
+    /// `build` along with the config's own.
+    errors: Vec<ConfigValidationError>,
+}
+
+/// Builds a `RAMMDeploymentConfig` from Rust, e.g. for use with `deploy`, rather than from a TOML
+/// or JSON config.
+///
+/// Asset types are given as strings, and parsed as they are from a config; nothing is checked until
+/// `build`, which reports every problem with the config at once, as `validate_detailed` does.
+///
+/// Assets are added to the builder's current RAMM, which the first of them starts if need be;
+/// `next_ramm` starts another one, which will be deployed after it. The config's other sections,
+/// e.g. `[gas]`, keep their defaults.
+///
+/// ```ignore
+/// let cfg = RAMMDeploymentConfig::builder()
+///     .target_env("testnet")
+///     .package_id(package_id)
+///     .fee_collection_address(fee_address)
+///     .add_asset("0x2::sui::SUI", sui_aggregator, 1_000_000, 9)
+///     .add_asset(usdc_type, usdc_aggregator, 1_000, 6)
+///     .build()?;
+/// ```
+#[derive(Default)]
+pub struct RAMMDeploymentConfigBuilder {
+    target_env: Option<String>,
+    ramm_pkg_addr_or_path: Option<RAMMPkgAddrSrc>,
+    /// The RAMMs finished with `next_ramm`, in the order they will be deployed.
+    ramms: Vec<RAMMSpecDraft>,
+    /// The RAMM being specified, deployed after the finished ones; `None` until it is started.
+    current: Option<RAMMSpecDraft>,
+    admin_cap_recipient: Option<SuiAddress>,
+    new_asset_cap_recipient: Option<SuiAddress>,
+}
+
+impl RAMMDeploymentConfig {
+    /// Start building a config from Rust; see `RAMMDeploymentConfigBuilder`.
+    pub fn builder() -> RAMMDeploymentConfigBuilder {
+        RAMMDeploymentConfigBuilder::default()
+    }
+}
+
+impl RAMMDeploymentConfigBuilder {
+    /// The Sui network environment to be targeted; see `RAMMDeploymentConfig::target_env`.
+    pub fn target_env(mut self, target_env: impl Into<String>) -> Self {
+        self.target_env = Some(target_env.into());
+        self
+    }
+
+    /// Publish the RAMM library at the given path, and deploy the RAMMs from it.
+    pub fn package_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.ramm_pkg_addr_or_path = Some(RAMMPkgAddrSrc::FromPkgPublication(path.into()));
+        self
+    }
+
+    /// Deploy the RAMMs from the already published package with the given ID.
+    pub fn package_id(mut self, package_id: ObjectID) -> Self {
+        self.ramm_pkg_addr_or_path = Some(RAMMPkgAddrSrc::FromTomlConfig(package_id));
+        self
+    }
+
+    /// The address the current RAMM's fees are collected to.
+    pub fn fee_collection_address(mut self, address: SuiAddress) -> Self {
+        self.current_ramm().fee_collection_address = Some(address);
+        self
+    }
+
+    /// Add an asset, priced by the Switchboard aggregator at `aggregator`, to the current RAMM.
+    pub fn add_asset(
+        mut self,
+        asset_type: &str,
+        aggregator_address: SuiAddress,
+        minimum_trade_amount: u64,
+        decimal_places: u8,
+    ) -> Self {
+        let ramm = self.current_ramm();
+        match parse_coin_type(asset_type) {
+            Ok(asset_type) => ramm.assets.push(AssetConfig {
+                asset_type,
+                aggregator_address,
+                minimum_trade_amount,
+                decimal_places: Some(decimal_places),
+                oracle_provider: OracleProvider::Switchboard,
+                coin_name_and_symbol: None,
+                oracle_reading: None,
+            }),
+            Err(reason) => ramm.errors.push(ConfigValidationError::InvalidAssetType {
+                asset_type: asset_type.to_string(),
+                reason,
+            }),
+        }
+        self
+    }
+
+    /// Finish specifying the current RAMM, and start specifying the next one.
+    pub fn next_ramm(mut self) -> Self {
+        let finished = self.current.replace(RAMMSpecDraft::default());
+        self.ramms.extend(finished);
+        self
+    }
+
+    /// The address each RAMM's admin capability is transferred to once it is populated; see
+    /// `RAMMDeploymentConfig::admin_cap_recipient`.
+    pub fn admin_cap_recipient(mut self, address: SuiAddress) -> Self {
+        self.admin_cap_recipient = Some(address);
+        self
+    }
+
+    /// The address each unpopulated RAMM's new asset capability is transferred to; see
+    /// `RAMMDeploymentConfig::new_asset_cap_recipient`.
+    pub fn new_asset_cap_recipient(mut self, address: SuiAddress) -> Self {
+        self.new_asset_cap_recipient = Some(address);
+        self
+    }
+
+    /// Assemble the config, and validate it as one read from a file would be.
+    ///
+    /// Every problem found is returned, rather than only the first.
+    pub fn build(self) -> Result<RAMMDeploymentConfig, Vec<ConfigValidationError>> {
+        let mut errors = Vec::new();
+        if self.target_env.is_none() {
+            errors.push(ConfigValidationError::MissingTargetEnv);
+        }
+        if self.ramm_pkg_addr_or_path.is_none() {
+            errors.push(ConfigValidationError::MissingPackage);
+        }
+
+        let drafts = self.ramms.into_iter().chain(self.current);
+        let mut ramms = Vec::new();
+        for (ix, draft) in drafts.enumerate() {
+            let mut ramm_errors = draft.errors;
+            match draft.fee_collection_address {
+                Some(fee_collection_address) => ramms.push(RAMMSpec {
+                    asset_count: None,
+                    fee_collection_address,
+                    assets: draft.assets,
+                }),
+                None => ramm_errors.push(ConfigValidationError::MissingFeeCollectionAddress),
+            }
+            errors.extend(
+                ramm_errors
+                    .into_iter()
+                    .map(|error| ConfigValidationError::InRAMM {
+                        ramm: ix + 1,
+                        error: Box::new(error),
+                    }),
+            );
+        }
+
+        let (target_env, ramm_pkg_addr_or_path) =
+            match (self.target_env, self.ramm_pkg_addr_or_path) {
+                (Some(target_env), Some(ramm_pkg_addr_or_path)) if errors.is_empty() => {
+                    (target_env, ramm_pkg_addr_or_path)
+                }
+                _ => return Err(errors),
+            };
+        let cfg = RAMMDeploymentConfig {
+            target_env,
+            ramm_pkg_addr_or_path,
+            asset_count: None,
+            fee_collection_address: None,
+            assets: Vec::new(),
+            ramms,
+            admin_cap_recipient: self.admin_cap_recipient,
+            new_asset_cap_recipient: self.new_asset_cap_recipient,
+            gas: GasConfig::default(),
+            retry: RetryPolicy::default(),
+            network: NetworkConfig::default(),
+            oracle: OracleConfig::default(),
+            faucet: None,
+            sponsor: None,
+            named_addresses: BTreeMap::new(),
+            initial_liquidity: BTreeMap::new(),
+        };
+        cfg.validate_detailed()?;
+
+        Ok(cfg)
+    }
+
+    /// The RAMM that assets are being added to, started if there is none yet.
+    fn current_ramm(&mut self) -> &mut RAMMSpecDraft {
+        self.current.get_or_insert_with(RAMMSpecDraft::default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BTC: &str =
+        "0x76a5ecf30b2cf49a342a9bd74a479702a1b321b0d45f06920618dbe7c2da52b1::test_coins::BTC";
+    const ETH: &str =
+        "0x76a5ecf30b2cf49a342a9bd74a479702a1b321b0d45f06920618dbe7c2da52b1::test_coins::ETH";
+
+    /// A builder for the testnet, whose first RAMM holds BTC and ETH, and collects fees to
+    /// `fee_address`.
+    fn builder(fee_address: SuiAddress) -> RAMMDeploymentConfigBuilder {
+        RAMMDeploymentConfig::builder()
+            .target_env("testnet")
+            .package_id(ObjectID::random())
+            .fee_collection_address(fee_address)
+            .add_asset(BTC, SuiAddress::random_for_testing_only(), 10_000, 8)
+            .add_asset(ETH, SuiAddress::random_for_testing_only(), 100_000, 8)
+    }
+
+    #[test]
+    fn first_ramm_is_started_implicitly() {
+        let fee_address = SuiAddress::random_for_testing_only();
+        let cfg = builder(fee_address).build().unwrap();
+
+        assert_eq!(cfg.ramms.len(), 1);
+        assert_eq!(cfg.ramms[0].fee_collection_address, fee_address);
+        assert_eq!(cfg.ramms[0].assets.len(), 2);
+    }
+
+    #[test]
+    fn next_ramm_starts_another_ramm() {
+        let second_fee_address = SuiAddress::random_for_testing_only();
+        let cfg = builder(SuiAddress::random_for_testing_only())
+            .next_ramm()
+            .fee_collection_address(second_fee_address)
+            .add_asset(ETH, SuiAddress::random_for_testing_only(), 100_000, 8)
+            .add_asset(BTC, SuiAddress::random_for_testing_only(), 10_000, 8)
+            .build()
+            .unwrap();
+
+        assert_eq!(cfg.ramms.len(), 2);
+        assert_eq!(cfg.ramms[1].fee_collection_address, second_fee_address);
+        assert_eq!(
+            cfg.ramms[1].assets[0].asset_type,
+            parse_coin_type(ETH).unwrap()
+        );
+    }
+
+    #[test]
+    fn ramm_without_fee_address_is_reported() {
+        let errors = builder(SuiAddress::random_for_testing_only())
+            .next_ramm()
+            .add_asset(BTC, SuiAddress::random_for_testing_only(), 10_000, 8)
+            .add_asset(ETH, SuiAddress::random_for_testing_only(), 100_000, 8)
+            .build()
+            .unwrap_err();
+
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            ConfigValidationError::InRAMM { ramm: 2, error }
+                if matches!(**error, ConfigValidationError::MissingFeeCollectionAddress)
+        )));
+    }
+}
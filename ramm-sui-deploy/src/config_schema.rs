@@ -0,0 +1,32 @@
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject},
+    schema_for,
+};
+
+use crate::{
+    error::RAMMDeploymentError,
+    types::{RAMMDeploymentConfig, TARGET_ENVS},
+};
+
+/// The JSON Schema of the TOML config, i.e. of `RAMMDeploymentConfig`, pretty-printed.
+///
+/// TOML maps onto JSON, so editors with a TOML language server, e.g. Taplo, can validate a config
+/// against it, and complete its fields, as it is written.
+///
+/// Sui addresses, object IDs and coin types are strings in the schema; what it can not express,
+/// e.g. that an asset's aggregator is live, is left to `validate`.
+pub fn config_schema() -> Result<String, RAMMDeploymentError> {
+    let schema = schema_for!(RAMMDeploymentConfig);
+    serde_json::to_string_pretty(&schema).map_err(RAMMDeploymentError::SchemaSerializationError)
+}
+
+/// The schema of `target_env`: one of the `TARGET_ENVS`, rather than any string.
+pub fn target_env_schema(_: &mut SchemaGenerator) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(TARGET_ENVS.iter().map(|env| (*env).into()).collect()),
+        ..Default::default()
+    }
+    .into()
+}
@@ -0,0 +1,1309 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use sui_json_rpc_types::SuiTransactionBlockEffectsAPI;
+use sui_keys::keystore::Keystore;
+use sui_sdk::SuiClient;
+use sui_types::base_types::{ObjectID, SuiAddress};
+use suibase::Helper;
+
+use crate::{
+    cap_transfer,
+    checkpoint::{DeploymentCheckpoint, RAMMCheckpoint},
+    coin_metadata,
+    env_file::DotenvOutput,
+    error::RAMMDeploymentError,
+    events::RAMMEvents,
+    explorer::Explorer,
+    interrupt::InterruptHandler,
+    lockfile::{self, DeploymentLock},
+    manifest::DeploymentManifest,
+    oracle,
+    report::{DeploymentReport, RAMMReport, TxReport},
+    smoke_test,
+    ts_config::TsSdkConfig,
+    types::{
+        DeploymentOptions, DeploymentStage, RAMMDeploymentConfig, RAMMPkgAddrSrc, RAMMSpec,
+        LOCALNET,
+    },
+    util, PopulationOutcome, RAMMObjectArgs, RAMMObjectIDs,
+};
+
+/// The remainder of a dry run in which the RAMM package would be published or upgraded: without the
+/// resulting package ID, the RAMM creation tx cannot be built - but the assets' aggregators can
+/// still be resolved.
+async fn dry_run_without_package(
+    sui_client: &SuiClient,
+    dplymt_cfg: &RAMMDeploymentConfig,
+) -> Result<(), RAMMDeploymentError> {
+    for ramm_spec in &dplymt_cfg.ramms {
+        crate::build_aggr_obj_args(sui_client, ramm_spec, &dplymt_cfg.network).await?;
+    }
+    log::info!("Dry run: resolved the aggregator objects of all assets.");
+    log::info!(
+        "Dry run: the RAMM package ID is only known after publication, so the RAMM \
+        creation txs and population PTBs would be built next, but cannot be simulated."
+    );
+    Ok(())
+}
+
+/// Build and dry run the tx that would publish the RAMM library at `path` - or, if
+/// `dplymt_opts.upgrade_cap` is set, upgrade the package it governs with it.
+async fn dry_run_pkg_publication(
+    sui_client: &SuiClient,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+    client_address: SuiAddress,
+    path: &Path,
+) -> Result<(), RAMMDeploymentError> {
+    match dplymt_opts.upgrade_cap {
+        Some(upgrade_cap) => {
+            let previous_package_id = crate::get_upgradable_pkg_id(sui_client, upgrade_cap).await?;
+            let upgrade_tx = crate::upgrade_tx(
+                sui_client,
+                path.to_path_buf(),
+                &dplymt_opts.pkg_build,
+                &dplymt_cfg.named_addresses,
+                client_address,
+                previous_package_id,
+                upgrade_cap,
+                dplymt_cfg.gas.publication_budget(),
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                dplymt_cfg.sponsor.as_ref(),
+            )
+            .await?;
+            let upgrade_tx =
+                crate::estimate_gas_budget(sui_client, upgrade_tx, dplymt_cfg.gas.safety_margin())
+                    .await?;
+            crate::log_would_execute("RAMM library upgrade", &upgrade_tx);
+        }
+        None => {
+            let publish_tx = crate::publish_tx(
+                sui_client,
+                path.to_path_buf(),
+                &dplymt_opts.pkg_build,
+                &dplymt_cfg.named_addresses,
+                client_address,
+                dplymt_cfg.gas.publication_budget(),
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                dplymt_cfg.sponsor.as_ref(),
+            )
+            .await?;
+            let publish_tx =
+                crate::estimate_gas_budget(sui_client, publish_tx, dplymt_cfg.gas.safety_margin())
+                    .await?;
+            crate::log_would_execute("RAMM library publication", &publish_tx);
+        }
+    }
+    Ok(())
+}
+
+/// Publish the RAMM library at `path` - or, if `dplymt_opts.upgrade_cap` is set, upgrade the
+/// package it governs with it.
+///
+/// Returns the ID of the resulting package, the tx with the gas it used, and, in the case of an
+/// upgrade, the ID of the package that was upgraded.
+async fn publish_or_upgrade_pkg(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+    client_address: SuiAddress,
+    path: &Path,
+    explorer: Option<&Explorer>,
+) -> Result<(ObjectID, TxReport, Option<ObjectID>), RAMMDeploymentError> {
+    match dplymt_opts.upgrade_cap {
+        Some(upgrade_cap) => {
+            let previous_package_id = crate::get_upgradable_pkg_id(sui_client, upgrade_cap).await?;
+            log::info!(
+                "RAMM library package {previous_package_id} to be upgraded with package at path \
+                {:?}",
+                path.as_os_str()
+            );
+            let response = crate::upgrade_ramm_pkg_runner(
+                sui_client,
+                keystore,
+                path.to_path_buf(),
+                &dplymt_opts.pkg_build,
+                &dplymt_cfg.named_addresses,
+                &client_address,
+                previous_package_id,
+                upgrade_cap,
+                dplymt_cfg.gas.publication_budget(),
+                dplymt_cfg.gas.safety_margin(),
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                dplymt_cfg.sponsor.as_ref(),
+                &dplymt_cfg.retry,
+                &dplymt_cfg.network,
+            )
+            .await?;
+
+            log::info!(
+                "Status of RAMM library upgrade tx: {:?}",
+                response.status_ok()
+            );
+            crate::tx_status::check_tx_status(&response)?;
+            let upgrade_tx = TxReport::from_response(&response);
+            if let Some(explorer) = explorer {
+                explorer.log_tx("RAMM library upgrade", &upgrade_tx.digest);
+            }
+
+            // Get the upgraded package's ID from the tx response.
+            let package_id = crate::get_ramm_id_from_tx_response(response)?;
+            Ok((package_id, upgrade_tx, Some(previous_package_id)))
+        }
+        None => {
+            log::info!(
+                "RAMM library package ID to be obtained from publication of package at path {:?}",
+                path.as_os_str()
+            );
+            let response = crate::publish_ramm_pkg_runner(
+                sui_client,
+                keystore,
+                path.to_path_buf(),
+                &dplymt_opts.pkg_build,
+                &dplymt_cfg.named_addresses,
+                &client_address,
+                dplymt_cfg.gas.publication_budget(),
+                dplymt_cfg.gas.safety_margin(),
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                dplymt_cfg.sponsor.as_ref(),
+                &dplymt_cfg.retry,
+                &dplymt_cfg.network,
+            )
+            .await?;
+
+            log::info!(
+                "Status of RAMM library publication tx: {:?}",
+                response.status_ok()
+            );
+            crate::tx_status::check_tx_status(&response)?;
+            let publish_tx = TxReport::from_response(&response);
+            if let Some(explorer) = explorer {
+                explorer.log_tx("RAMM library publication", &publish_tx.digest);
+            }
+
+            // Get the package's ID from the tx response.
+            let package_id = crate::get_ramm_id_from_tx_response(response)?;
+            Ok((package_id, publish_tx, None))
+        }
+    }
+}
+
+/// Instead of signing and executing the deployment's next transactions, write them, unsigned, to
+/// `unsigned_dir`, so that they can be signed offline:
+/// * if the RAMM package is to be published or upgraded, only that tx is written, as the RAMMs
+///   can only be created once the package's ID is known, and
+/// * otherwise, the creation tx of each RAMM is written.
+///
+/// The population PTBs need the objects created by the RAMM creation txs, so they cannot be
+/// exported before those have been executed.
+async fn export_unsigned_txs(
+    sui_client: &SuiClient,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+    client_address: SuiAddress,
+    unsigned_dir: &Path,
+) -> Result<(), RAMMDeploymentError> {
+    let mut tx_paths: Vec<PathBuf> = Vec::new();
+    match (&dplymt_cfg.ramm_pkg_addr_or_path, dplymt_opts.upgrade_cap) {
+        (RAMMPkgAddrSrc::FromPkgPublication(path), upgrade_cap) => {
+            let (tx_name, tx_data) = match upgrade_cap {
+                Some(upgrade_cap) => {
+                    let package_id = crate::get_upgradable_pkg_id(sui_client, upgrade_cap).await?;
+                    let upgrade_tx = crate::upgrade_tx(
+                        sui_client,
+                        path.to_path_buf(),
+                        &dplymt_opts.pkg_build,
+                        &dplymt_cfg.named_addresses,
+                        client_address,
+                        package_id,
+                        upgrade_cap,
+                        dplymt_cfg.gas.publication_budget(),
+                        dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                        dplymt_cfg.sponsor.as_ref(),
+                    )
+                    .await?;
+                    ("upgrade", upgrade_tx)
+                }
+                None => {
+                    let publish_tx = crate::publish_tx(
+                        sui_client,
+                        path.to_path_buf(),
+                        &dplymt_opts.pkg_build,
+                        &dplymt_cfg.named_addresses,
+                        client_address,
+                        dplymt_cfg.gas.publication_budget(),
+                        dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                        dplymt_cfg.sponsor.as_ref(),
+                    )
+                    .await?;
+                    ("publish", publish_tx)
+                }
+            };
+            let tx_data =
+                crate::estimate_gas_budget(sui_client, tx_data, dplymt_cfg.gas.safety_margin())
+                    .await?;
+            tx_paths.push(crate::offline::write_unsigned_tx(
+                unsigned_dir,
+                tx_name,
+                &tx_data,
+            )?);
+        }
+        (RAMMPkgAddrSrc::FromTomlConfig(package_id), _) => {
+            for (ix, ramm_spec) in dplymt_cfg.ramms.iter().enumerate() {
+                let new_ramm_tx = crate::new_ramm_tx(
+                    sui_client,
+                    dplymt_cfg,
+                    ramm_spec,
+                    &client_address,
+                    *package_id,
+                    dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                )
+                .await?;
+                let new_ramm_tx = crate::estimate_gas_budget(
+                    sui_client,
+                    new_ramm_tx,
+                    dplymt_cfg.gas.safety_margin(),
+                )
+                .await?;
+                tx_paths.push(crate::offline::write_unsigned_tx(
+                    unsigned_dir,
+                    &format!("new_ramm_{}", ix + 1),
+                    &new_ramm_tx,
+                )?);
+            }
+        }
+    }
+
+    println!("Wrote the following unsigned transactions, to be signed by {client_address}:");
+    for tx_path in &tx_paths {
+        println!("\t{}", tx_path.display());
+    }
+    println!(
+        "Sign each of them offline, e.g. with `sui keytool sign --address {client_address} \
+        --data <contents of the file>`, then submit them, in order. Each tx is built against the \
+        current version of its gas coin, so txs sharing a gas coin must be exported, signed and \
+        submitted one at a time. Once a package is published, set its ID as \
+        `ramm_pkg_addr_or_path` and rerun the tool to continue the deployment."
+    );
+
+    Ok(())
+}
+
+/// Submit a transaction that was exported with `--unsigned-out` and signed offline, to the network
+/// the config targets, and show its outcome.
+pub async fn submit_signed_tx(
+    dplymt_cfg: &RAMMDeploymentConfig,
+    tx_path: &Path,
+    sig_path: &Path,
+) -> Result<(), RAMMDeploymentError> {
+    let (_, sui_client) =
+        crate::get_suibase_and_sui_client(&dplymt_cfg.target_env, &dplymt_cfg.network).await?;
+
+    let resp = crate::offline::submit_signed_tx(
+        &sui_client,
+        tx_path,
+        sig_path,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await?;
+
+    println!("Submitted signed transaction {}", resp.digest);
+    if let Some(effects) = &resp.effects {
+        println!("Status: {:?}", effects.status());
+        for created in effects.created() {
+            println!("\tCreated object {}", created.object_id());
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a Sui client for the config's target network, with the help of `suibase` for network
+/// selection - unless `--rpc-url` bypasses it, in which case no helper is returned - and fetch the
+/// address with which txs are signed.
+///
+/// Also returned is the block explorer for the network, if it has one, or if one was provided via
+/// the CLI.
+async fn connect(
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+) -> Result<(Option<Helper>, SuiClient, SuiAddress, Option<Explorer>), RAMMDeploymentError> {
+    let (suibase, sui_client) =
+        crate::get_suibase_and_sui_client(&dplymt_cfg.target_env, &dplymt_cfg.network).await?;
+
+    let client_address: SuiAddress = crate::get_signer_address(
+        suibase.as_ref(),
+        dplymt_opts.keystore_from_env,
+        dplymt_opts.signer.as_ref(),
+    )?;
+    log::info!(
+        "Using address {} for publishing and deployment.",
+        client_address
+    );
+
+    let network = crate::resolve_target_network(&dplymt_cfg.target_env)?;
+    let explorer = Explorer::for_network(&network, dplymt_opts.explorer_url.as_deref());
+
+    Ok((suibase, sui_client, client_address, explorer))
+}
+
+/// Log explorer links to the objects of each of the given RAMMs, numbered in order.
+fn log_ramm_links(explorer: &Explorer, all_ramm_ids: &[RAMMObjectIDs]) {
+    for (ix, ramm_ids) in all_ramm_ids.iter().enumerate() {
+        log::info!(
+            "Explorer links for RAMM #{}:\n{}",
+            ix + 1,
+            explorer.ramm_links(ramm_ids)
+        );
+    }
+}
+
+/// The `publish` stage: only publish (or upgrade) the RAMM package at the config's path, and return
+/// the resulting package's ID, for use by later `create` stages.
+///
+/// If `dplymt_opts.manifest_path` is set, a `DeploymentManifest` with the package, but no RAMMs,
+/// is written to it.
+pub async fn publish_stage(
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+) -> Result<ObjectID, RAMMDeploymentError> {
+    let path = match &dplymt_cfg.ramm_pkg_addr_or_path {
+        RAMMPkgAddrSrc::FromPkgPublication(path) => path,
+        RAMMPkgAddrSrc::FromTomlConfig(package_id) => {
+            return Err(RAMMDeploymentError::NothingToPublish(*package_id))
+        }
+    };
+
+    let (suibase, sui_client, client_address, explorer) = connect(dplymt_cfg, dplymt_opts).await?;
+    let keystore = crate::get_keystore(
+        suibase.as_ref(),
+        dplymt_opts.keystore_path.as_deref(),
+        dplymt_opts.keystore_from_env,
+        client_address,
+    )?;
+
+    let (package_id, publish_tx, upgraded_from) = util::in_phase(
+        "publish",
+        None,
+        publish_or_upgrade_pkg(
+            &sui_client,
+            &keystore,
+            dplymt_cfg,
+            dplymt_opts,
+            client_address,
+            path,
+            explorer.as_ref(),
+        ),
+    )
+    .await?;
+
+    if let Some(manifest_path) = &dplymt_opts.manifest_path {
+        DeploymentManifest::new(
+            dplymt_cfg,
+            package_id,
+            Some(publish_tx.digest),
+            upgraded_from,
+        )
+        .write_to_file(manifest_path)?;
+        log::info!("Wrote deployment manifest to {}", manifest_path.display());
+    }
+
+    Ok(package_id)
+}
+
+/// The `create` stage: create each of the config's RAMMs, from the already published package the
+/// config names, populate it unless `populate` is unset, and return the RAMMs' objects' IDs.
+///
+/// Unpopulated RAMMs can then be populated with the `populate` stage.
+pub async fn create_stage(
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+    populate: bool,
+) -> Result<Vec<RAMMObjectIDs>, RAMMDeploymentError> {
+    let ramm_package_id = match dplymt_cfg.ramm_pkg_addr_or_path {
+        RAMMPkgAddrSrc::FromTomlConfig(package_id) => package_id,
+        // Ruled out when the CLI arguments were read.
+        RAMMPkgAddrSrc::FromPkgPublication(_) => {
+            return Err(RAMMDeploymentError::StageRequiresPackageId(
+                "create".to_string(),
+            ))
+        }
+    };
+
+    let (suibase, sui_client, client_address, explorer) = connect(dplymt_cfg, dplymt_opts).await?;
+    crate::abi::check_package_abi(&sui_client, ramm_package_id, dplymt_cfg).await?;
+    let keystore = crate::get_keystore(
+        suibase.as_ref(),
+        dplymt_opts.keystore_path.as_deref(),
+        dplymt_opts.keystore_from_env,
+        client_address,
+    )?;
+
+    let mut all_ramm_ids = Vec::with_capacity(dplymt_cfg.ramms.len());
+    for (ix, ramm_spec) in dplymt_cfg.ramms.iter().enumerate() {
+        let new_ramm_tx_response = util::in_phase(
+            "create",
+            Some(ix + 1),
+            crate::new_ramm_tx_runner(
+                &sui_client,
+                dplymt_cfg,
+                ramm_spec,
+                &keystore,
+                &client_address,
+                ramm_package_id,
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+            ),
+        )
+        .await?;
+        log::info!(
+            "Status of RAMM #{} creation tx {}: {:?}",
+            ix + 1,
+            new_ramm_tx_response.digest,
+            new_ramm_tx_response.status_ok()
+        );
+        crate::tx_status::check_tx_status(&new_ramm_tx_response)?;
+        if let Some(explorer) = &explorer {
+            explorer.log_tx(
+                &format!("RAMM #{} creation", ix + 1),
+                &new_ramm_tx_response.digest,
+            );
+        }
+        RAMMEvents::from_tx_response(&new_ramm_tx_response)?.log();
+
+        let (ramm_obj_args, ramm_obj_ids) =
+            crate::build_ramm_obj_args(&sui_client, new_ramm_tx_response, client_address).await?;
+        log::info!("Created RAMM #{}:\n{}", ix + 1, ramm_obj_ids);
+
+        if !populate {
+            let transferred = transfer_ramm_caps(
+                &sui_client,
+                &keystore,
+                dplymt_cfg,
+                client_address,
+                ramm_obj_ids.admin_cap,
+                Some(ramm_obj_ids.new_asset_cap),
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                explorer.as_ref(),
+            )
+            .await?;
+            if transferred.is_some() && dplymt_cfg.new_asset_cap_recipient.is_some() {
+                log::warn!(
+                    "RAMM #{}'s new asset cap was transferred: only its recipient can now \
+                    populate it.",
+                    ix + 1
+                );
+            }
+        } else {
+            populate_ramm(
+                &sui_client,
+                &keystore,
+                dplymt_cfg,
+                dplymt_opts,
+                ramm_spec,
+                client_address,
+                ramm_package_id,
+                ramm_obj_args,
+                &ramm_obj_ids,
+                explorer.as_ref(),
+            )
+            .await?;
+            log::info!("RAMM #{} populated and initialized.", ix + 1);
+        }
+        all_ramm_ids.push(ramm_obj_ids);
+    }
+
+    if let Some(explorer) = &explorer {
+        log_ramm_links(explorer, &all_ramm_ids);
+    }
+
+    Ok(all_ramm_ids)
+}
+
+/// The `populate` stage: add the assets of the config's `ramm_index`-th RAMM to the already created
+/// RAMM with the given IDs, and initialize it.
+pub async fn populate_stage(
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+    ramm_index: usize,
+    ramm_ids: &RAMMObjectIDs,
+) -> Result<(), RAMMDeploymentError> {
+    let ramm_package_id = match dplymt_cfg.ramm_pkg_addr_or_path {
+        RAMMPkgAddrSrc::FromTomlConfig(package_id) => package_id,
+        // Ruled out when the CLI arguments were read.
+        RAMMPkgAddrSrc::FromPkgPublication(_) => {
+            return Err(RAMMDeploymentError::StageRequiresPackageId(
+                "populate".to_string(),
+            ))
+        }
+    };
+    let ramm_spec = &dplymt_cfg.ramms[ramm_index - 1];
+
+    let (suibase, sui_client, client_address, explorer) = connect(dplymt_cfg, dplymt_opts).await?;
+    crate::abi::check_package_abi(&sui_client, ramm_package_id, dplymt_cfg).await?;
+    let keystore = crate::get_keystore(
+        suibase.as_ref(),
+        dplymt_opts.keystore_path.as_deref(),
+        dplymt_opts.keystore_from_env,
+        client_address,
+    )?;
+
+    let ramm_obj_args = crate::ramm_obj_args_from_ids(&sui_client, ramm_ids).await?;
+    populate_ramm(
+        &sui_client,
+        &keystore,
+        dplymt_cfg,
+        dplymt_opts,
+        ramm_spec,
+        client_address,
+        ramm_package_id,
+        ramm_obj_args,
+        ramm_ids,
+        explorer.as_ref(),
+    )
+    .await
+}
+
+/// Add the assets of the given RAMM specification to an already created RAMM, initialize it, and
+/// check its on-chain state.
+///
+/// Unlike in a full deployment, a population PTB that does not succeed is an error.
+async fn populate_ramm(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+    ramm_spec: &RAMMSpec,
+    client_address: SuiAddress,
+    ramm_package_id: ObjectID,
+    ramm_obj_args: RAMMObjectArgs,
+    ramm_ids: &RAMMObjectIDs,
+    explorer: Option<&Explorer>,
+) -> Result<(), RAMMDeploymentError> {
+    let ramm_id = ramm_ids.ramm;
+    let aggr_obj_args =
+        crate::build_aggr_obj_args(sui_client, ramm_spec, &dplymt_cfg.network).await?;
+
+    let PopulationOutcome {
+        response: ptb_response,
+        events: ramm_events,
+        ..
+    } = util::in_phase(
+        "populate",
+        None,
+        crate::add_assets_and_init_ramm_runner(
+            sui_client,
+            keystore,
+            dplymt_cfg,
+            ramm_spec,
+            client_address,
+            ramm_package_id,
+            ramm_obj_args,
+            aggr_obj_args,
+            dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+        ),
+    )
+    .await?;
+    log::info!(
+        "RAMM {ramm_id} PTB response status: {:?}",
+        ptb_response.status_ok()
+    );
+
+    crate::tx_status::check_tx_status(&ptb_response)?;
+    if let Some(explorer) = explorer {
+        explorer.log_tx(&format!("RAMM {ramm_id} population"), &ptb_response.digest);
+    }
+    ramm_events.check_population(ramm_ids, ramm_spec)?;
+    crate::verify_ramm(sui_client, ramm_id, ramm_spec).await?;
+
+    transfer_ramm_caps(
+        sui_client,
+        keystore,
+        dplymt_cfg,
+        client_address,
+        ramm_obj_args.admin_cap.id(),
+        None,
+        dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+        explorer,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Transfer a RAMM's capabilities to the recipients the config names, if any, logging the
+/// transfer tx; see `cap_transfer::transfer_caps`.
+async fn transfer_ramm_caps(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    client_address: SuiAddress,
+    admin_cap: ObjectID,
+    new_asset_cap: Option<ObjectID>,
+    gas_coin: Option<ObjectID>,
+    explorer: Option<&Explorer>,
+) -> Result<Option<sui_types::digests::TransactionDigest>, RAMMDeploymentError> {
+    let digest = cap_transfer::transfer_caps(
+        sui_client,
+        keystore,
+        dplymt_cfg,
+        client_address,
+        admin_cap,
+        new_asset_cap,
+        gas_coin,
+    )
+    .await?;
+    if let (Some(digest), Some(explorer)) = (&digest, explorer) {
+        explorer.log_tx("Capability transfer", digest);
+    }
+    Ok(digest)
+}
+
+/// Read each asset's decimal places from the chain where the config omits them, and cross-check
+/// those it sets, failing on any mismatch if `strict` is set; see
+/// `coin_metadata::resolve_decimal_places`.
+async fn resolve_asset_decimals(
+    dplymt_cfg: &mut RAMMDeploymentConfig,
+    strict: bool,
+) -> Result<(), RAMMDeploymentError> {
+    let (_, sui_client) =
+        crate::get_suibase_and_sui_client(&dplymt_cfg.target_env, &dplymt_cfg.network).await?;
+
+    coin_metadata::resolve_decimal_places(&sui_client, dplymt_cfg, strict).await
+}
+
+/// Read the latest result of each asset's price feed, to be shown along with the config; see
+/// `oracle::read_oracle_prices`.
+async fn read_oracle_prices(
+    dplymt_cfg: &mut RAMMDeploymentConfig,
+    on_localnet: bool,
+) -> Result<(), RAMMDeploymentError> {
+    let (_, sui_client) =
+        crate::get_suibase_and_sui_client(&dplymt_cfg.target_env, &dplymt_cfg.network).await?;
+
+    oracle::read_oracle_prices(&sui_client, dplymt_cfg, !on_localnet).await;
+    Ok(())
+}
+
+/// Complete the config with what is read from the chain before it is shown for confirmation: each
+/// asset's decimal places, and the latest result of its price feed. Publishing the package alone
+/// involves no assets, so this does nothing for the `publish` stage.
+///
+/// With `dplymt_opts.require_fresh_oracles`, or `dplymt_opts.strict`, a stale price feed is an
+/// error, except on a localnet, whose feeds are test fixtures.
+pub async fn prepare_deployment(
+    dplymt_cfg: &mut RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+) -> Result<(), RAMMDeploymentError> {
+    if matches!(dplymt_opts.stage, DeploymentStage::Publish) {
+        return Ok(());
+    }
+    resolve_asset_decimals(dplymt_cfg, dplymt_opts.strict).await?;
+
+    let on_localnet = crate::resolve_target_network(&dplymt_cfg.target_env)
+        .is_ok_and(|network| network == LOCALNET);
+    read_oracle_prices(dplymt_cfg, on_localnet).await?;
+    let require_fresh_oracles = dplymt_opts.require_fresh_oracles || dplymt_opts.strict;
+    if require_fresh_oracles && on_localnet {
+        log::info!("Not checking that the price feeds are fresh, as the target is a localnet.");
+    } else if require_fresh_oracles {
+        oracle::check_oracles_fresh(dplymt_cfg)?;
+    }
+
+    Ok(())
+}
+
+/// The `inspect` stage: query the RAMM with the given ID, and show its on-chain state.
+pub async fn inspect_stage(
+    dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_id: ObjectID,
+) -> Result<(), RAMMDeploymentError> {
+    let (_, sui_client) =
+        crate::get_suibase_and_sui_client(&dplymt_cfg.target_env, &dplymt_cfg.network).await?;
+
+    let ramm_state = crate::inspect::read_ramm_state(&sui_client, ramm_id).await?;
+    println!("{}", ramm_state);
+
+    Ok(())
+}
+
+/// If a checkpoint path was provided, write the deployment's progress to it; it is also recorded
+/// with the Ctrl-C handler, if there is one.
+fn save_checkpoint(
+    checkpoint: &DeploymentCheckpoint,
+    dplymt_opts: &DeploymentOptions,
+    interrupt: Option<&InterruptHandler>,
+) -> Result<(), RAMMDeploymentError> {
+    if let Some(interrupt) = interrupt {
+        interrupt.record(checkpoint);
+    }
+    if let Some(checkpoint_path) = &dplymt_opts.checkpoint_path {
+        checkpoint.write_to_file(checkpoint_path)?;
+        log::info!(
+            "Wrote deployment checkpoint to {}",
+            checkpoint_path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Sum of the gas budgets of the deployment's txs that remain to be sent, given its checkpoint:
+/// those already recorded in it are not sent again.
+fn remaining_gas_budget(
+    dplymt_cfg: &RAMMDeploymentConfig,
+    checkpoint: &DeploymentCheckpoint,
+) -> u64 {
+    let gas = &dplymt_cfg.gas;
+    let publication = match (&dplymt_cfg.ramm_pkg_addr_or_path, checkpoint.package_id) {
+        (RAMMPkgAddrSrc::FromPkgPublication(_), None) => gas.publication_budget(),
+        _ => 0,
+    };
+    let ramms: u64 = (0..dplymt_cfg.ramms.len())
+        .map(|ix| match checkpoint.ramms.get(ix) {
+            None => gas.create_ramm_budget() + gas.populate_budget(),
+            Some(ramm) if ramm.populate_tx_digest.is_none() => gas.populate_budget(),
+            Some(_) => 0,
+        })
+        .sum();
+
+    publication + ramms
+}
+
+/// Deploy every RAMM specified in the given config, all from the same RAMM package.
+///
+/// The Sui client and the keystore are set up once, and shared by every RAMM's txs; the package is
+/// likewise published - or read from the checkpoint or the config - once, before the first RAMM is
+/// created, and never again for the others.
+///
+/// Returns a `DeploymentReport` of the package used, the RAMMs deployed, the txs that deployed them
+/// and the gas each used, and the deployment's duration.
+///
+/// If `dplymt_opts.dry_run` is set, every transaction that can be built is built and dry run, but
+/// none is executed; `Ok(None)` is then returned, as no objects will have been created. The
+/// population PTBs of RAMMs created, per the checkpoint, but not populated, are dev-inspected.
+///
+/// Otherwise, each population PTB is dev-inspected, and a preview of it printed, before it is
+/// signed.
+///
+/// If `dplymt_opts.manifest_path` is set, a `DeploymentManifest` is written to it once all RAMMs
+/// have been deployed, along with the report; it lists only those whose population PTB succeeded.
+/// The same goes for the
+/// TS SDK config and `dplymt_opts.ts_config_path`, and for the `.env` file and
+/// `dplymt_opts.env_out_path`.
+///
+/// If `dplymt_opts.checkpoint_path` is set, the deployment's progress is written to it after each
+/// stage; if `dplymt_opts.resume_path` is set, the stages recorded as complete in it are skipped.
+/// If `handle_interrupt` is set, Ctrl-C is handled while the deployment runs, as described in
+/// `InterruptHandler`, which exits the process.
+///
+/// Unless it is a dry run, the signer's balance is checked to cover the gas budgets of all the
+/// txs still to be sent before any of them is; `InsufficientGasBalance` is returned otherwise.
+///
+/// Unless it is a dry run, or `dplymt_opts.force` is set, `AlreadyDeployed` is returned if the
+/// lockfile at `dplymt_opts.lockfile_path` records a deployment of the same config; a deployment
+/// in which every RAMM is populated is recorded there once it completes.
+pub async fn ramm_deployment(
+    dplymt_cfg: RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+    handle_interrupt: bool,
+) -> Result<Option<DeploymentReport>, RAMMDeploymentError> {
+    let start = Instant::now();
+    let (suibase, sui_client, client_address, explorer) = connect(&dplymt_cfg, dplymt_opts).await?;
+
+    // Offline signing: the txs are built without any access to the keystore.
+    if let Some(unsigned_dir) = &dplymt_opts.unsigned_out_dir {
+        // A sponsored tx needs a second signature, which offline signing does not provide for.
+        if dplymt_cfg.sponsor.is_some() {
+            return Err(RAMMDeploymentError::SponsoredOfflineSigning);
+        }
+        export_unsigned_txs(
+            &sui_client,
+            &dplymt_cfg,
+            dplymt_opts,
+            client_address,
+            unsigned_dir,
+        )
+        .await?;
+        return Ok(None);
+    }
+
+    let keystore = crate::get_keystore(
+        suibase.as_ref(),
+        dplymt_opts.keystore_path.as_deref(),
+        dplymt_opts.keystore_from_env,
+        client_address,
+    )?;
+
+    /*
+    Deployment checkpoint: either resumed from a file, or started afresh
+    */
+    let network = crate::resolve_target_network(&dplymt_cfg.target_env)?;
+    let mut checkpoint = match &dplymt_opts.resume_path {
+        Some(resume_path) => {
+            let checkpoint = DeploymentCheckpoint::read_from_file(resume_path)?;
+            checkpoint.check_resumable(&network, dplymt_cfg.ramms.len())?;
+            log::info!(
+                "Resuming deployment from checkpoint {}",
+                resume_path.display()
+            );
+            checkpoint
+        }
+        None => DeploymentCheckpoint::new(network),
+    };
+    // On Ctrl-C, the completed stages are reported and the checkpoint written, before exiting.
+    let interrupt = handle_interrupt.then(|| {
+        InterruptHandler::install(
+            &checkpoint,
+            dplymt_cfg.ramms.len(),
+            dplymt_opts.checkpoint_path.clone(),
+        )
+    });
+
+    // Guard against deploying the same config twice, e.g. by running the tool again by accident.
+    let config_hash = lockfile::config_hash(&dplymt_cfg, &checkpoint.network);
+    let mut deployment_lock = DeploymentLock::read_from_file(&dplymt_opts.lockfile_path)?;
+
+    if !dplymt_opts.dry_run {
+        deployment_lock.check_not_deployed(&config_hash, dplymt_opts.force)?;
+        crate::check_gas_balance(
+            &sui_client,
+            dplymt_cfg.gas_owner(client_address),
+            remaining_gas_budget(&dplymt_cfg, &checkpoint),
+            &dplymt_cfg.network,
+        )
+        .await?;
+    }
+
+    // The publication or upgrade tx, if either is performed as part of this deployment.
+    let mut publish_tx = checkpoint.publish_tx_digest.map(TxReport::from_checkpoint);
+    // ID of the package that was upgraded, if the RAMM package is upgraded in this deployment.
+    let mut upgraded_from = checkpoint.upgraded_from;
+
+    /*
+    Obtaining the RAMM package ID, either from the TOML config or from publishing (or upgrading)
+    the package.
+    */
+    let ramm_package_id = match checkpoint.package_id {
+        Some(package_id) => {
+            log::info!("RAMM library package ID read from the checkpoint.");
+            package_id
+        }
+        None => match &dplymt_cfg.ramm_pkg_addr_or_path {
+            // RAMM package address provided in TOML
+            RAMMPkgAddrSrc::FromTomlConfig(addr) => {
+                log::info!("RAMM library package ID read from TOML config.");
+                *addr
+            }
+            // RAMM package must be published (or upgraded) to get a new package ID
+            RAMMPkgAddrSrc::FromPkgPublication(path) => {
+                if dplymt_opts.dry_run {
+                    dry_run_pkg_publication(
+                        &sui_client,
+                        &dplymt_cfg,
+                        dplymt_opts,
+                        client_address,
+                        path,
+                    )
+                    .await?;
+                    dry_run_without_package(&sui_client, &dplymt_cfg).await?;
+                    return Ok(None);
+                }
+                let (package_id, tx, previous_package_id) = util::in_phase(
+                    "publish",
+                    None,
+                    publish_or_upgrade_pkg(
+                        &sui_client,
+                        &keystore,
+                        &dplymt_cfg,
+                        dplymt_opts,
+                        client_address,
+                        path,
+                        explorer.as_ref(),
+                    ),
+                )
+                .await?;
+                publish_tx = Some(tx);
+                upgraded_from = previous_package_id;
+                package_id
+            }
+        },
+    };
+    log::info!("RAMM package ID: {ramm_package_id}");
+    // A package that was not just published may be of an incompatible version of the library.
+    crate::abi::check_package_abi(&sui_client, ramm_package_id, &dplymt_cfg).await?;
+
+    if dplymt_opts.dry_run {
+        // RAMMs already created, per the checkpoint, but not yet populated, exist on-chain: their
+        // population PTBs can be previewed, and the dry run stops there.
+        for (ix, ramm_checkpoint) in checkpoint.ramms.iter().enumerate() {
+            if ramm_checkpoint.populate_tx_digest.is_some() {
+                continue;
+            }
+            let ramm_spec = &dplymt_cfg.ramms[ix];
+            let ramm_obj_args =
+                crate::ramm_obj_args_from_ids(&sui_client, &ramm_checkpoint.obj_ids).await?;
+            let aggr_obj_args =
+                crate::build_aggr_obj_args(&sui_client, ramm_spec, &dplymt_cfg.network).await?;
+            let population_preview = crate::preview::preview_population(
+                &sui_client,
+                &dplymt_cfg,
+                ramm_spec,
+                client_address,
+                ramm_package_id,
+                ramm_obj_args,
+                aggr_obj_args,
+            )
+            .await?;
+            println!("RAMM #{}:\n{}", ix + 1, population_preview);
+        }
+
+        // RAMMs already created, per the checkpoint, would not be created again.
+        for (ix, ramm_spec) in dplymt_cfg
+            .ramms
+            .iter()
+            .enumerate()
+            .skip(checkpoint.ramms.len())
+        {
+            let new_ramm_tx = crate::new_ramm_tx(
+                &sui_client,
+                &dplymt_cfg,
+                ramm_spec,
+                &client_address,
+                ramm_package_id,
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+            )
+            .await?;
+            let new_ramm_tx = crate::estimate_gas_budget(
+                &sui_client,
+                new_ramm_tx,
+                dplymt_cfg.gas.safety_margin(),
+            )
+            .await?;
+            crate::log_would_execute(&format!("RAMM #{} creation", ix + 1), &new_ramm_tx);
+
+            crate::build_aggr_obj_args(&sui_client, ramm_spec, &dplymt_cfg.network).await?;
+            log::info!(
+                "Dry run: resolved the aggregator objects of all assets of RAMM #{}.",
+                ix + 1
+            );
+        }
+        log::info!(
+            "Dry run: the RAMMs and their capabilities only exist after the RAMM creation txs are \
+            executed, so the population PTBs would be built next, but cannot be simulated."
+        );
+        return Ok(None);
+    }
+
+    // The package stage is complete.
+    checkpoint.package_id = Some(ramm_package_id);
+    checkpoint.publish_tx_digest = publish_tx.as_ref().map(|tx| tx.digest);
+    checkpoint.upgraded_from = upgraded_from;
+    save_checkpoint(&checkpoint, dplymt_opts, interrupt.as_ref())?;
+
+    let mut manifest = DeploymentManifest::new(
+        &dplymt_cfg,
+        ramm_package_id,
+        checkpoint.publish_tx_digest,
+        upgraded_from,
+    );
+    let mut report = DeploymentReport::new(
+        dplymt_cfg.target_env.clone(),
+        ramm_package_id,
+        publish_tx,
+        upgraded_from,
+    );
+    let mut ts_config = TsSdkConfig::new(&dplymt_cfg, ramm_package_id);
+    let mut env_output = DotenvOutput::new(ramm_package_id);
+
+    // Every RAMM in the config is created from the same package, one after the other.
+    for (ix, ramm_spec) in dplymt_cfg.ramms.iter().enumerate() {
+        log::info!("Deploying RAMM #{} of {}.", ix + 1, dplymt_cfg.ramms.len());
+
+        let (ramm_obj_args, ramm_obj_ids, create_tx, mut ramm_events) = match checkpoint
+            .ramms
+            .get(ix)
+        {
+            // Deployed in full in an earlier run.
+            Some(RAMMCheckpoint {
+                obj_ids,
+                create_tx_digest,
+                populate_tx_digest: Some(populate_tx_digest),
+                ..
+            }) => {
+                log::info!(
+                    "RAMM #{} was already deployed, per the checkpoint; skipping it.",
+                    ix + 1
+                );
+                ts_config.add_ramm(ramm_spec, obj_ids);
+                env_output.add_ramm(obj_ids);
+                manifest.add_ramm(
+                    ramm_spec,
+                    obj_ids.clone(),
+                    *create_tx_digest,
+                    *populate_tx_digest,
+                    None,
+                );
+                report.ramms.push(RAMMReport {
+                    ramm: obj_ids.clone(),
+                    create_tx: TxReport::from_checkpoint(*create_tx_digest),
+                    populate_tx: TxReport::from_checkpoint(*populate_tx_digest),
+                    populated: true,
+                });
+                continue;
+            }
+            // Created, but not populated, in an earlier run.
+            Some(ramm_checkpoint) => {
+                log::info!(
+                    "RAMM #{} was already created, per the checkpoint; resuming with its \
+                    population.",
+                    ix + 1
+                );
+                let ramm_obj_args =
+                    crate::refresh_ramm_obj_args(&sui_client, ramm_checkpoint.obj_args).await?;
+                (
+                    ramm_obj_args,
+                    ramm_checkpoint.obj_ids.clone(),
+                    TxReport::from_checkpoint(ramm_checkpoint.create_tx_digest),
+                    RAMMEvents::default(),
+                )
+            }
+            None => {
+                // The response from the tx that creates the RAMM.
+                let new_ramm_tx_response = util::in_phase(
+                    "create",
+                    Some(ix + 1),
+                    crate::new_ramm_tx_runner(
+                        &sui_client,
+                        &dplymt_cfg,
+                        ramm_spec,
+                        &keystore,
+                        &client_address,
+                        ramm_package_id,
+                        dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                    ),
+                )
+                .await?;
+                log::info!(
+                    "Status of RAMM #{} creation tx: {:?}",
+                    ix + 1,
+                    new_ramm_tx_response.status_ok()
+                );
+                crate::tx_status::check_tx_status(&new_ramm_tx_response)?;
+                let create_tx = TxReport::from_response(&new_ramm_tx_response);
+                if let Some(explorer) = &explorer {
+                    explorer.log_tx(&format!("RAMM #{} creation", ix + 1), &create_tx.digest);
+                }
+                let creation_events = RAMMEvents::from_tx_response(&new_ramm_tx_response)?;
+                creation_events.log();
+
+                /*
+                The RAMM and its capabilities, extracted from the tx response, and represented as
+                ObjectArg`s, which is the SDK's representation of Move objects.
+
+                Also returned are the IDs of those objects, to display to the user at the end of the program.
+                */
+                let (ramm_obj_args, ramm_obj_ids) =
+                    crate::build_ramm_obj_args(&sui_client, new_ramm_tx_response, client_address)
+                        .await?;
+
+                checkpoint.ramms.push(RAMMCheckpoint {
+                    obj_args: ramm_obj_args,
+                    obj_ids: ramm_obj_ids.clone(),
+                    create_tx_digest: create_tx.digest,
+                    populate_tx_digest: None,
+                });
+                save_checkpoint(&checkpoint, dplymt_opts, interrupt.as_ref())?;
+
+                (ramm_obj_args, ramm_obj_ids, create_tx, creation_events)
+            }
+        };
+
+        /*
+        For each asset's aggregator address read from the TOML, use the `SuiClient`'s `ReadApi`
+        to query its `SuiObjectData`, and then use that to build an `ObjectArg` for use in the PTB.
+        */
+        let aggr_obj_args =
+            crate::build_aggr_obj_args(&sui_client, ramm_spec, &dplymt_cfg.network).await?;
+
+        /*
+        Construct the PTB that will populate and initialize the RAMM.
+        Note that a PTB requires a coin and the network's current gas price, which have to be obtained
+        as part of the process.
+        */
+        let PopulationOutcome {
+            response: ptb_response,
+            events: population_events,
+            tx: populate_tx,
+        } = util::in_phase(
+            "populate",
+            Some(ix + 1),
+            crate::add_assets_and_init_ramm_runner(
+                &sui_client,
+                &keystore,
+                &dplymt_cfg,
+                ramm_spec,
+                client_address,
+                ramm_package_id,
+                ramm_obj_args,
+                aggr_obj_args.clone(),
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+            ),
+        )
+        .await?;
+
+        log::info!(
+            "RAMM #{} PTB response status: {:?}",
+            ix + 1,
+            ptb_response.status_ok()
+        );
+
+        let ptb_status = crate::tx_status::check_tx_status(&ptb_response);
+        if let Some(explorer) = &explorer {
+            explorer.log_tx(
+                &format!("RAMM #{} population", ix + 1),
+                &ptb_response.digest,
+            );
+        }
+        let populated = ptb_status.is_ok();
+        if populated {
+            // Check the RAMM's deployment txs and on-chain state, rather than trusting the tx
+            // status alone.
+            ramm_events.extend(population_events);
+            ramm_events.check_population(&ramm_obj_ids, ramm_spec)?;
+            crate::verify_ramm(&sui_client, ramm_obj_ids.ramm, ramm_spec).await?;
+
+            checkpoint.ramms[ix].populate_tx_digest = Some(ptb_response.digest);
+            save_checkpoint(&checkpoint, dplymt_opts, interrupt.as_ref())?;
+
+            ts_config.add_ramm(ramm_spec, &ramm_obj_ids);
+            env_output.add_ramm(&ramm_obj_ids);
+
+            manifest.add_ramm(
+                ramm_spec,
+                ramm_obj_ids.clone(),
+                create_tx.digest,
+                ptb_response.digest,
+                Some(ramm_events),
+            );
+
+            // The smoke tests' outcomes are reported on their own: the RAMM is deployed either way.
+            // Liquidity is seeded first, as the smoke trade requires it.
+            if let Some(whole_units) = dplymt_opts.seed_liquidity {
+                let seed_result = smoke_test::seed_liquidity(
+                    &sui_client,
+                    &keystore,
+                    &dplymt_cfg,
+                    ramm_spec,
+                    client_address,
+                    ramm_package_id,
+                    ramm_obj_args,
+                    &aggr_obj_args,
+                    whole_units,
+                    dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                )
+                .await;
+                match seed_result {
+                    Ok(()) => println!("Liquidity seeding of RAMM #{}: passed.", ix + 1),
+                    Err(e) => println!("Liquidity seeding of RAMM #{}: FAILED - {}", ix + 1, e),
+                }
+            }
+            if dplymt_opts.smoke_test {
+                let smoke_result = smoke_test::smoke_trade(
+                    &sui_client,
+                    &keystore,
+                    &dplymt_cfg,
+                    ramm_spec,
+                    client_address,
+                    ramm_package_id,
+                    ramm_obj_args,
+                    &aggr_obj_args,
+                    dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                )
+                .await;
+                match smoke_result {
+                    Ok(()) => println!("Smoke trade against RAMM #{}: passed.", ix + 1),
+                    Err(e) => println!("Smoke trade against RAMM #{}: FAILED - {}", ix + 1, e),
+                }
+            }
+
+            // The admin cap is only handed over once the RAMM is populated and tested, as both
+            // require it to be held by the signer.
+            let transfer_digest = transfer_ramm_caps(
+                &sui_client,
+                &keystore,
+                &dplymt_cfg,
+                client_address,
+                ramm_obj_ids.admin_cap,
+                None,
+                dplymt_cfg.gas_coin(dplymt_opts.gas_coin),
+                explorer.as_ref(),
+            )
+            .await?;
+            if let (Some(recipient), Some(digest)) =
+                (dplymt_cfg.admin_cap_recipient, transfer_digest)
+            {
+                manifest.record_cap_transfer(recipient, digest);
+            }
+        } else if let Err(e) = ptb_status {
+            log::warn!(
+                "The population PTB of RAMM #{} did not succeed; it will not be in the manifest. \
+                {}",
+                ix + 1,
+                e
+            );
+        }
+
+        report.ramms.push(RAMMReport {
+            ramm: ramm_obj_ids,
+            create_tx,
+            populate_tx,
+            populated,
+        });
+    }
+
+    report.finish(start.elapsed());
+    manifest.report = Some(report.clone());
+
+    if let Some(manifest_path) = &dplymt_opts.manifest_path {
+        manifest.write_to_file(manifest_path)?;
+        log::info!("Wrote deployment manifest to {}", manifest_path.display());
+    }
+
+    if let Some(ts_config_path) = &dplymt_opts.ts_config_path {
+        ts_config.write_to_file(ts_config_path)?;
+        log::info!("Wrote TS SDK config to {}", ts_config_path.display());
+    }
+
+    if let Some(env_out_path) = &dplymt_opts.env_out_path {
+        env_output.write_to_file(env_out_path, dplymt_opts.env_append)?;
+        log::info!("Wrote deployed objects' IDs to {}", env_out_path.display());
+    }
+
+    // Only a deployment in which every RAMM was populated is recorded, so that one with failed
+    // populations can be retried without `--force`.
+    if report.ramms.iter().all(|ramm| ramm.populated) {
+        deployment_lock.record(
+            config_hash,
+            checkpoint.network.clone(),
+            ramm_package_id,
+            report.ramms.iter().map(|ramm| ramm.ramm.ramm).collect(),
+            dplymt_opts.manifest_path.clone(),
+        );
+        deployment_lock.write_to_file(&dplymt_opts.lockfile_path)?;
+        log::info!(
+            "Recorded the deployment in the lockfile {}",
+            dplymt_opts.lockfile_path.display()
+        );
+    }
+
+    if let Some(explorer) = &explorer {
+        let all_ramm_ids: Vec<RAMMObjectIDs> =
+            report.ramms.iter().map(|ramm| ramm.ramm.clone()).collect();
+        log_ramm_links(explorer, &all_ramm_ids);
+    }
+
+    Ok(Some(report))
+}
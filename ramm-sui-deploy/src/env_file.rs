@@ -0,0 +1,80 @@
+use std::{fs::OpenOptions, io::Write, path::Path};
+
+use sui_types::base_types::ObjectID;
+
+use crate::{error::RAMMDeploymentError, RAMMObjectIDs};
+
+/// The IDs of a deployment's objects, as `KEY=value` lines of a `.env`-style file, for consumption
+/// by shell scripts and CI pipelines.
+///
+/// The first RAMM's IDs are written as `RAMM_OBJECT_ID`, `RAMM_ADMIN_CAP` and
+/// `RAMM_NEW_ASSET_CAP`; those of the `n`-th RAMM, for `n > 1`, as e.g. `RAMM_2_OBJECT_ID`.
+#[derive(Debug)]
+pub struct DotenvOutput {
+    vars: Vec<(String, String)>,
+}
+
+impl DotenvOutput {
+    /// Start the file's variables with the ID of the RAMM package used in the deployment.
+    pub fn new(package_id: ObjectID) -> Self {
+        DotenvOutput {
+            vars: vec![("RAMM_PACKAGE_ID".to_string(), package_id.to_string())],
+        }
+    }
+
+    /// Add the IDs of a successfully deployed RAMM; RAMMs are numbered in the order in which
+    /// they are added.
+    pub fn add_ramm(&mut self, ramm: &RAMMObjectIDs) {
+        let ramm_count = (self.vars.len() - 1) / 3 + 1;
+        let prefix = match ramm_count {
+            1 => "RAMM".to_string(),
+            n => format!("RAMM_{n}"),
+        };
+
+        self.vars
+            .push((format!("{prefix}_OBJECT_ID"), ramm.ramm.to_string()));
+        self.vars
+            .push((format!("{prefix}_ADMIN_CAP"), ramm.admin_cap.to_string()));
+        self.vars.push((
+            format!("{prefix}_NEW_ASSET_CAP"),
+            ramm.new_asset_cap.to_string(),
+        ));
+    }
+
+    /// Write the variables to the file at the given path, one `KEY=value` line each.
+    ///
+    /// If `append` is set, they are appended to the file; otherwise, it is overwritten.
+    /// Either way, the file is created if it does not exist.
+    pub fn write_to_file(&self, path: &Path, append: bool) -> Result<(), RAMMDeploymentError> {
+        let contents: String = self
+            .vars
+            .iter()
+            .map(|(key, value)| format!("{key}={}\n", escape_dotenv_value(value)))
+            .collect();
+
+        OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(RAMMDeploymentError::EnvFileWriteError)
+    }
+}
+
+/// Quote a value for a `.env` file, unless it consists only of characters that no shell or
+/// dotenv parser treats specially - as object IDs do.
+///
+/// Quoted values are single-quoted, so nothing within them is expanded; single quotes within
+/// the value are themselves escaped as `'\''`.
+fn escape_dotenv_value(value: &str) -> String {
+    let is_plain = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "_-.:/".contains(c));
+    if is_plain {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
@@ -7,32 +7,154 @@ pub enum RAMMDeploymentError {
 
     #[error("Error reading the TOML config file into a `String`: {0}")]
     TOMLFileReadError(std::io::Error),
+    #[error("Error reading the TOML config from STDIN: {0}")]
+    TOMLStdinReadError(std::io::Error),
+    #[error(
+        "Reading the TOML config from STDIN requires `--yes`, as STDIN is then unavailable for \
+        confirmation."
+    )]
+    StdinConfigWithoutAssumeYes,
     #[error("Error parsing the executable's user input: {0}")]
     CLIError(clap::Error),
     #[error("No TOML config file provided - it is mandatory to provide one.")]
     NoTOMLConfigProvided,
     #[error("Failed to parse the TOML config data: {0}")]
     TOMLParseError(toml::de::Error),
+    #[error("Failed to parse the JSON config data: {0}")]
+    JSONParseError(serde_json::Error),
+    #[error("Failed to serialize the config into TOML: {0}")]
+    TOMLSerializationError(toml::ser::Error),
+    #[error("Failed to read an answer from STDIN: {0}")]
+    ConfigWizardInputError(std::io::Error),
+    #[error("STDIN was closed before the config was complete.")]
+    ConfigWizardInputEnded,
+    #[error("Refusing to overwrite {0}: pass the path of a file that does not exist yet.")]
+    GeneratedConfigExists(std::path::PathBuf),
+    #[error("Failed to write the generated config: {0}")]
+    GeneratedConfigWriteError(std::io::Error),
+    #[error("`{coin_type}` is not a valid coin type: {reason}")]
+    InvalidCoinType { coin_type: String, reason: String },
 
     #[error("The parsed TOML config has bad data.")]
     InvalidConfigData,
 
     #[error("Failed to fetch the Sui client's active address: {0}")]
     SuiClientActiveAddressError(suibase::Error),
+    #[error("Failed to resolve the Suibase address alias `{alias}`: {error}")]
+    SignerAliasError {
+        alias: String,
+        error: suibase::Error,
+    },
+    #[error(
+        "The keystore holds no signing key for the address {0}, with which txs were to be signed."
+    )]
+    SignerNotInKeystore(sui_types::base_types::SuiAddress),
     #[error("Failed to fetch Suibase workdir specified in config: {0}")]
     SuibaseWorkdirError(suibase::Error),
     #[error("Failed to get the RPC URL for the selected workdir: {0}")]
     RpcUrlSelectionError(suibase::Error),
+    #[error(
+        "The Suibase workdir `{workdir}` is missing or misconfigured: {reason}. Set it up with \
+        Suibase, e.g. `{workdir} start`, or target another environment."
+    )]
+    SuibaseWorkdirUnavailable { workdir: String, reason: String },
     #[error("Failed to build a Sui client from the selected RPC URL: {0}")]
     BuildSuiClientFromRpcUrlError(sui_sdk::error::Error),
+    #[error(
+        "--rpc-url can not be used with the `active` target environment, which only Suibase can \
+        resolve; set `target_env` to the network the URL serves."
+    )]
+    RpcUrlWithActiveEnv,
+    #[error(
+        "The gas budget for the {tx_name} tx is {budget} SUI, over the {ceiling} SUI that a \
+        deployment tx should ever need; check it for an extra zero."
+    )]
+    GasBudgetAboveCeiling {
+        tx_name: &'static str,
+        budget: String,
+        ceiling: String,
+    },
+    #[error("Failed to query the chain identifier of the full node at --rpc-url: {0}")]
+    ChainIdentifierQueryError(sui_sdk::error::Error),
+    #[error(
+        "The full node at `{url}` serves the chain `{chain_id}`, which is not the `{target_env}` \
+        target environment's."
+    )]
+    RpcUrlNetworkMismatch {
+        url: String,
+        target_env: String,
+        chain_id: String,
+    },
+    #[error("With --rpc-url, Suibase is bypassed, so {0}.")]
+    SuibaseBypassed(&'static str),
 
     #[error("Failed to fetch pathname of file-based keystore: {0}")]
     KeystorePathnameError(suibase::Error),
     #[error("Failed to open file-based keystore: {0}")]
     KeystoreOpenError(anyhow::Error),
+    #[error("Failed to read a private key from the `{var}` env variable: {reason}")]
+    EnvKeyError {
+        var: &'static str,
+        reason: &'static str,
+    },
 
     #[error("Failed to build the RAMM package: {0}")]
-    PkgBuildError(sui_types::error::SuiError),
+    PkgBuildError(String),
+    #[error(
+        "The Move compiler reported warnings while building the RAMM library, which --strict makes \
+        an error:\n{0}"
+    )]
+    PkgBuildWarnings(String),
+    #[error(
+        "The RAMM library path `{}` {reason}; it was resolved, from the working directory, to \
+        `{}`.",
+        path.display(),
+        resolved.display()
+    )]
+    InvalidPackagePath {
+        path: std::path::PathBuf,
+        resolved: std::path::PathBuf,
+        reason: String,
+    },
+
+    #[error(
+        "The RAMM library depends on unpublished packages: {}. Publish them first, or pass \
+        `--with-unpublished-deps` to bundle them, for development and testing only.",
+        .0.join(", ")
+    )]
+    UnpublishedDependencies(Vec<String>),
+    #[error(
+        "The config overrides named addresses that package `{package}` does not declare: {}. Its \
+        `Move.toml` declares: {}.",
+        names.join(", "),
+        declared.join(", ")
+    )]
+    UnknownNamedAddresses {
+        package: String,
+        names: Vec<String>,
+        declared: Vec<String>,
+    },
+    #[error("Failed to fetch the RAMM package's modules: {0}")]
+    AbiQueryError(sui_sdk::error::Error),
+    #[error(
+        "Package {package} is incompatible with the deployment: `{function}`: {reason}. It may be \
+        of an older version of the RAMM library."
+    )]
+    AbiMismatch {
+        package: sui_types::base_types::ObjectID,
+        function: String,
+        reason: String,
+    },
+    #[error("Failed to fetch the RAMM library's dependencies: {0}")]
+    DependencyQueryError(sui_sdk::error::Error),
+    #[error(
+        "Dependency {package} ({id}) of the RAMM library does not exist on the target network; the \
+        library may have been built for another network."
+    )]
+    MissingDependency {
+        package: String,
+        id: sui_types::base_types::ObjectID,
+    },
 
     #[error("Failed to build publication transaction for RAMM library: {0}")]
     PublishTxError(anyhow::Error),
@@ -40,21 +162,411 @@ pub enum RAMMDeploymentError {
     TxSignatureError(signature::Error),
     #[error("Failed to execute transaction block: {0}")]
     TxBlockExecutionError(sui_sdk::error::Error),
+    #[error("Timed out after {timeout_secs}s while {operation}.")]
+    NetworkTimeout {
+        operation: String,
+        timeout_secs: u64,
+    },
+    #[error("Failed to fetch the `UpgradeCap` object's data: {0}")]
+    UpgradeCapQueryError(sui_sdk::error::Error),
+    #[error("Object {0} is not an `UpgradeCap`, or its `package` field could not be read.")]
+    InvalidUpgradeCap(sui_types::base_types::ObjectID),
+    #[error("Failed to build upgrade transaction for RAMM library: {0}")]
+    UpgradeTxError(anyhow::Error),
+    #[error(
+        "Upgrading the RAMM package requires the config to provide a path to its library, not an \
+        address."
+    )]
+    UpgradeWithoutPackagePath,
     #[error("Failed to build RAMM creation tx: {0}")]
     NewRammTxError(anyhow::Error),
+    #[error("Failed to build the PTB that populates the RAMM: {0}")]
+    PtbBuildError(String),
+    #[error("Failed to dry run transaction to estimate its gas cost: {0}")]
+    DryRunQueryError(sui_sdk::error::Error),
+    #[error("Dry run of transaction failed, so it was not executed. Cause: {0}")]
+    DryRunFailed(String),
 
     #[error("Failed to fetch data for capability object: {0}")]
     CapObjectQueryError(sui_sdk::error::Error),
+    #[error("The type of object {0}, created in the RAMM creation tx, could not be read.")]
+    UnknownCapType(sui_types::base_types::ObjectID),
+    #[error("The RAMM creation tx was expected to create exactly 1 `{cap}`, but created {count}.")]
+    UnexpectedCapObjectCount { cap: &'static str, count: usize },
+    #[error("The response to the RAMM creation tx has no effects.")]
+    MissingRammTxEffects,
+    #[error("The RAMM creation tx did not create a shared object for the RAMM.")]
+    MissingSharedRammObject,
+    #[error("The RAMM object {id} was expected to be shared, but its owner is {owner:?}.")]
+    UnexpectedRammOwner {
+        id: sui_types::base_types::ObjectID,
+        owner: sui_types::object::Owner,
+    },
 
     #[error("Failed to fetch aggregator object data. Node response: {0}")]
     AggregatorDataQueryError(sui_sdk::error::Error),
-    #[error("There is an issue with Aggregator object data: {0}")]
-    AggregatorObjectResponseError(sui_types::error::SuiObjectResponseError),
-    #[error("The `owner` field of Aggregator object *must* be requested; it was `None`.")]
-    AggregatorObjectOwnerError,
+    #[error("There is an issue with the data of Aggregator {aggregator}: {error}")]
+    AggregatorObjectResponseError {
+        aggregator: sui_types::base_types::SuiAddress,
+        error: sui_types::error::SuiObjectResponseError,
+    },
+    #[error("The `owner` field of Aggregator {0} *must* be requested; it was `None`.")]
+    AggregatorObjectOwnerError(sui_types::base_types::SuiAddress),
+    #[error("Aggregator {0} is not a shared object, so it cannot be used by the RAMM.")]
+    AggregatorNotShared(sui_types::base_types::SuiAddress),
+    #[error(
+        "The latest result of Aggregator {aggregator} is {age_secs}s old, more than the \
+        {max_staleness_secs}s allowed: its feed may be dead."
+    )]
+    StaleOracle {
+        aggregator: sui_types::base_types::SuiAddress,
+        age_secs: u64,
+        max_staleness_secs: u64,
+    },
+    #[error("The latest result of Aggregator {aggregator} could not be read: {reason}")]
+    UnreadableOracle {
+        aggregator: sui_types::base_types::SuiAddress,
+        reason: String,
+    },
+    #[error("Could not parse the latest result of Aggregator {aggregator}: {reason}")]
+    AggregatorResultParseError {
+        aggregator: sui_types::base_types::SuiAddress,
+        reason: String,
+    },
 
     #[error("Failed to fetch coin object from active address to pay for PTB: {0}")]
     CoinQueryError(sui_sdk::error::Error),
     #[error("Failed to fetch gas price for the PTB: {0}")]
     GasPriceQueryError(sui_sdk::error::Error),
+    #[error(
+        "The gas price of {price} MIST is below the network's reference gas price of {reference} \
+        MIST."
+    )]
+    GasPriceBelowReference { price: u64, reference: u64 },
+    #[error(
+        "Address {0} owns no SUI coins to pay for gas with. Fund it, e.g. from a faucet, and retry."
+    )]
+    NoGasCoins(sui_types::base_types::SuiAddress),
+    #[error(
+        "Address {address} needs {required} MIST to cover gas, but only has {available} MIST."
+    )]
+    InsufficientGasBalance {
+        address: sui_types::base_types::SuiAddress,
+        required: u64,
+        available: u64,
+    },
+    #[error(
+        "The gas coin {0}, provided via the CLI or the `[sponsor]` table, does not exist on the \
+        network."
+    )]
+    GasCoinNotFound(sui_types::base_types::ObjectID),
+    #[error(
+        "The gas coin {coin}, provided via the CLI or the `[sponsor]` table, is not a SUI coin \
+        owned by {signer}, which pays for the gas."
+    )]
+    GasCoinNotOwned {
+        coin: sui_types::base_types::ObjectID,
+        signer: sui_types::base_types::SuiAddress,
+    },
+    #[error("--gas-coin can not be used with a `[sponsor]`, whose `gas_coin` pays for every tx.")]
+    SponsorWithGasCoin,
+    #[error(
+        "Sponsored txs can not be exported for offline signing, which only provides for the \
+        signer's signature."
+    )]
+    SponsoredOfflineSigning,
+    #[error("Failed to build the tx that merges coins to pay for the PTB's gas: {0}")]
+    MergeCoinsTxError(anyhow::Error),
+
+    #[error("Failed to serialize the deployment manifest into JSON: {0}")]
+    ManifestSerializationError(serde_json::Error),
+    #[error("Failed to write the deployment manifest to its file: {0}")]
+    ManifestWriteError(std::io::Error),
+    #[error("Failed to serialize the TS SDK config into JSON: {0}")]
+    TsConfigSerializationError(serde_json::Error),
+    #[error("Failed to write the TS SDK config to its file: {0}")]
+    TsConfigWriteError(std::io::Error),
+
+    #[error("Failed to serialize the transaction's data: {0}")]
+    TxSerializationError(bcs::Error),
+    #[error("Failed to write the unsigned transaction to its file: {0}")]
+    UnsignedTxWriteError(std::io::Error),
+    #[error("Failed to read the unsigned transaction file: {0}")]
+    UnsignedTxReadError(std::io::Error),
+    #[error("Failed to parse the unsigned transaction file: {0}")]
+    UnsignedTxParseError(String),
+    #[error("Failed to read the signature file: {0}")]
+    SignatureReadError(std::io::Error),
+    #[error("Failed to parse the signature: {0}")]
+    InvalidSignature(String),
+    #[error("The signature is not the transaction's sender's ({signer}) signature over it")]
+    SignatureMismatch {
+        signer: sui_types::base_types::SuiAddress,
+    },
+
+    #[error("Failed to write the deployment's IDs to the `.env` file: {0}")]
+    EnvFileWriteError(std::io::Error),
+
+    #[error("`{flag}` only applies to a full deployment, not to the `{stage}` subcommand")]
+    OptionRequiresFullDeployment { flag: &'static str, stage: String },
+    #[error(
+        "The `{0}` subcommand requires an already published package: set its ID as \
+        `ramm_pkg_addr_or_path`, or pass `--package-id`"
+    )]
+    StageRequiresPackageId(String),
+    #[error("RAMM index {index} is out of range: the config specifies {count} RAMMs")]
+    RAMMIndexOutOfRange { index: usize, count: usize },
+    #[error("Object {0} does not exist on the target network")]
+    ObjectNotFound(sui_types::base_types::ObjectID),
+    #[error(
+        "The deployment was not assented to: set `assume_yes` in its options to deploy the config \
+        without it being shown."
+    )]
+    DeploymentNotAssented,
+    #[error(
+        "Only a full deployment can be run with `deploy`; run the other stages with their own \
+        functions in the `deployment` module."
+    )]
+    DeployRequiresFullStage,
+    #[error("The config names the already published package {0}: there is nothing to publish")]
+    NothingToPublish(sui_types::base_types::ObjectID),
+    #[error("Failed to parse the RAMM's on-chain state: {0}")]
+    RAMMStateParseError(String),
+
+    #[error("Failed to read the deployment checkpoint file: {0}")]
+    CheckpointReadError(std::io::Error),
+    #[error("Failed to parse the deployment checkpoint: {0}")]
+    CheckpointParseError(serde_json::Error),
+    #[error("Failed to serialize the deployment checkpoint into JSON: {0}")]
+    CheckpointSerializationError(serde_json::Error),
+    #[error("Failed to write the deployment checkpoint to its file: {0}")]
+    CheckpointWriteError(std::io::Error),
+    #[error(
+        "The checkpoint is for a deployment to `{checkpoint}`, but the current network is \
+        `{current}`."
+    )]
+    CheckpointNetworkMismatch { checkpoint: String, current: String },
+    #[error("The checkpoint records {checkpoint} RAMMs, but the config only specifies {config}.")]
+    CheckpointConfigMismatch { checkpoint: usize, config: usize },
+    #[error("Object {0}, recorded in the checkpoint, could not be found on the network.")]
+    CheckpointObjectNotFound(sui_types::base_types::ObjectID),
+
+    #[error("Failed to read the deployment lockfile: {0}")]
+    LockfileReadError(std::io::Error),
+    #[error("Failed to parse the deployment lockfile: {0}")]
+    LockfileParseError(serde_json::Error),
+    #[error("Failed to serialize the deployment report into JSON: {0}")]
+    ReportSerializationError(serde_json::Error),
+    #[error("Failed to serialize the deployment lockfile into JSON: {0}")]
+    LockfileSerializationError(serde_json::Error),
+    #[error("Failed to serialize the config's JSON Schema: {0}")]
+    SchemaSerializationError(serde_json::Error),
+    #[error("Failed to write the deployment lockfile: {0}")]
+    LockfileWriteError(std::io::Error),
+    #[error(
+        "This config (hash {config_hash}) was already deployed to `{network}`; see {prior}. Pass \
+        `--force` to deploy it again."
+    )]
+    AlreadyDeployed {
+        config_hash: String,
+        network: String,
+        prior: String,
+    },
+
+    #[error("Smoke tests require the config to have a `[faucet]` table.")]
+    SmokeTestWithoutFaucet,
+    #[error("Failed to build the tx that mints test coins from the faucet: {0}")]
+    FaucetMintTxError(anyhow::Error),
+    #[error("The faucet mint tx did not create a coin owned by the signer.")]
+    MintedCoinNotFound,
+    #[error("Smoke tests only support RAMMs with {supported:?} assets; this one has {count}.")]
+    SmokeTestUnsupportedAssetCount { count: u8, supported: Vec<u8> },
+    #[error("Smoke test tx failed: {0}")]
+    SmokeTestFailed(String),
+    #[error("Minting initial liquidity requires the config to have a `[faucet]` table.")]
+    InitialLiquidityWithoutFaucet,
+    #[error(
+        "The initial liquidity coin {coin} is not a coin of {asset} owned by the signer {owner}."
+    )]
+    InitialLiquidityCoinNotFound {
+        coin: sui_types::base_types::ObjectID,
+        asset: String,
+        owner: sui_types::base_types::SuiAddress,
+    },
+    #[error(
+        "The initial liquidity coin {coin} holds {balance}, less than the {amount} to deposit."
+    )]
+    InitialLiquidityCoinTooSmall {
+        coin: sui_types::base_types::ObjectID,
+        balance: u64,
+        amount: u64,
+    },
+    #[error(
+        "Depositing {amount} of {asset} as initial liquidity, in tx {digest}, minted no LP tokens."
+    )]
+    NoInitialLPTokens {
+        asset: String,
+        amount: u64,
+        digest: sui_types::digests::TransactionDigest,
+    },
+
+    #[error("The prebuilt bytecode directory {} is invalid: {reason}", dir.display())]
+    BytecodeDirError {
+        dir: std::path::PathBuf,
+        reason: String,
+    },
+
+    #[error("The response to publication tx {0} contains no published package")]
+    PackageIdNotFound(sui_types::digests::TransactionDigest),
+    #[error("Failed to dev-inspect the population PTB: {0}")]
+    DevInspectQueryError(sui_sdk::error::Error),
+    #[error("Dev-inspecting the population PTB reported a failure: {0}")]
+    DevInspectFailed(String),
+    #[error("Tx {digest} failed: {error}")]
+    TxExecutionFailed {
+        digest: sui_types::digests::TransactionDigest,
+        error: String,
+    },
+    #[error("Move abort in module `{module}` with code {code}: {message}")]
+    MoveAbort {
+        module: String,
+        code: u64,
+        message: String,
+    },
+
+    #[error("Failed to fetch an asset's coin metadata: {0}")]
+    CoinMetadataQueryError(sui_sdk::error::Error),
+    #[error(
+        "Asset {0} sets no `decimal_places`, and has no on-chain CoinMetadata to read them from"
+    )]
+    CoinMetadataNotFound(String),
+    #[error(
+        "{0} asset(s) are configured with decimal places that differ from their CoinMetadata's"
+    )]
+    DecimalPlacesMismatch(usize),
+    #[error("The decimal places of asset {0} have not been resolved")]
+    DecimalPlacesUnresolved(String),
+
+    #[error("Failed to parse the RAMM event {event_type}: {error}")]
+    EventParseError {
+        event_type: String,
+        error: bcs::Error,
+    },
+
+    #[error(
+        "The effects of the cap transfer tx do not show capability {cap} as owned by {recipient}"
+    )]
+    CapTransferNotVerified {
+        cap: sui_types::base_types::ObjectID,
+        recipient: sui_types::base_types::SuiAddress,
+    },
+
+    #[error("Failed to fetch the created RAMM's object data: {0}")]
+    RAMMObjectQueryError(sui_sdk::error::Error),
+    #[error("Post-deployment verification of the RAMM failed: {0}")]
+    VerificationFailed(String),
+}
+
+/// A single problem found in a deployment config by `RAMMDeploymentConfig::validate_detailed`.
+#[derive(Debug, Error)]
+pub enum ConfigValidationError {
+    #[error("The config specifies no RAMMs.")]
+    NoRAMMs,
+    #[error("The config has no target environment.")]
+    MissingTargetEnv,
+    #[error(
+        "The config specifies neither the ID of a published RAMM package, nor the path of one to \
+        publish."
+    )]
+    MissingPackage,
+    #[error(
+        "The target environment is `{0}`; it must be `active`, `localnet`, `devnet`, `testnet` or \
+        `mainnet`."
+    )]
+    InvalidTargetEnv(String),
+    #[error("`{0}` must not be the zero address, `0x0`.")]
+    ZeroCapRecipient(&'static str),
+    #[error("RAMM #{ramm}: {error}")]
+    InRAMM {
+        /// Position of the RAMM in the config, starting at 1.
+        ramm: usize,
+        error: Box<ConfigValidationError>,
+    },
+
+    #[error("A RAMM must have at least one asset.")]
+    NoAssets,
+    #[error("The RAMM has no fee collection address.")]
+    MissingFeeCollectionAddress,
+    #[error("Asset type `{asset_type}` is invalid: {reason}.")]
+    InvalidAssetType { asset_type: String, reason: String },
+    #[error("The asset count is {count}, but {assets} assets were specified.")]
+    AssetCountMismatch { count: u8, assets: usize },
+    #[error(
+        "{0} assets were specified, more than the {max} a RAMM's asset count can be.", max = u8::MAX
+    )]
+    TooManyAssets(usize),
+    #[error(
+        "The RAMM has {count} assets, but the RAMM package only supports RAMMs with {supported:?} \
+        assets."
+    )]
+    UnsupportedAssetCount { count: u8, supported: Vec<u8> },
+    #[error("The fee collection address must not be the zero address, `0x0`.")]
+    ZeroFeeCollectionAddress,
+    #[error(
+        "Asset {asset_type} has {decimal_places} decimal places; it must have between {min} and \
+        {max}."
+    )]
+    DecimalPlacesOutOfRange {
+        asset_type: String,
+        decimal_places: u8,
+        min: u8,
+        max: u8,
+    },
+    #[error(
+        "Asset {0} has a minimum trade amount of 0, which disables the minimum trade protection."
+    )]
+    ZeroMinimumTradeAmount(String),
+    #[error("The price feed at {0} is used by more than one asset.")]
+    DuplicatePriceFeed(sui_types::base_types::SuiAddress),
+    #[error("Asset type {0} appears more than once in the RAMM.")]
+    DuplicateAssetType(String),
+
+    #[error(
+        "The `{table}` table's `{name}` budget is {budget} MIST; it must be between 1 and {max}."
+    )]
+    GasBudgetOutOfRange {
+        table: &'static str,
+        name: &'static str,
+        budget: u64,
+        max: u64,
+    },
+    #[error("The gas price, `price`, must be at least 1 MIST.")]
+    ZeroGasPrice,
+    #[error("The gas safety margin, `safety_margin`, must be a number.")]
+    NaNGasSafetyMargin,
+    #[error("The retry policy's `max_attempts` must be at least 1.")]
+    ZeroRetryAttempts,
+    #[error("The retry policy's `multiplier` must be a finite number, at least 1.0.")]
+    RetryMultiplierBelowOne,
+    #[error("The network timeout, `timeout_secs`, must be at least 1 second.")]
+    ZeroNetworkTimeout,
+    #[error("The network's `max_concurrency` must be at least 1.")]
+    ZeroMaxConcurrency,
+    #[error("The network's `poll_interval_ms` must be at least 1 millisecond.")]
+    ZeroPollInterval,
+    #[error("The network's `max_wait_secs` must be at least 1 second.")]
+    ZeroMaxWait,
+    #[error("The oracles' `max_staleness_secs` must be at least 1 second.")]
+    ZeroOracleStaleness,
+    #[error(
+        "The `[initial_liquidity]` table has an entry for {0}, which is not an asset of any RAMM."
+    )]
+    UnknownInitialLiquidityAsset(String),
+    #[error("The initial liquidity of asset {0} is 0; it must be at least 1.")]
+    ZeroInitialLiquidity(String),
+    #[error(
+        "Initial liquidity without a `coin` is minted from the faucet, so the config must have a \
+        `[faucet]` table."
+    )]
+    InitialLiquidityWithoutFaucet,
 }
@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use move_core_types::language_storage::StructTag;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sui_json_rpc_types::{ObjectChange, SuiEvent, SuiTransactionBlockResponse};
+use sui_types::{
+    base_types::{ObjectID, SuiAddress},
+    TypeTag,
+};
+
+use crate::{error::RAMMDeploymentError, types::RAMMSpec, RAMMObjectIDs, RAMM_MODULE_NAME};
+
+/// Name of the RAMM library's module in which its events are declared.
+const EVENTS_MODULE: &str = "events";
+
+/// Emitted by the RAMM interface's `liquidity_deposit_<N>`, for each deposit of an asset into a
+/// RAMM; the population PTB makes one for each asset with initial liquidity.
+///
+/// Mirrors the Move struct `ramm_sui::events::LiquidityDepositEvent`, from whose BCS it is
+/// deserialized.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LiquidityDepositEvent {
+    pub ramm_id: ObjectID,
+    pub trader: SuiAddress,
+    /// The asset's `TypeName`, i.e. its type with a full-length address, and without `0x`.
+    pub token_in: String,
+    pub amount_in: u64,
+    /// Amount of LP tokens minted for the deposit.
+    pub lpt: u64,
+}
+
+/// What the txs deploying a RAMM record of it: the objects its creation made, whether its
+/// population consumed its new asset capability - which `initialize_ramm` does - and the
+/// liquidity deposits the population made.
+///
+/// The RAMM library emits no events of its own on creation or initialization, so these are read
+/// from the txs' object changes; only the deposits are read from events.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RAMMEvents {
+    /// The RAMM and capabilities created by the `new_ramm` tx.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RAMMObjectIDs>,
+    /// The new asset capability deleted by the population PTB, on the RAMM's initialization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_asset_cap_deleted: Option<ObjectID>,
+    pub deposits: Vec<LiquidityDepositEvent>,
+}
+
+impl RAMMEvents {
+    /// Read what a RAMM deployment tx records from its response, which must have been requested
+    /// with its object changes and events; other objects and events are ignored.
+    pub fn from_tx_response(
+        response: &SuiTransactionBlockResponse,
+    ) -> Result<Self, RAMMDeploymentError> {
+        let mut ramm_events = RAMMEvents::default();
+
+        let (mut ramm, mut admin_cap, mut new_asset_cap) = (None, None, None);
+        let object_changes = response.object_changes.iter().flatten();
+        for object_change in object_changes {
+            match object_change {
+                ObjectChange::Created {
+                    object_type,
+                    object_id,
+                    ..
+                } => match ramm_type_name(object_type) {
+                    Some("RAMM") => ramm = Some(*object_id),
+                    Some("RAMMAdminCap") => admin_cap = Some(*object_id),
+                    Some("RAMMNewAssetCap") => new_asset_cap = Some(*object_id),
+                    _ => {}
+                },
+                ObjectChange::Deleted {
+                    object_type,
+                    object_id,
+                    ..
+                } if ramm_type_name(object_type) == Some("RAMMNewAssetCap") => {
+                    ramm_events.new_asset_cap_deleted = Some(*object_id);
+                }
+                _ => {}
+            }
+        }
+        if let (Some(ramm), Some(admin_cap), Some(new_asset_cap)) = (ramm, admin_cap, new_asset_cap)
+        {
+            ramm_events.created = Some(RAMMObjectIDs {
+                ramm,
+                admin_cap,
+                new_asset_cap,
+            });
+        }
+
+        let events = response.events.iter().flat_map(|events| events.data.iter());
+        for event in events.filter(|event| event.type_.module.as_str() == EVENTS_MODULE) {
+            if event.type_.name.as_str() == "LiquidityDepositEvent" {
+                ramm_events.deposits.push(parse_event(event)?);
+            }
+        }
+
+        Ok(ramm_events)
+    }
+
+    /// Append what a later tx of the same RAMM's deployment, i.e. its population PTB, records to
+    /// this.
+    pub fn extend(&mut self, later: RAMMEvents) {
+        self.created = self.created.take().or(later.created);
+        self.new_asset_cap_deleted = self
+            .new_asset_cap_deleted
+            .take()
+            .or(later.new_asset_cap_deleted);
+        self.deposits.extend(later.deposits);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.created.is_none() && self.new_asset_cap_deleted.is_none() && self.deposits.is_empty()
+    }
+
+    /// Log each of the records, at `Info` level.
+    pub fn log(&self) {
+        if let Some(created) = &self.created {
+            log::info!(
+                "Created RAMM {}, with admin cap {} and new asset cap {}.",
+                created.ramm,
+                created.admin_cap,
+                created.new_asset_cap
+            );
+        }
+        if let Some(new_asset_cap) = &self.new_asset_cap_deleted {
+            log::info!("Deleted new asset cap {new_asset_cap}, on the RAMM's initialization.");
+        }
+        for deposit in &self.deposits {
+            log::info!(
+                "Event: {} of asset {} deposited into RAMM {} by {}, for {} LP tokens.",
+                deposit.amount_in,
+                deposit.token_in,
+                deposit.ramm_id,
+                deposit.trader,
+                deposit.lpt
+            );
+        }
+    }
+
+    /// Check that the records of the RAMM with the given IDs confirm its deployment: that its
+    /// creation, if recorded - i.e. not made in an earlier run - made those objects, that its
+    /// population consumed its new asset capability, i.e. initialized it, and that each of its
+    /// deposits was of one of the specification's assets into it, and minted LP tokens.
+    ///
+    /// Each discrepancy is logged, so that all of them are reported at once, before
+    /// `VerificationFailed` is returned.
+    pub fn check_population(
+        &self,
+        ramm_ids: &RAMMObjectIDs,
+        ramm_spec: &RAMMSpec,
+    ) -> Result<(), RAMMDeploymentError> {
+        let ramm_id = ramm_ids.ramm;
+        let mut valid = true;
+        if let Some(created) = &self.created {
+            if created.ramm != ramm_id
+                || created.admin_cap != ramm_ids.admin_cap
+                || created.new_asset_cap != ramm_ids.new_asset_cap
+            {
+                log::error!("RAMM {ramm_id}'s creation tx created different objects: {created:?}");
+                valid = false;
+            }
+        }
+        if self.new_asset_cap_deleted != Some(ramm_ids.new_asset_cap) {
+            log::error!(
+                "The population of RAMM {ramm_id} did not delete its new asset cap {}, as its \
+                initialization does.",
+                ramm_ids.new_asset_cap
+            );
+            valid = false;
+        }
+        for deposit in &self.deposits {
+            let token_in = TypeTag::from_str(&format!("0x{}", deposit.token_in)).ok();
+            let is_spec_asset = ramm_spec
+                .assets
+                .iter()
+                .any(|asset| token_in.as_ref() == Some(&asset.asset_type));
+            if deposit.ramm_id != ramm_id || !is_spec_asset || deposit.lpt == 0 {
+                log::error!(
+                    "A deposit made by the population of RAMM {ramm_id} does not match its \
+                    specification: {deposit:?}"
+                );
+                valid = false;
+            }
+        }
+
+        if !valid {
+            return Err(RAMMDeploymentError::VerificationFailed(format!(
+                "the deployment txs of RAMM {ramm_id} do not match its specification"
+            )));
+        }
+        log::info!(
+            "The deployment txs of RAMM {ramm_id} confirm that it was initialized, with {} \
+            deposit(s).",
+            self.deposits.len()
+        );
+        Ok(())
+    }
+}
+
+/// The name of the given type, if it is declared in the RAMM library's `ramm` module.
+fn ramm_type_name(object_type: &StructTag) -> Option<&str> {
+    (object_type.module.as_ident_str() == RAMM_MODULE_NAME).then(|| object_type.name.as_str())
+}
+
+/// Deserialize a RAMM event's Move struct from its BCS.
+fn parse_event<T: DeserializeOwned>(event: &SuiEvent) -> Result<T, RAMMDeploymentError> {
+    bcs::from_bytes(&event.bcs).map_err(|error| RAMMDeploymentError::EventParseError {
+        event_type: event.type_.to_string(),
+        error,
+    })
+}
@@ -0,0 +1,58 @@
+use colored::Colorize;
+use sui_types::{base_types::ObjectID, digests::TransactionDigest};
+
+use crate::RAMMObjectIDs;
+
+/// Base URL of the Suiscan explorer, to which the network's name is appended.
+const SUISCAN_BASE_URL: &str = "https://suiscan.xyz";
+
+/// A block explorer, used to log clickable links to the deployment's txs and objects.
+#[derive(Debug)]
+pub struct Explorer {
+    /// URL to which `/tx/<digest>` and `/object/<id>` are appended, without a trailing `/`.
+    base_url: String,
+}
+
+impl Explorer {
+    /// The explorer for the given Suibase workdir, e.g. `testnet`: `base_url` if it was provided,
+    /// or Suiscan otherwise.
+    ///
+    /// `None` is returned for networks without a public explorer, such as `localnet`, unless
+    /// `base_url` was provided.
+    pub fn for_network(network: &str, base_url: Option<&str>) -> Option<Self> {
+        let base_url = match (base_url, network) {
+            (Some(base_url), _) => base_url.trim_end_matches('/').to_string(),
+            (None, "mainnet" | "testnet" | "devnet") => format!("{SUISCAN_BASE_URL}/{network}"),
+            (None, _) => return None,
+        };
+
+        Some(Explorer { base_url })
+    }
+
+    pub fn tx_url(&self, digest: &TransactionDigest) -> String {
+        format!("{}/tx/{digest}", self.base_url)
+    }
+
+    pub fn object_url(&self, id: &ObjectID) -> String {
+        format!("{}/object/{id}", self.base_url)
+    }
+
+    /// Log the link to the given tx, at `Info` level.
+    pub fn log_tx(&self, tx_name: &str, digest: &TransactionDigest) {
+        log::info!("{tx_name} tx: {}", self.tx_url(digest));
+    }
+
+    /// Links to the objects of a deployed RAMM, one per line, in the style of `RAMMObjectIDs`'
+    /// `Display`.
+    pub fn ramm_links(&self, ramm_ids: &RAMMObjectIDs) -> String {
+        format!(
+            "{}: {}\n{}: {}\n{}: {}",
+            "RAMM".red(),
+            self.object_url(&ramm_ids.ramm),
+            "Admin Cap".green(),
+            self.object_url(&ramm_ids.admin_cap),
+            "New Asset Cap".bright_blue(),
+            self.object_url(&ramm_ids.new_asset_cap),
+        )
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use colored::Colorize;
+use futures::{stream, StreamExt, TryStreamExt};
+use sui_types::{base_types::SuiAddress, TypeTag};
+
+use crate::{
+    coin_metadata,
+    error::{ConfigValidationError, RAMMDeploymentError},
+    get_suibase_and_sui_client,
+    types::{
+        parse_coin_type, plausible_minimum_trade_amount, AssetConfig, GasConfig, NetworkConfig,
+        OracleConfig, OracleProvider, RAMMDeploymentConfig, RAMMPkgAddrSrc, RAMMSpec, RetryPolicy,
+        TARGET_ENVS,
+    },
+};
+
+/// The package source suggested by the wizard: the RAMM library's path, relative to the
+/// deployment tool's directory.
+const DEFAULT_PKG_ADDR_OR_PATH: &str = "../ramm-sui";
+
+/// What the `generate-config` subcommand was asked to do, as read from the CLI.
+#[derive(Debug)]
+pub struct GenerateConfigOptions {
+    /// Path of the file the config is written to; it must not exist yet.
+    pub out_path: PathBuf,
+    /// If present, the coin types from which to scaffold the config's assets, without prompting
+    /// the user; see `scaffold_from_coins`.
+    pub from_coins: Option<Vec<String>>,
+    /// The target environment; prompted for if absent, and required with `from_coins`.
+    pub target_env: Option<String>,
+    /// The fee collection address; prompted for if absent, and required with `from_coins`.
+    pub fee_collection_address: Option<SuiAddress>,
+    /// The RAMM package's ID, or the path of its library; prompted for if absent, defaulting to
+    /// `DEFAULT_PKG_ADDR_OR_PATH` with `from_coins`.
+    pub ramm_pkg_addr_or_path: Option<String>,
+}
+
+/// Generate a deployment config, and write it, as TOML, to the options' `out_path`.
+///
+/// Unless `from_coins` is given, the user is prompted for each of the config's fields that was not
+/// given via the CLI. Either way, the config is validated like one read from a file before being
+/// written, save for the placeholder aggregators of a scaffolded config.
+///
+/// Returns the path the config was written to.
+pub async fn generate_config(opts: GenerateConfigOptions) -> Result<PathBuf, RAMMDeploymentError> {
+    // Checked first, so that the user does not fill in a whole config only for it to be refused.
+    if opts.out_path.exists() {
+        return Err(RAMMDeploymentError::GeneratedConfigExists(opts.out_path));
+    }
+
+    let dplymt_cfg = match &opts.from_coins {
+        Some(coin_types) => scaffold_from_coins(&opts, coin_types).await?,
+        None => prompt_for_config(&opts)?,
+    };
+
+    let scaffolded = opts.from_coins.is_some();
+    let errors: Vec<ConfigValidationError> = match dplymt_cfg.validate_detailed() {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .into_iter()
+            .filter(|error| !(scaffolded && is_placeholder_error(error)))
+            .collect(),
+    };
+    if !errors.is_empty() {
+        for error in errors {
+            log::error!("{error}");
+        }
+        return Err(RAMMDeploymentError::InvalidConfigData);
+    }
+    write_config(&dplymt_cfg, &opts.out_path)?;
+
+    Ok(opts.out_path)
+}
+
+/// Build a single-RAMM config with one asset per coin type, reading each one's decimal places
+/// from its on-chain `CoinMetadata`.
+///
+/// This does not prompt the user, for use e.g. in CI. Each asset's minimum trade amount is set to
+/// the smallest plausible one for its decimal places, and its aggregator, which cannot be inferred,
+/// to a placeholder that must be replaced before the config is deployed; the config is written
+/// regardless, with a warning.
+async fn scaffold_from_coins(
+    opts: &GenerateConfigOptions,
+    coin_types: &[String],
+) -> Result<RAMMDeploymentConfig, RAMMDeploymentError> {
+    // Both are enforced by the CLI, as the user is not prompted for them here.
+    let target_env = opts.target_env.clone().unwrap_or_default();
+    let fee_collection_address = opts.fee_collection_address.unwrap_or(SuiAddress::ZERO);
+
+    let asset_types = coin_types
+        .iter()
+        .map(|coin_type| {
+            parse_coin_type(coin_type).map_err(|reason| RAMMDeploymentError::InvalidCoinType {
+                coin_type: coin_type.clone(),
+                reason,
+            })
+        })
+        .collect::<Result<Vec<TypeTag>, _>>()?;
+
+    let network_cfg = NetworkConfig::default();
+    let (_, sui_client) = get_suibase_and_sui_client(&target_env, &network_cfg).await?;
+    let metadatas = stream::iter(coin_types.iter())
+        .map(|coin_type| coin_metadata::fetch_metadata(&sui_client, coin_type, &network_cfg))
+        .buffered(network_cfg.max_concurrency)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut assets = Vec::with_capacity(asset_types.len());
+    for ((asset_type, coin_type), metadata) in
+        asset_types.into_iter().zip(coin_types).zip(metadatas)
+    {
+        let decimal_places = metadata
+            .ok_or_else(|| RAMMDeploymentError::CoinMetadataNotFound(coin_type.clone()))?
+            .decimals;
+        log::info!("Asset {coin_type} has {decimal_places} decimal places, per its CoinMetadata.");
+        assets.push(AssetConfig {
+            asset_type,
+            aggregator_address: SuiAddress::ZERO,
+            minimum_trade_amount: plausible_minimum_trade_amount(decimal_places),
+            decimal_places: Some(decimal_places),
+            oracle_provider: OracleProvider::default(),
+            coin_name_and_symbol: None,
+            oracle_reading: None,
+        });
+    }
+    log::warn!(
+        "Each asset's `aggregator_address` is a placeholder, `0x0`: replace it with the address of \
+        the asset's price feed, and review its `minimum_trade_amount`, before deploying the config."
+    );
+
+    let pkg_addr_or_path = opts
+        .ramm_pkg_addr_or_path
+        .as_deref()
+        .unwrap_or(DEFAULT_PKG_ADDR_OR_PATH);
+    Ok(new_config(
+        target_env,
+        RAMMPkgAddrSrc::from_addr_or_path(pkg_addr_or_path),
+        RAMMSpec {
+            asset_count: None,
+            fee_collection_address,
+            assets,
+        },
+    ))
+}
+
+/// Whether a validation error is only due to the placeholder aggregators of a scaffolded config,
+/// which are shared by all of its assets.
+fn is_placeholder_error(error: &ConfigValidationError) -> bool {
+    match error {
+        ConfigValidationError::InRAMM { error, .. } => matches!(
+            **error,
+            ConfigValidationError::DuplicatePriceFeed(address) if address == SuiAddress::ZERO
+        ),
+        _ => false,
+    }
+}
+
+/// Build a single-RAMM config from the user's answers to a series of prompts on `STDIN`.
+///
+/// Each answer is validated as soon as it is given, and the question asked again if it is
+/// invalid; each asset is checked against those already added, as the RAMM's specification is.
+fn prompt_for_config(
+    opts: &GenerateConfigOptions,
+) -> Result<RAMMDeploymentConfig, RAMMDeploymentError> {
+    println!(
+        "This wizard writes a config that deploys a single RAMM. Press {} to accept a suggested \
+        answer, shown in brackets.",
+        "Enter".bright_green()
+    );
+
+    let target_env = match &opts.target_env {
+        Some(target_env) => target_env.clone(),
+        None => prompt(
+            "Target environment",
+            Some("testnet"),
+            |answer| match TARGET_ENVS.contains(&answer) {
+                true => Ok(answer.to_string()),
+                false => Err(ConfigValidationError::InvalidTargetEnv(answer.to_string())),
+            },
+        )?,
+    };
+    let ramm_pkg_addr_or_path = match &opts.ramm_pkg_addr_or_path {
+        Some(pkg_addr_or_path) => RAMMPkgAddrSrc::from_addr_or_path(pkg_addr_or_path),
+        None => prompt(
+            "RAMM package ID, or path of the RAMM library to publish",
+            Some(DEFAULT_PKG_ADDR_OR_PATH),
+            |answer| Ok::<_, String>(RAMMPkgAddrSrc::from_addr_or_path(answer)),
+        )?,
+    };
+    let fee_collection_address = match opts.fee_collection_address {
+        Some(address) => address,
+        None => prompt(
+            "Fee collection address",
+            None,
+            |answer| match SuiAddress::from_str(answer) {
+                Ok(address) if address == SuiAddress::ZERO => {
+                    Err(ConfigValidationError::ZeroFeeCollectionAddress.to_string())
+                }
+                Ok(address) => Ok(address),
+                Err(e) => Err(e.to_string()),
+            },
+        )?,
+    };
+
+    let mut ramm_spec = RAMMSpec {
+        asset_count: None,
+        fee_collection_address,
+        assets: Vec::new(),
+    };
+    loop {
+        println!(
+            "{}",
+            format!("Asset #{}", ramm_spec.assets.len() + 1).on_bright_black()
+        );
+        ramm_spec.assets.push(prompt_for_asset()?);
+
+        let mut errors = Vec::new();
+        ramm_spec.validate_ramm_spec(&mut errors);
+        if !errors.is_empty() {
+            for error in errors {
+                println!("{}: {error}", "Invalid".red());
+            }
+            println!("The asset was discarded; enter it again.");
+            ramm_spec.assets.pop();
+            continue;
+        }
+
+        let another = prompt(
+            "Add another asset? (yes/no)",
+            Some("yes"),
+            |answer| match answer {
+                "yes" => Ok(true),
+                "no" => Ok(false),
+                _ => Err("reply with \"yes\" or \"no\""),
+            },
+        )?;
+        if !another {
+            break;
+        }
+    }
+
+    Ok(new_config(target_env, ramm_pkg_addr_or_path, ramm_spec))
+}
+
+/// Prompt the user for each of an asset's fields.
+fn prompt_for_asset() -> Result<AssetConfig, RAMMDeploymentError> {
+    let asset_type = prompt("Coin type, e.g. 0x2::sui::SUI", None, parse_coin_type)?;
+    let aggregator_address = prompt("Address of its Switchboard aggregator", None, |answer| {
+        SuiAddress::from_str(answer).map_err(|e| e.to_string())
+    })?;
+    let decimal_places = prompt(
+        "Decimal places, or nothing to read them from its CoinMetadata when deploying",
+        None,
+        |answer| match answer {
+            "" => Ok(None),
+            _ => u8::from_str(answer).map(Some).map_err(|e| e.to_string()),
+        },
+    )?;
+    let minimum_trade_amount = prompt(
+        "Minimum trade amount, in the coin's smallest unit",
+        None,
+        |answer| match u64::from_str(answer) {
+            Ok(0) => Err("it must be non-zero".to_string()),
+            Ok(amount) => Ok(amount),
+            Err(e) => Err(e.to_string()),
+        },
+    )?;
+
+    Ok(AssetConfig {
+        asset_type,
+        aggregator_address,
+        minimum_trade_amount,
+        decimal_places,
+        oracle_provider: OracleProvider::Switchboard,
+        coin_name_and_symbol: None,
+        oracle_reading: None,
+    })
+}
+
+/// Ask the user a question on `STDOUT`, and parse their answer from `STDIN`, asking again until
+/// `parse` accepts it.
+///
+/// An empty answer is taken to be `default`, if there is one. If `STDIN` is closed before an answer
+/// is accepted, `ConfigWizardInputEnded` is returned.
+fn prompt<T, E: Display>(
+    question: &str,
+    default: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<T, RAMMDeploymentError> {
+    let mut input = String::new();
+    loop {
+        match default {
+            Some(default) => print!("{question} [{default}]: "),
+            None => print!("{question}: "),
+        }
+        io::stdout()
+            .flush()
+            .map_err(RAMMDeploymentError::ConfigWizardInputError)?;
+
+        input.clear();
+        let read = io::stdin()
+            .read_line(&mut input)
+            .map_err(RAMMDeploymentError::ConfigWizardInputError)?;
+        if read == 0 {
+            return Err(RAMMDeploymentError::ConfigWizardInputEnded);
+        }
+        let answer = match (input.trim(), default) {
+            ("", Some(default)) => default,
+            (answer, _) => answer,
+        };
+        match parse(answer) {
+            Ok(value) => return Ok(value),
+            Err(e) => println!("{}: {e}", "Invalid answer".red()),
+        }
+    }
+}
+
+/// A config deploying the given RAMM, with every optional table left at its defaults.
+fn new_config(
+    target_env: String,
+    ramm_pkg_addr_or_path: RAMMPkgAddrSrc,
+    ramm_spec: RAMMSpec,
+) -> RAMMDeploymentConfig {
+    RAMMDeploymentConfig {
+        target_env,
+        ramm_pkg_addr_or_path,
+        asset_count: None,
+        fee_collection_address: None,
+        assets: Vec::new(),
+        ramms: vec![ramm_spec],
+        admin_cap_recipient: None,
+        new_asset_cap_recipient: None,
+        gas: GasConfig::default(),
+        retry: RetryPolicy::default(),
+        network: NetworkConfig::default(),
+        oracle: OracleConfig::default(),
+        faucet: None,
+        sponsor: None,
+        named_addresses: BTreeMap::new(),
+        initial_liquidity: BTreeMap::new(),
+    }
+}
+
+/// Write the config, as TOML, to a new file at the given path.
+fn write_config(dplymt_cfg: &RAMMDeploymentConfig, path: &Path) -> Result<(), RAMMDeploymentError> {
+    let toml = dplymt_cfg.to_toml()?;
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(toml.as_bytes()))
+        .map_err(RAMMDeploymentError::GeneratedConfigWriteError)
+}
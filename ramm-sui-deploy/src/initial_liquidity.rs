@@ -0,0 +1,248 @@
+use sui_json_rpc_types::SuiTransactionBlockResponse;
+use sui_keys::keystore::Keystore;
+use sui_sdk::SuiClient;
+use sui_types::{
+    base_types::{ObjectID, SuiAddress},
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    transaction::{Argument, Command, ObjectArg},
+    Identifier, TypeTag,
+};
+
+use crate::{
+    error::RAMMDeploymentError,
+    smoke_test,
+    types::{RAMMDeploymentConfig, RAMMSpec},
+};
+
+/// A deposit of an asset's initial liquidity into a RAMM, made by its population PTB right after
+/// the RAMM is initialized; see `InitialLiquidity`.
+pub struct LiquidityDeposit {
+    pub asset_type: TypeTag,
+    pub amount: u64,
+    /// The coin to deposit: one minted from the faucet for this deposit, which is deposited whole,
+    /// or the one given in the config, from which `amount` is split.
+    pub coin: ObjectArg,
+    pub split: bool,
+    /// The RAMM interface's `liquidity_deposit_<N>`, and its type arguments and aggregators: those
+    /// of the deposited asset, followed by those of the others, in the RAMM's order.
+    module: Identifier,
+    function: Identifier,
+    type_args: Vec<TypeTag>,
+    aggregators: Vec<ObjectArg>,
+}
+
+/// Get the coins with which the RAMM's assets that have an entry in the config's
+/// `[initial_liquidity]` are to be deposited into it:
+/// * an entry with a `coin` is checked to name a coin of the asset, owned by the signer, holding
+///   at least `amount`, and
+/// * for any other, `amount` is minted from the faucet, in a tx of its own.
+///
+/// This must happen before the gas coin of the PTB that deposits them is selected, as minting
+/// spends gas.
+pub async fn prepare_deposits(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
+    client_address: SuiAddress,
+    aggr_obj_args: &[ObjectArg],
+    gas_coin: Option<ObjectID>,
+) -> Result<Vec<LiquidityDeposit>, RAMMDeploymentError> {
+    let mut deposits = Vec::new();
+    let seeded = ramm_spec
+        .assets
+        .iter()
+        .any(|asset| dplymt_cfg.initial_liquidity_of(asset).is_some());
+    if !seeded {
+        return Ok(deposits);
+    }
+    // Found before any liquidity is minted, so that none is for a RAMM it can not be deposited in.
+    let (module, function) =
+        smoke_test::interface_function(ramm_spec.asset_count(), "liquidity_deposit")?;
+
+    for (ix, asset) in ramm_spec.assets.iter().enumerate() {
+        let Some(liquidity) = dplymt_cfg.initial_liquidity_of(asset) else {
+            continue;
+        };
+
+        let (coin, split) = match liquidity.coin {
+            Some(coin_id) => {
+                let coin = provided_coin(
+                    sui_client,
+                    client_address,
+                    &asset.asset_type,
+                    coin_id,
+                    liquidity.amount,
+                )
+                .await?;
+                (coin, true)
+            }
+            None => {
+                let faucet = dplymt_cfg
+                    .faucet
+                    .as_ref()
+                    .ok_or(RAMMDeploymentError::InitialLiquidityWithoutFaucet)?;
+                let coin = smoke_test::mint_test_coins(
+                    sui_client,
+                    keystore,
+                    dplymt_cfg,
+                    faucet,
+                    client_address,
+                    &asset.asset_type,
+                    liquidity.amount,
+                    gas_coin,
+                )
+                .await?;
+                (coin, false)
+            }
+        };
+
+        let order: Vec<usize> = std::iter::once(ix)
+            .chain((0..ramm_spec.assets.len()).filter(|other| *other != ix))
+            .collect();
+        deposits.push(LiquidityDeposit {
+            asset_type: asset.asset_type.clone(),
+            amount: liquidity.amount,
+            coin,
+            split,
+            module: module.clone(),
+            function: function.clone(),
+            type_args: order
+                .iter()
+                .map(|&asset_ix| ramm_spec.assets[asset_ix].asset_type.clone())
+                .collect(),
+            aggregators: order
+                .iter()
+                .map(|&asset_ix| aggr_obj_args[asset_ix])
+                .collect(),
+        });
+    }
+
+    Ok(deposits)
+}
+
+/// Find the coin given in the config as the source of an asset's initial liquidity among the
+/// signer's coins of the asset, and check that it holds at least `amount`.
+async fn provided_coin(
+    sui_client: &SuiClient,
+    client_address: SuiAddress,
+    asset_type: &TypeTag,
+    coin_id: ObjectID,
+    amount: u64,
+) -> Result<ObjectArg, RAMMDeploymentError> {
+    let mut cursor: Option<ObjectID> = None;
+    loop {
+        let coin_page = sui_client
+            .coin_read_api()
+            .get_coins(client_address, Some(asset_type.to_string()), cursor, None)
+            .await
+            .map_err(RAMMDeploymentError::CoinQueryError)?;
+        if let Some(coin) = coin_page
+            .data
+            .iter()
+            .find(|coin| coin.coin_object_id == coin_id)
+        {
+            if coin.balance < amount {
+                return Err(RAMMDeploymentError::InitialLiquidityCoinTooSmall {
+                    coin: coin_id,
+                    balance: coin.balance,
+                    amount,
+                });
+            }
+            return Ok(ObjectArg::ImmOrOwnedObject(coin.object_ref()));
+        }
+        if !coin_page.has_next_page {
+            return Err(RAMMDeploymentError::InitialLiquidityCoinNotFound {
+                coin: coin_id,
+                asset: asset_type.to_string(),
+                owner: client_address,
+            });
+        }
+        cursor = coin_page.next_cursor;
+    }
+}
+
+/// Add a `liquidity_deposit_<N>` call to the PTB for each of the deposits, after the RAMM, given
+/// as `ramm_arg`, has been initialized.
+pub fn add_deposits(
+    ptb: &mut ProgrammableTransactionBuilder,
+    ramm_package_id: ObjectID,
+    ramm_arg: Argument,
+    deposits: &[LiquidityDeposit],
+) -> Result<(), RAMMDeploymentError> {
+    let ptb_build_error = |err: anyhow::Error| {
+        RAMMDeploymentError::PtbBuildError(format!("initial liquidity deposit: {err}"))
+    };
+    for deposit in deposits {
+        let mut coin_arg = ptb.obj(deposit.coin).map_err(ptb_build_error)?;
+        if deposit.split {
+            let amount_arg = ptb.pure(deposit.amount).map_err(ptb_build_error)?;
+            let Argument::Result(split_ix) =
+                ptb.command(Command::SplitCoins(coin_arg, vec![amount_arg]))
+            else {
+                return Err(RAMMDeploymentError::PtbBuildError(
+                    "initial liquidity deposit: splitting the coin did not yield a result"
+                        .to_string(),
+                ));
+            };
+            coin_arg = Argument::NestedResult(split_ix, 0);
+        }
+
+        let mut move_call_args: Vec<Argument> = vec![ramm_arg, coin_arg];
+        for aggregator in &deposit.aggregators {
+            move_call_args.push(ptb.obj(*aggregator).map_err(ptb_build_error)?);
+        }
+        ptb.programmable_move_call(
+            ramm_package_id,
+            deposit.module.clone(),
+            deposit.function.clone(),
+            deposit.type_args.clone(),
+            move_call_args,
+        );
+    }
+    if !deposits.is_empty() {
+        log::info!(
+            "PTB: Deposited the initial liquidity of {} assets",
+            deposits.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Check that each of the deposits made by the population PTB that `response` is to minted
+/// `client_address` a non-zero amount of LP tokens.
+pub async fn check_lp_tokens(
+    sui_client: &SuiClient,
+    response: &SuiTransactionBlockResponse,
+    client_address: SuiAddress,
+    ramm_package_id: ObjectID,
+    deposits: &[LiquidityDeposit],
+) -> Result<(), RAMMDeploymentError> {
+    for deposit in deposits {
+        let lp_tokens = smoke_test::minted_lp_tokens(
+            sui_client,
+            response,
+            client_address,
+            ramm_package_id,
+            &deposit.asset_type,
+        )
+        .await?;
+        match lp_tokens {
+            Some(lp_tokens) if lp_tokens > 0 => log::info!(
+                "Deposited {} of {} into the RAMM as initial liquidity, for {lp_tokens} LP tokens.",
+                deposit.amount,
+                deposit.asset_type
+            ),
+            _ => {
+                return Err(RAMMDeploymentError::NoInitialLPTokens {
+                    asset: deposit.asset_type.to_string(),
+                    amount: deposit.amount,
+                    digest: response.digest,
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
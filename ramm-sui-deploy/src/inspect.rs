@@ -0,0 +1,203 @@
+use std::fmt::Display;
+
+use colored::Colorize;
+use serde_json::Value;
+use sui_json_rpc_types::{SuiObjectDataOptions, SuiParsedData};
+use sui_sdk::SuiClient;
+use sui_types::base_types::ObjectID;
+
+use crate::error::RAMMDeploymentError;
+
+/// An asset of a live RAMM, as read from the RAMM's on-chain state.
+///
+/// Amounts stored as `u256` in Sui Move are kept as the decimal strings the RPC returns them as.
+#[derive(Debug)]
+pub struct AssetState {
+    /// The asset's index in the RAMM.
+    pub index: u8,
+    /// The asset's type name, in the form `<package-id>::<module>::<type-name>`.
+    pub asset_type: String,
+    pub aggregator_address: String,
+    pub minimum_trade_amount: String,
+    pub deposits_enabled: bool,
+    pub balance: String,
+    pub lp_tokens_issued: String,
+}
+
+/// The state of a live RAMM, as read from its Move object's fields.
+#[derive(Debug)]
+pub struct RAMMState {
+    pub ramm_id: ObjectID,
+    pub admin_cap_id: String,
+    pub new_asset_cap_id: String,
+    pub is_initialized: bool,
+    pub fee_collector: String,
+    pub asset_count: u8,
+    /// The RAMM's assets, in the order of their indexes.
+    pub assets: Vec<AssetState>,
+}
+
+/// Query the network for the RAMM with the given ID, and parse its Move object's fields.
+pub async fn read_ramm_state(
+    sui_client: &SuiClient,
+    ramm_id: ObjectID,
+) -> Result<RAMMState, RAMMDeploymentError> {
+    let ramm_obj = sui_client
+        .read_api()
+        .get_object_with_options(ramm_id, SuiObjectDataOptions::new().with_content())
+        .await
+        .map_err(RAMMDeploymentError::RAMMObjectQueryError)?;
+
+    let fields = match ramm_obj
+        .object()
+        .ok()
+        .and_then(|data| data.content.as_ref())
+    {
+        Some(SuiParsedData::MoveObject(ramm)) => ramm.fields.clone().to_json_value(),
+        _ => return Err(RAMMDeploymentError::ObjectNotFound(ramm_id)),
+    };
+
+    let asset_count = fields["asset_count"]
+        .as_u64()
+        .and_then(|count| u8::try_from(count).ok())
+        .ok_or_else(|| missing_field("asset_count"))?;
+
+    let mut assets = Vec::with_capacity(usize::from(asset_count));
+    for (asset_type, index) in vec_map_entries(&fields, "types_to_indexes")? {
+        let asset_type = asset_type["name"]
+            .as_str()
+            .ok_or_else(|| missing_field("types_to_indexes"))?;
+        let index = index
+            .as_u64()
+            .and_then(|index| u8::try_from(index).ok())
+            .ok_or_else(|| missing_field("types_to_indexes"))?;
+
+        assets.push(AssetState {
+            index,
+            asset_type: asset_type.to_string(),
+            aggregator_address: vec_map_value(&fields, "aggregator_addrs", index)?,
+            minimum_trade_amount: vec_map_value(&fields, "minimum_trade_amounts", index)?,
+            deposits_enabled: vec_map_value(&fields, "deposits_enabled", index)? == "true",
+            balance: vec_map_value(&fields, "balances", index)?,
+            lp_tokens_issued: vec_map_value(&fields, "lp_tokens_issued", index)?,
+        });
+    }
+    assets.sort_by_key(|asset| asset.index);
+
+    Ok(RAMMState {
+        ramm_id,
+        admin_cap_id: id_string(&fields["admin_cap_id"])
+            .ok_or_else(|| missing_field("admin_cap_id"))?,
+        new_asset_cap_id: id_string(&fields["new_asset_cap_id"])
+            .ok_or_else(|| missing_field("new_asset_cap_id"))?,
+        is_initialized: fields["is_initialized"]
+            .as_bool()
+            .ok_or_else(|| missing_field("is_initialized"))?,
+        fee_collector: id_string(&fields["fee_collector"])
+            .ok_or_else(|| missing_field("fee_collector"))?,
+        asset_count,
+        assets,
+    })
+}
+
+/// The error for a field of the RAMM that is absent, or not of the expected shape.
+fn missing_field(field: &str) -> RAMMDeploymentError {
+    RAMMDeploymentError::RAMMStateParseError(format!("field `{field}` is missing or malformed"))
+}
+
+/// An object ID or address, which the RPC renders either as a string, or as an `ID` struct
+/// wrapping one.
+fn id_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(id) => Some(id.clone()),
+        Value::Object(id) => id.get("bytes").or_else(|| id.get("id")).and_then(id_string),
+        _ => None,
+    }
+}
+
+/// The `(key, value)` entries of the Move `VecMap` in the given field.
+fn vec_map_entries(
+    fields: &Value,
+    field: &str,
+) -> Result<Vec<(Value, Value)>, RAMMDeploymentError> {
+    let entries = fields[field]["contents"]
+        .as_array()
+        .ok_or_else(|| missing_field(field))?;
+
+    Ok(entries
+        .iter()
+        .map(|entry| (entry["key"].clone(), entry["value"].clone()))
+        .collect())
+}
+
+/// The value of the given asset index in the Move `VecMap` in the given field, as a string.
+fn vec_map_value(fields: &Value, field: &str, index: u8) -> Result<String, RAMMDeploymentError> {
+    let value = vec_map_entries(fields, field)?
+        .into_iter()
+        .find(|(key, _)| key.as_u64() == Some(u64::from(index)))
+        .map(|(_, value)| value)
+        .ok_or_else(|| missing_field(field))?;
+
+    Ok(match value {
+        Value::String(value) => value,
+        value => value.to_string(),
+    })
+}
+
+impl Display for RAMMState {
+    /// Display a RAMM's state in human-readable format, in the style of the deployment config's
+    /// `Display`.
+    ///
+    /// This function uses [ANSI escape codes](https://en.wikipedia.org/wiki/ANSI_escape_code)
+    /// to color-code the output.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:\n", "RAMM State".on_bright_black())?;
+        write!(f, "\t{}: {}\n", "RAMM ID".green(), self.ramm_id)?;
+        write!(f, "\t{}: {}\n", "Admin Cap ID".green(), self.admin_cap_id)?;
+        write!(
+            f,
+            "\t{}: {}\n",
+            "New Asset Cap ID".green(),
+            self.new_asset_cap_id
+        )?;
+        write!(f, "\t{}: {}\n", "Initialized".green(), self.is_initialized)?;
+        write!(
+            f,
+            "\t{}: {}\n",
+            "Fee collection address".green(),
+            self.fee_collector
+        )?;
+        write!(f, "\t{}: {}\n", "Asset count".green(), self.asset_count)?;
+        write!(f, "\t{}:\n", "List of assets".green())?;
+        for asset in &self.assets {
+            write!(f, "\t\t{} #{}:\n", "asset data".purple(), asset.index)?;
+            write!(f, "\t\t\t{}: {}\n", "asset type".cyan(), asset.asset_type)?;
+            write!(
+                f,
+                "\t\t\t{}: {}\n",
+                "aggregator address".cyan(),
+                asset.aggregator_address
+            )?;
+            write!(
+                f,
+                "\t\t\t{}: {}\n",
+                "minimum trade amount".cyan(),
+                asset.minimum_trade_amount
+            )?;
+            write!(
+                f,
+                "\t\t\t{}: {}\n",
+                "deposits enabled".cyan(),
+                asset.deposits_enabled
+            )?;
+            write!(f, "\t\t\t{}: {}\n", "balance".cyan(), asset.balance)?;
+            write!(
+                f,
+                "\t\t\t{}: {}\n",
+                "LP tokens issued".cyan(),
+                asset.lp_tokens_issued
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use tokio::{sync::watch, task::JoinHandle};
+
+use crate::checkpoint::DeploymentCheckpoint;
+
+/// Where the checkpoint of an interrupted deployment is written if no `--checkpoint` path was
+/// given, so that it can still be resumed with `--resume`.
+pub const INTERRUPTED_CHECKPOINT_PATH: &str = "ramm_deploy.interrupted.json";
+
+/// Exit status of a deployment interrupted with Ctrl-C, as a shell reports a process killed by
+/// `SIGINT`.
+const INTERRUPTED_EXIT_STATUS: i32 = 130;
+
+/// Handles Ctrl-C for as long as it is alive, i.e. while a deployment is running.
+///
+/// On interrupt, it logs which of the deployment's stages were completed, per the checkpoint last
+/// passed to `record`, and which may have been in progress, writes that checkpoint, and exits with
+/// a non-zero status, so that no object is left behind without the user knowing whether it exists.
+pub struct InterruptHandler {
+    progress: watch::Sender<DeploymentCheckpoint>,
+    task: JoinHandle<()>,
+}
+
+impl InterruptHandler {
+    /// Start handling Ctrl-C, from the given checkpoint of a deployment of `ramm_count` RAMMs; on
+    /// interrupt, the checkpoint is written to `checkpoint_path`, or to
+    /// `INTERRUPTED_CHECKPOINT_PATH` if there is none.
+    pub fn install(
+        checkpoint: &DeploymentCheckpoint,
+        ramm_count: usize,
+        checkpoint_path: Option<PathBuf>,
+    ) -> Self {
+        let (progress, progress_rx) = watch::channel(checkpoint.clone());
+        let checkpoint_path =
+            checkpoint_path.unwrap_or_else(|| PathBuf::from(INTERRUPTED_CHECKPOINT_PATH));
+
+        let task = tokio::spawn(async move {
+            if let Err(e) = tokio::signal::ctrl_c().await {
+                log::warn!("Could not listen for Ctrl-C, which will abort the deployment: {e}");
+                return;
+            }
+            let checkpoint = progress_rx.borrow().clone();
+            log::error!("Deployment interrupted.");
+            report_progress(&checkpoint, ramm_count, checkpoint_path);
+            std::process::exit(INTERRUPTED_EXIT_STATUS);
+        });
+
+        InterruptHandler { progress, task }
+    }
+
+    /// Record the deployment's progress, to be reported if it is interrupted.
+    pub fn record(&self, checkpoint: &DeploymentCheckpoint) {
+        self.progress.send_replace(checkpoint.clone());
+    }
+}
+
+impl Drop for InterruptHandler {
+    /// Once the deployment is over, Ctrl-C is no longer handled.
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Log the stages of the interrupted deployment that were completed, the one that may have been in
+/// progress, and write its checkpoint, if any stage was completed.
+fn report_progress(checkpoint: &DeploymentCheckpoint, ramm_count: usize, checkpoint_path: PathBuf) {
+    let Some(package_id) = checkpoint.package_id else {
+        log::error!(
+            "No stage was completed. If the package was being published, its publication tx may \
+            still have been executed: check the signer's latest txs before deploying again."
+        );
+        return;
+    };
+
+    log::error!("Completed: the RAMM package is {package_id}.");
+    for (ix, ramm) in checkpoint.ramms.iter().enumerate() {
+        log::error!(
+            "Completed: RAMM #{} was created as {}, with admin cap {} and new asset cap {}.",
+            ix + 1,
+            ramm.obj_ids.ramm,
+            ramm.obj_ids.admin_cap,
+            ramm.obj_ids.new_asset_cap
+        );
+        match ramm.populate_tx_digest {
+            Some(digest) => {
+                log::error!("Completed: RAMM #{} was populated in tx {digest}.", ix + 1)
+            }
+            None => log::error!(
+                "RAMM #{} was not populated: its population PTB may still have been executed.",
+                ix + 1
+            ),
+        }
+    }
+    let next_ramm_pending = checkpoint
+        .ramms
+        .last()
+        .map_or(true, |ramm| ramm.populate_tx_digest.is_some());
+    if next_ramm_pending && checkpoint.ramms.len() < ramm_count {
+        log::error!(
+            "RAMM #{} was not created: if its creation tx was sent, it may still have been \
+            executed, and the RAMM then exists without its capabilities having been recorded.",
+            checkpoint.ramms.len() + 1
+        );
+    }
+
+    match checkpoint.write_to_file(&checkpoint_path) {
+        Ok(()) => log::error!(
+            "Wrote the deployment's checkpoint to {}: pass it to --resume to complete the \
+            deployment.",
+            checkpoint_path.display()
+        ),
+        Err(e) => log::error!("Failed to write the deployment's checkpoint: {e}"),
+    }
+}
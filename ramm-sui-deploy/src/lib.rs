@@ -1,37 +1,99 @@
+pub mod abi;
+pub mod cap_transfer;
+pub mod checkpoint;
+pub mod client;
+pub mod coin_metadata;
+pub mod config_builder;
+pub mod config_schema;
+pub mod deployment;
+pub mod env_file;
 pub mod error;
+pub mod events;
+pub mod explorer;
+pub mod generate_config;
+pub mod initial_liquidity;
+pub mod inspect;
+pub mod interrupt;
+pub mod lockfile;
+pub mod manifest;
+pub mod offline;
+pub mod oracle;
+pub mod pkg_cache;
+pub mod preview;
+pub mod report;
+pub mod report_format;
+pub mod smoke_test;
+pub mod ts_config;
+pub mod tx_status;
 pub mod types;
 pub mod util;
 
-use std::{ffi::OsString, fmt::Display, fs, io, path::PathBuf, str::FromStr};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ffi::OsString,
+    fmt::Display,
+    fs,
+    future::Future,
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
 
-use clap::{Arg, ArgMatches, Command};
+use clap::{parser::ValueSource, Arg, ArgMatches, Command};
+use clap_complete::Shell;
 use colored::Colorize;
 use error::RAMMDeploymentError;
+use futures::{stream, StreamExt, TryStreamExt};
+use jsonrpsee::{
+    core::Error as RpcError,
+    types::error::{CallError, ErrorCode},
+};
+use log::LevelFilter;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
-use move_core_types::{ident_str, identifier::IdentStr};
+use move_compiler::diagnostics::{report_diagnostics_to_buffer, Diagnostics, FilesSourceText};
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use move_package::compilation::build_plan::BuildPlan;
 use shared_crypto::intent::Intent;
 use sui_json_rpc_types::{
-    Coin, OwnedObjectRef, SuiObjectDataOptions, SuiTransactionBlockEffectsAPI,
-    SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+    Coin, SuiExecutionStatus, SuiMoveValue, SuiObjectDataOptions, SuiParsedData,
+    SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
 };
 use suibase::Helper;
 
-use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, InMemKeystore, Keystore};
 use sui_move_build::{BuildConfig, CompiledPackage};
 use sui_sdk::{json::SuiJsonValue, SuiClient, SuiClientBuilder};
 use sui_types::{
     base_types::{MoveObjectType, ObjectID, ObjectType, SuiAddress},
+    crypto::{EncodeDecodeBase64, SuiKeyPair},
+    digests::TransactionDigest,
+    gas_coin::MIST_PER_SUI,
+    move_package::UpgradePolicy,
     object::Owner,
     programmable_transaction_builder::ProgrammableTransactionBuilder,
-    quorum_driver_types::ExecuteTransactionRequestType,
-    transaction::{Argument, ObjectArg, ProgrammableTransaction, Transaction, TransactionData},
-    Identifier, TypeTag,
+    transaction::{
+        self, Argument, ObjectArg, ProgrammableTransaction, Transaction, TransactionData,
+        TransactionDataAPI,
+    },
+    TypeTag,
 };
 
-use crate::types::{AssetConfig, RAMMDeploymentConfig};
+use crate::client::DeploymentClient;
+use crate::generate_config::GenerateConfigOptions;
+use crate::pkg_cache::PackageArtifacts;
+use crate::report::DeploymentReport;
+use crate::types::{
+    ConfigFormat, DeploymentOptions, DeploymentStage, ExecutionWait, GasConfig, GasProfile,
+    NetworkConfig, OutputFormat, PkgBuildOptions, RAMMDeploymentConfig, RAMMPkgAddrSrc, RAMMSpec,
+    RetryPolicy, SignerSelection, SponsorConfig, LOCALNET, MAX_GAS_BUDGET, TARGET_ENVS,
+};
+use crate::util::LogBackend;
 
-/// This represents the gas budget (in MIST units, where 10^9 MIST is 1 SUI) to be used
-/// when publishing the RAMM package.
+/// This represents the default gas budget (in MIST units, where 10^9 MIST is 1 SUI) to be used
+/// when publishing the RAMM package, if none is specified in the deployment config.
 ///
 /// Publishing it in the testnet in mid/late 2023 cost roughly 0.25 SUI, on average.
 const PACKAGE_PUBLICATION_GAS_BUDGET: u64 = 500_000_000;
@@ -39,70 +101,959 @@ const PACKAGE_PUBLICATION_GAS_BUDGET: u64 = 500_000_000;
 /// Name of the module in the RAMM package that contains the API to create and initialize it.
 pub const RAMM_MODULE_NAME: &IdentStr = ident_str!("ramm");
 
-/// Gas budget for the transaction that creates the RAMM.
+/// Default gas budget for the transaction that creates the RAMM.
 const CREATE_RAMM_GAS_BUDGET: u64 = 100_000_000;
 
-/// Gas budget for the PTB that will add assets to the RAMM, and initialize it.
+/// Default gas budget for the PTB that will add assets to the RAMM, and initialize it.
 const RAMM_PTB_GAS_BUDGET: u64 = 100_000_000;
 
-/// Parse a RAMM's deployment configuration from a given `FilePath`.
+/// Default gas budgets used instead of the above when deploying to a localnet, whose gas can only
+/// be obtained from its faucet: lower budgets let a few of its coins cover a whole deployment.
+///
+/// Each still exceeds what its tx was measured to use on the testnet.
+const LOCALNET_PACKAGE_PUBLICATION_GAS_BUDGET: u64 = 400_000_000;
+const LOCALNET_CREATE_RAMM_GAS_BUDGET: u64 = 50_000_000;
+const LOCALNET_RAMM_PTB_GAS_BUDGET: u64 = 50_000_000;
+
+/// Gas units of computation of a PTB that merges the active address' coins, in case none of them
+/// can, on its own, cover the gas budget of the PTB that populates the RAMM; see
+/// `GasConfig::merge_coins_budget`.
+const MERGE_COINS_BASE_GAS_UNITS: u64 = 2_000;
+/// Gas units added to a merge PTB's computation by each coin merged in it.
+const MERGE_COINS_GAS_UNITS_PER_COIN: u64 = 20;
+/// Storage cost, in MIST, of a merge PTB, which rewrites the coin merged into; unlike its
+/// computation, it does not depend on the gas price.
+const MERGE_COINS_STORAGE_COST: u64 = 2_000_000;
+/// Maximum number of coins merged into the gas coin by a single PTB, well within the protocol's
+/// limit on a command's arguments; more coins are merged by successive PTBs.
+const MAX_COINS_PER_MERGE: usize = 500;
+
+/// A gas price provided by the user that exceeds the reference gas price by more than this factor
+/// is likely a typo, and a warning is logged.
+const GAS_PRICE_WARNING_FACTOR: u64 = 10;
+
+/// Maximum delay between two attempts to submit a tx, however many retries preceded it.
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
+/// JSON-RPC error code with which a Sui fullnode reports a transient failure to execute a tx,
+/// e.g. one that timed out before reaching finality.
+const SUI_TRANSIENT_ERROR_CODE: i32 = -32050;
+
+/// Parse a RAMM's deployment configuration, written in the given format, from a given
+/// `FilePath`, or from `STDIN` if the path is `-`.
 ///
 /// It is assumed that configs are not sizable files, so they're read directly from the
-/// filesystem into a `String`, and from there parsed using `toml::from_str`.
-fn parse_ramm_cfg(toml_path: PathBuf) -> Result<RAMMDeploymentConfig, RAMMDeploymentError> {
-    let config_string: String =
-        fs::read_to_string(toml_path).map_err(RAMMDeploymentError::TOMLFileReadError)?;
+/// filesystem (or `STDIN`) into a `String`, and from there parsed using `toml::from_str` or
+/// `serde_json::from_str`.
+///
+/// The config is then normalized and validated; if it is invalid, every problem found with it is
+/// logged, and `InvalidConfigData` is returned.
+pub fn parse_ramm_cfg(
+    toml_path: PathBuf,
+    format: ConfigFormat,
+) -> Result<RAMMDeploymentConfig, RAMMDeploymentError> {
+    let mut cfg = read_ramm_cfg(toml_path, format)?;
 
-    let cfg: RAMMDeploymentConfig =
-        toml::from_str(&config_string).map_err(RAMMDeploymentError::TOMLParseError)?;
+    if !cfg.normalize_ramm_specs() {
+        return Err(RAMMDeploymentError::InvalidConfigData);
+    }
+    match cfg.validate_detailed() {
+        Ok(()) => Ok(cfg),
+        Err(errors) => {
+            log::error!("The config has {} problem(s):", errors.len());
+            for (ix, error) in errors.iter().enumerate() {
+                log::error!("  {}. {error}", ix + 1);
+            }
+            Err(RAMMDeploymentError::InvalidConfigData)
+        }
+    }
+}
+
+/// Read a RAMM's deployment configuration, as `parse_ramm_cfg` does, but without normalizing or
+/// validating it.
+fn read_ramm_cfg(
+    toml_path: PathBuf,
+    format: ConfigFormat,
+) -> Result<RAMMDeploymentConfig, RAMMDeploymentError> {
+    let config_string: String = if is_stdin_path(&toml_path) {
+        io::read_to_string(io::stdin()).map_err(RAMMDeploymentError::TOMLStdinReadError)?
+    } else {
+        fs::read_to_string(toml_path).map_err(RAMMDeploymentError::TOMLFileReadError)?
+    };
 
-    match cfg.validate_ramm_cfg() {
-        true => Ok(cfg),
-        _ => Err(RAMMDeploymentError::InvalidConfigData),
+    match format {
+        ConfigFormat::Toml => {
+            toml::from_str(&config_string).map_err(RAMMDeploymentError::TOMLParseError)
+        }
+        ConfigFormat::Json => {
+            serde_json::from_str(&config_string).map_err(RAMMDeploymentError::JSONParseError)
+        }
     }
 }
 
-/// Build a [`RAMMDeploymentConfig`] from `main`'s `args` iterator.
+/// Whether the path given for the config is `-`, meaning the config is to be read from `STDIN`.
+fn is_stdin_path(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// The arguments shared by every stage of a deployment, i.e. by the deployer's top-level command
+/// and each of its subcommands.
+fn deployment_args() -> Vec<Arg> {
+    #[allow(unused_mut)]
+    let mut args = vec![
+        Arg::new("TOML config")
+            .short('t')
+            .long("toml")
+            .help("Path to the TOML config containing the RAMM's deployment parameters, or `-` to read it from STDIN.")
+            .required(true)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("format")
+            .long("format")
+            .help("Format of the config. If absent, inferred from its extension, defaulting to TOML.")
+            .required(false)
+            .num_args(1)
+            .value_parser(["toml", "json"]),
+        Arg::new("output format")
+            .long("output-format")
+            .help("Format of the report printed after a successful deployment: `human` (the default), `json`, `table` or `markdown`.")
+            .required(false)
+            .num_args(1)
+            .value_parser(["human", "json", "table", "markdown"]),
+        Arg::new("print config")
+            .long("print-config")
+            .help("Print the parsed config as canonical TOML, and exit without accessing the network.")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("publish gas")
+            .long("publish-gas")
+            .help("Gas budget (MIST) for the package publication tx. Overrides the TOML config.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(u64).range(1..=MAX_GAS_BUDGET)),
+        Arg::new("create gas")
+            .long("create-gas")
+            .help("Gas budget (MIST) for the RAMM creation tx. Overrides the TOML config.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(u64).range(1..=MAX_GAS_BUDGET)),
+        Arg::new("populate gas")
+            .long("populate-gas")
+            .help("Gas budget (MIST) for the RAMM population PTB. Overrides the TOML config.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(u64).range(1..=MAX_GAS_BUDGET)),
+        Arg::new("gas price")
+            .long("gas-price")
+            .help(
+                "Gas price (MIST per unit of gas) for the RAMM population PTB, instead of the \
+                network's reference gas price; must not be below it. Overrides the TOML config.",
+            )
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("wait for")
+            .long("wait-for")
+            .help(
+                "What the node waits for before acknowledging a tx. `local-execution` (the \
+                default) lets created objects be read right away, but may time out on busy \
+                nodes; `effects-cert` returns sooner, after which the tool polls the node for \
+                the tx's effects. Overrides the TOML config.",
+            )
+            .required(false)
+            .num_args(1)
+            .value_parser(["local-execution", "effects-cert"]),
+        Arg::new("assume yes")
+            .short('y')
+            .long("yes")
+            .help("Accept the displayed deployment config without prompting for confirmation.")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("dry run")
+            .long("dry-run")
+            .help("Build and dry run the deployment's transactions, without ever submitting them.")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("strict")
+            .long("strict")
+            .help(
+                "Abort, instead of only warning, if an asset's configured decimal places differ \
+                from those in its on-chain CoinMetadata, if a gas budget is implausibly large, if \
+                the Move compiler warns while building the RAMM library, or if a price feed is \
+                stale, as with --require-fresh-oracles.",
+            )
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("require fresh oracles")
+            .long("require-fresh-oracles")
+            .help(
+                "Abort if any asset's price feed is older than the config's \
+                `[oracle] max_staleness_secs` (600 by default), or cannot be read.",
+            )
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("smoke test")
+            .long("smoke-test")
+            .help(
+                "After deploying each RAMM, mint its first asset from the config's faucet and \
+                trade it in for the second, to check that the RAMM can be traded with. The \
+                RAMM must already hold liquidity for the trade to succeed.",
+            )
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("seed liquidity")
+            .long("seed-liquidity")
+            .help(
+                "After deploying each RAMM, mint this many whole units of each of its assets \
+                from the config's faucet, deposit them into the RAMM, and check that LP tokens \
+                are minted back. Done before any smoke trade.",
+            )
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("manifest")
+            .long("manifest")
+            .help("Path of the JSON file to which to write the manifest of a successful deployment.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("ts config")
+            .long("ts-config")
+            .help("Path of the `.ts` (or `.json`) file to which to write the TS SDK's config after a successful deployment.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("env out")
+            .long("env-out")
+            .help("Path of the `.env` file to which to write the deployed objects' IDs, as `KEY=value` lines, after a successful deployment.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("env append")
+            .long("env-append")
+            .help("Append to the `--env-out` file, instead of overwriting it.")
+            .requires("env out")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("unsigned out")
+            .long("unsigned-out")
+            .help(
+                "Instead of signing and executing the deployment's next transactions, write \
+                them, unsigned and BCS-serialized in base64, to files in this directory, to be \
+                signed offline; the tool then stops.",
+            )
+            .required(false)
+            .num_args(1)
+            .conflicts_with("dry run")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("submit signed")
+            .long("submit-signed")
+            .value_names(["TX_FILE", "SIG_FILE"])
+            .help(
+                "Submit a transaction exported with `--unsigned-out`, along with the base64 \
+                signature produced for it offline, instead of deploying anything.",
+            )
+            .required(false)
+            .num_args(2)
+            .conflicts_with_all(["dry run", "unsigned out"])
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("upgrade cap")
+            .long("upgrade-cap")
+            .help("Object ID of an `UpgradeCap`: upgrade the package it governs with the library at the config's path, instead of publishing it anew.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(ObjectID)),
+        Arg::new("package path")
+            .long("package-path")
+            .value_name("PATH")
+            .help("Path of the RAMM library to publish, instead of the config's `ramm_pkg_addr_or_path`.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("no cache")
+            .long("no-cache")
+            .help("Compile the RAMM library anew, instead of reusing a cached compilation of unchanged sources.")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("bytecode dir")
+            .long("bytecode-dir")
+            .value_name("DIR")
+            .help("Skip compiling the RAMM library, and publish the prebuilt modules in DIR/bytecode.json, the output of `sui move build --dump-bytecode-as-base64`.")
+            .value_parser(clap::value_parser!(PathBuf))
+            .conflicts_with("no cache"),
+        Arg::new("with unpublished deps")
+            .long("with-unpublished-deps")
+            .help("For development and testing only: publish the RAMM library's unpublished dependencies along with it, as part of its package.")
+            .action(clap::ArgAction::SetTrue)
+            .conflicts_with("bytecode dir"),
+        Arg::new("force")
+            .long("force")
+            .help("Deploy the config even if the lockfile records a successful deployment of it.")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("lockfile")
+            .long("lockfile")
+            .help("Path of the JSON lockfile recording successful deployments, instead of `ramm_deploy.lock`.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("checkpoint")
+            .long("checkpoint")
+            .help("Path of the JSON file to which to write the deployment's progress after each stage.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("resume")
+            .long("resume")
+            .help("Path of a checkpoint from which to resume a partially completed deployment, skipping its completed stages.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("gas coin")
+            .long("gas-coin")
+            .help("Object ID of the SUI coin, owned by the signer, with which to pay for gas.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(ObjectID)),
+        Arg::new("keystore")
+            .long("keystore")
+            .help("Path of the keystore with which to sign txs, instead of Suibase's.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("rpc url")
+            .long("rpc-url")
+            .value_name("URL")
+            .help(
+                "URL of the full node to connect to, bypassing Suibase: the signer must then be \
+                given with --address or --keystore-env, and the keystore with --keystore or \
+                --keystore-env.",
+            )
+            .required(false)
+            .num_args(1),
+        Arg::new("localnet")
+            .long("localnet")
+            .help(
+                "Deploy to the Suibase localnet workdir, whatever the config's target environment, \
+                with lower default gas budgets, and without checking that price feeds are fresh.",
+            )
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("keystore env")
+            .long("keystore-env")
+            .help(
+                "Sign txs with the base64-encoded private key in the RAMM_DEPLOY_KEY env variable, \
+                kept in memory, instead of with a keystore file.",
+            )
+            .conflicts_with("keystore")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("address")
+            .long("address")
+            .help("Address with which to sign txs, instead of the workdir's active address.")
+            .required(false)
+            .num_args(1)
+            .value_parser(clap::value_parser!(SuiAddress)),
+        Arg::new("alias")
+            .long("alias")
+            .help("Suibase name of the address with which to sign txs, e.g. sb-1-ed25519.")
+            .required(false)
+            .num_args(1)
+            .conflicts_with_all(["address", "keystore env", "rpc url"]),
+        Arg::new("explorer")
+            .long("explorer")
+            .value_name("URL")
+            .help("Base URL of the block explorer to link txs and objects to, instead of Suiscan's for the target network.")
+            .required(false)
+            .num_args(1),
+        Arg::new("verbose")
+            .short('v')
+            .long("verbose")
+            .help("Log more detail: `-v` for debug logs, `-vv` for trace logs.")
+            .action(clap::ArgAction::Count)
+            .conflicts_with("quiet"),
+        Arg::new("quiet")
+            .short('q')
+            .long("quiet")
+            .help("Log less detail: `-q` for warnings and errors only, `-qq` for errors only.")
+            .action(clap::ArgAction::Count),
+    ];
+    #[cfg(feature = "tracing")]
+    args.push(
+        Arg::new("log format")
+            .long("log-format")
+            .help("How to log: `plain` (the default), `spans` to log with `tracing`, timing each deployment phase, or `json` to do so as JSON.")
+            .required(false)
+            .num_args(1)
+            .value_parser(["plain", "spans", "json"]),
+    );
+
+    args
+}
+
+/// The ID of an already published RAMM package, for the stages that do not publish one.
+fn package_id_arg() -> Arg {
+    Arg::new("package id")
+        .long("package-id")
+        .help("Object ID of the published RAMM package to use, instead of the config's.")
+        .required(false)
+        .num_args(1)
+        .value_parser(clap::value_parser!(ObjectID))
+}
+
+/// The ID of an already created RAMM, for the stages that operate on one.
+fn ramm_id_arg() -> Arg {
+    Arg::new("ramm id")
+        .long("ramm")
+        .help("Object ID of the RAMM.")
+        .required(true)
+        .num_args(1)
+        .value_parser(clap::value_parser!(ObjectID))
+}
+
+/// Arguments that only apply to a `full` deployment, as their IDs and flags: they are rejected when
+/// any other subcommand is used, save for those in `PUBLISH_STAGE_ARGS`.
+const FULL_DEPLOYMENT_ARGS: [(&str, &str); 13] = [
+    ("package path", "--package-path"),
+    ("output format", "--output-format"),
+    ("dry run", "--dry-run"),
+    ("smoke test", "--smoke-test"),
+    ("seed liquidity", "--seed-liquidity"),
+    ("manifest", "--manifest"),
+    ("ts config", "--ts-config"),
+    ("env out", "--env-out"),
+    ("env append", "--env-append"),
+    ("unsigned out", "--unsigned-out"),
+    ("submit signed", "--submit-signed"),
+    ("checkpoint", "--checkpoint"),
+    ("resume", "--resume"),
+];
+
+/// Of the `FULL_DEPLOYMENT_ARGS`, those that the `publish` subcommand also accepts.
+const PUBLISH_STAGE_ARGS: [&str; 2] = ["manifest", "package path"];
+
+/// The name of the subcommand the user selected - `full` if none was - along with its arguments.
+fn stage_matches(deployer_m: &ArgMatches) -> (&str, &ArgMatches) {
+    deployer_m.subcommand().unwrap_or(("full", deployer_m))
+}
+
+/// The `deployer` command, from which both [`parse_cli_args`] parses the user's CLI input and
+/// [`write_completions`] generates shell completion scripts.
+pub fn cli_command() -> Command {
+    Command::new("deployer")
+        .about("Deploy a RAMM to a Sui target network with assets specified in a TOML config.")
+        .help_expected(true)
+        .args(deployment_args())
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
+        .subcommand(
+            Command::new("full")
+                .about("Publish the RAMM package, then create and populate each of the config's RAMMs. The default.")
+                .args(deployment_args()),
+        )
+        .subcommand(
+            Command::new("publish")
+                .about("Only publish (or upgrade) the RAMM package at the config's path.")
+                .args(deployment_args()),
+        )
+        .subcommand(
+            Command::new("create")
+                .about("Create and populate each of the config's RAMMs, from an already published package, without publishing it.")
+                .args(deployment_args())
+                .arg(package_id_arg())
+                .arg(
+                    Arg::new("no populate")
+                        .long("no-populate")
+                        .help("Only create the RAMMs, leaving them to be populated with the `populate` subcommand.")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("populate")
+                .about("Only add the assets of one of the config's RAMMs to an already created RAMM, and initialize it.")
+                .args(deployment_args())
+                .arg(package_id_arg())
+                .arg(ramm_id_arg())
+                .arg(
+                    Arg::new("admin cap")
+                        .long("admin-cap")
+                        .help("Object ID of the RAMM's admin capability.")
+                        .required(true)
+                        .num_args(1)
+                        .value_parser(clap::value_parser!(ObjectID)),
+                )
+                .arg(
+                    Arg::new("new asset cap")
+                        .long("new-asset-cap")
+                        .help("Object ID of the RAMM's new asset capability.")
+                        .required(true)
+                        .num_args(1)
+                        .value_parser(clap::value_parser!(ObjectID)),
+                )
+                .arg(
+                    Arg::new("ramm index")
+                        .long("ramm-index")
+                        .help("Position, starting at 1, of the RAMM's specification among the config's RAMMs.")
+                        .required(false)
+                        .num_args(1)
+                        .default_value("1")
+                        .value_parser(clap::value_parser!(usize).range(1..)),
+                ),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Only check the config, without accessing the network, and show it.")
+                .args(deployment_args().into_iter().filter(|arg| {
+                    ["TOML config", "format", "verbose", "quiet", "log format"]
+                        .contains(&arg.get_id().as_str())
+                })),
+        )
+        .subcommand(
+            Command::new("generate-config")
+                .about("Write a new config, from answers to a series of prompts, or scaffolded from a list of coin types.")
+                .args(deployment_args().into_iter().filter(|arg| {
+                    ["verbose", "quiet", "log format"].contains(&arg.get_id().as_str())
+                }))
+                .arg(
+                    Arg::new("out")
+                        .short('o')
+                        .long("out")
+                        .help("Path of the config to write; it must not exist yet.")
+                        .required(true)
+                        .num_args(1)
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("from coins")
+                        .long("from-coins")
+                        .help(
+                            "Comma-separated coin types from which to scaffold the RAMM's assets, \
+                            without prompting, reading their decimal places from the network. \
+                            Requires --target-env and --fee-address.",
+                        )
+                        .required(false)
+                        .num_args(1)
+                        .value_delimiter(',')
+                        .requires_all(["target env", "fee address"]),
+                )
+                .arg(
+                    Arg::new("target env")
+                        .long("target-env")
+                        .help("The config's target environment, instead of prompting for it.")
+                        .required(false)
+                        .num_args(1)
+                        .value_parser(TARGET_ENVS),
+                )
+                .arg(
+                    Arg::new("fee address")
+                        .long("fee-address")
+                        .help("The RAMM's fee collection address, instead of prompting for it.")
+                        .required(false)
+                        .num_args(1)
+                        .value_parser(clap::value_parser!(SuiAddress)),
+                )
+                .arg(
+                    Arg::new("package")
+                        .long("package")
+                        .help("The RAMM package's ID, or the path of its library, instead of prompting for it.")
+                        .required(false)
+                        .num_args(1),
+                ),
+        )
+        .subcommand(
+            Command::new("inspect")
+                .about("Query a RAMM on the config's target network, without submitting any tx.")
+                .args(deployment_args())
+                .arg(ramm_id_arg()),
+        )
+        .subcommand(
+            Command::new("schema")
+                .about("Print the JSON Schema of the TOML config to STDOUT, e.g. for editors to validate it against."),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Print a completion script for the given shell to STDOUT.")
+                .hide(true)
+                .arg(
+                    Arg::new("shell")
+                        .help("The shell to complete the deployer's arguments in.")
+                        .required(true)
+                        .num_args(1)
+                        .value_parser(["bash", "zsh", "fish"]),
+                ),
+        )
+        .no_binary_name(true)
+}
+
+/// Parse the user's CLI input from `main`'s `args` iterator.
+///
+/// This is done separately from [`deployment_cfg_from_args`], so that the log level requested via
+/// the CLI can be used to initialize logging before the config is parsed.
+///
+/// If no subcommand is given, the arguments are those of the `full` subcommand, i.e. the whole
+/// deployment is run.
+pub fn parse_cli_args(
+    args: impl Iterator<Item = OsString>,
+) -> Result<ArgMatches, RAMMDeploymentError> {
+    cli_command()
+        .try_get_matches_from(args)
+        .map_err(RAMMDeploymentError::CLIError)
+}
+
+/// The shell passed to the hidden `completions` subcommand, if it was the one selected.
+pub fn completions_shell(deployer_m: &ArgMatches) -> Option<Shell> {
+    let Some(("completions", completions_m)) = deployer_m.subcommand() else {
+        return None;
+    };
+
+    match completions_m.get_one::<String>("shell").unwrap().as_str() {
+        "bash" => Some(Shell::Bash),
+        "zsh" => Some(Shell::Zsh),
+        "fish" => Some(Shell::Fish),
+        _ => None,
+    }
+}
+
+/// Whether the `schema` subcommand was the one selected.
+pub fn schema_requested(deployer_m: &ArgMatches) -> bool {
+    matches!(deployer_m.subcommand(), Some(("schema", _)))
+}
+
+/// Write the completion script of the [`cli_command`] for the given shell to `out`, completing the
+/// arguments of the binary named `bin_name`.
+pub fn write_completions(shell: Shell, bin_name: &str, out: &mut dyn io::Write) {
+    clap_complete::generate(shell, &mut cli_command(), bin_name, out);
+}
+
+/// Map the CLI's `-v`/`-q` counts to the level at which to log, starting from `Info`: each `-v`
+/// raises it by one level, up to `Trace`, and each `-q` lowers it by one, down to `Error`.
+pub fn log_level_from_args(deployer_m: &ArgMatches) -> LevelFilter {
+    let (_, deployer_m) = stage_matches(deployer_m);
+    let verbose = i16::from(deployer_m.get_count("verbose"));
+    let quiet = i16::from(deployer_m.get_count("quiet"));
+
+    match verbose - quiet {
+        i16::MIN..=-2 => LevelFilter::Error,
+        -1 => LevelFilter::Warn,
+        0 => LevelFilter::Info,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// The `LogBackend` selected with the CLI's `--log-format`, which only exists with the `tracing`
+/// feature; `simplelog` is used otherwise.
+pub fn log_backend_from_args(deployer_m: &ArgMatches) -> LogBackend {
+    let (_, deployer_m) = stage_matches(deployer_m);
+    match deployer_m
+        .try_get_one::<String>("log format")
+        .ok()
+        .flatten()
+        .map(String::as_str)
+    {
+        #[cfg(feature = "tracing")]
+        Some("spans") => LogBackend::Tracing,
+        #[cfg(feature = "tracing")]
+        Some("json") => LogBackend::TracingJson,
+        _ => LogBackend::Simplelog,
+    }
+}
+
+/// Build a [`RAMMDeploymentConfig`], and the [`DeploymentOptions`] to use when deploying it,
+/// from the user's CLI input, as parsed by [`parse_cli_args`].
 ///
 /// This function performs IO. It does the following:
 ///
-/// 1. parse the user's CLI input from the `args` iterator
-/// 2. parse the RAMM's deployment config from the TOML file
-/// 3. check whether
+/// 1. parse the RAMM's deployment config from the TOML file, or from `STDIN` if its path is `-`,
+///    in which case `--yes` is required
+///    - the config may instead be JSON, if its path ends in `.json`, or `--format json` is passed
+/// 2. check whether
 ///
 ///    a. to use the config's address of an already published RAMM library, or
 ///
 ///    b. to publish the library residing at the filepath specified by the user
+/// 3. select the config's gas profile for the target network, and then override its gas budgets
+///    with any provided via the CLI
+/// 4. collect the remaining CLI flags into the deployment's runtime options, including the
+///    deployment stage selected by the subcommand, if any.
+///
+/// Each gas budget is taken from, in order of precedence:
+/// 1. its CLI flag (`--publish-gas`, `--create-gas`, `--populate-gas`),
+/// 2. the TOML config's `[gas.<network>]` profile for the target network,
+/// 3. the TOML config's `[gas]` table, or
+/// 4. the compiled-in default.
 pub fn deployment_cfg_from_args(
-    args: impl Iterator<Item = OsString>,
-) -> Result<RAMMDeploymentConfig, RAMMDeploymentError> {
-    let deployer = Command::new("deployer")
-        .about("Deploy a RAMM to a Sui target network with assets specified in a TOML config.")
-        .help_expected(true)
-        .arg(
-            Arg::new("TOML config")
-                .short('t')
-                .long("toml")
-                .help("Path to the TOML config containing the RAMM's deployment parameters.")
-                .required(true)
-                .num_args(1)
-                .value_parser(clap::value_parser!(PathBuf)),
-        )
-        .no_binary_name(true);
-    let deployer_m: ArgMatches = match deployer.try_get_matches_from(args) {
-        Err(err) => return Err(RAMMDeploymentError::CLIError(err)),
-        Ok(sub_cmd) => sub_cmd,
+    deployer_m: &ArgMatches,
+) -> Result<(RAMMDeploymentConfig, DeploymentOptions), RAMMDeploymentError> {
+    let (stage_name, deployer_m) = stage_matches(deployer_m);
+    if stage_name != "full" {
+        for (arg_id, flag) in FULL_DEPLOYMENT_ARGS {
+            if stage_name == "publish" && PUBLISH_STAGE_ARGS.contains(&arg_id) {
+                continue;
+            }
+            if deployer_m.value_source(arg_id) == Some(ValueSource::CommandLine) {
+                return Err(RAMMDeploymentError::OptionRequiresFullDeployment {
+                    flag,
+                    stage: stage_name.to_string(),
+                });
+            }
+        }
+    }
+
+    let (toml_path, format) = cfg_path_and_format(deployer_m)?;
+
+    // The user's confirmation of the config is read from `STDIN`, so it cannot also be the
+    // config's source - unless confirmation is skipped altogether.
+    if is_stdin_path(&toml_path) && !deployer_m.get_flag("assume yes") {
+        return Err(RAMMDeploymentError::StdinConfigWithoutAssumeYes);
+    }
+
+    // Parse the deployment config from the provided filepath.
+    let mut ramm_cfg = parse_ramm_cfg(toml_path, format)?;
+
+    // The library to publish may be given via the CLI, e.g. to run the tool from a directory the
+    // config's relative path does not resolve from.
+    if let Some(package_path) = deployer_m.get_one::<PathBuf>("package path") {
+        log::info!(
+            "Using the RAMM library at {} provided via the CLI.",
+            package_path.display()
+        );
+        ramm_cfg.ramm_pkg_addr_or_path = RAMMPkgAddrSrc::FromPkgPublication(package_path.clone());
+    }
+    // The stages that follow publication use an already published package, possibly one given via
+    // the CLI rather than the config.
+    if matches!(stage_name, "create" | "populate") {
+        if let Some(package_id) = deployer_m.get_one::<ObjectID>("package id") {
+            log::info!("Using RAMM package {package_id} provided via the CLI.");
+            ramm_cfg.ramm_pkg_addr_or_path = RAMMPkgAddrSrc::FromTomlConfig(*package_id);
+        }
+    }
+    let stage = match stage_name {
+        "publish" => DeploymentStage::Publish,
+        "create" => DeploymentStage::Create {
+            populate: !deployer_m.get_flag("no populate"),
+        },
+        "populate" => {
+            let ramm_index = *deployer_m.get_one::<usize>("ramm index").unwrap();
+            if ramm_index > ramm_cfg.ramms.len() {
+                return Err(RAMMDeploymentError::RAMMIndexOutOfRange {
+                    index: ramm_index,
+                    count: ramm_cfg.ramms.len(),
+                });
+            }
+            DeploymentStage::Populate {
+                ramm_index,
+                ramm_ids: RAMMObjectIDs {
+                    ramm: *deployer_m.get_one::<ObjectID>("ramm id").unwrap(),
+                    admin_cap: *deployer_m.get_one::<ObjectID>("admin cap").unwrap(),
+                    new_asset_cap: *deployer_m.get_one::<ObjectID>("new asset cap").unwrap(),
+                },
+            }
+        }
+        "inspect" => DeploymentStage::Inspect(*deployer_m.get_one::<ObjectID>("ramm id").unwrap()),
+        _ => DeploymentStage::Full,
     };
+    if matches!(
+        stage,
+        DeploymentStage::Create { .. } | DeploymentStage::Populate { .. }
+    ) {
+        if let RAMMPkgAddrSrc::FromPkgPublication(_) = ramm_cfg.ramm_pkg_addr_or_path {
+            return Err(RAMMDeploymentError::StageRequiresPackageId(
+                stage_name.to_string(),
+            ));
+        }
+    }
+
+    // A wrong path is reported now, rather than as a failure to build the library.
+    if matches!(stage, DeploymentStage::Full | DeploymentStage::Publish) {
+        if let RAMMPkgAddrSrc::FromPkgPublication(package_path) = &ramm_cfg.ramm_pkg_addr_or_path {
+            check_package_path(package_path)?;
+        }
+    }
+
+    // An upgrade requires the updated library's source, so the package must be given by path.
+    let upgrade_cap = deployer_m.get_one::<ObjectID>("upgrade cap").copied();
+    if upgrade_cap.is_some() {
+        if let RAMMPkgAddrSrc::FromTomlConfig(_) = ramm_cfg.ramm_pkg_addr_or_path {
+            return Err(RAMMDeploymentError::UpgradeWithoutPackagePath);
+        }
+    }
+
+    // `--localnet` targets the Suibase localnet workdir, whatever the config's environment.
+    if deployer_m.get_flag("localnet") {
+        ramm_cfg.target_env = LOCALNET.to_string();
+    }
+
+    // `--rpc-url` bypasses Suibase, which alone can resolve `active`.
+    if let Some(rpc_url) = deployer_m.get_one::<String>("rpc url") {
+        if ramm_cfg.target_env == "active" {
+            return Err(RAMMDeploymentError::RpcUrlWithActiveEnv);
+        }
+        ramm_cfg.network.rpc_url = Some(rpc_url.clone());
+    }
+
+    // Pick the gas profile for the network being targeted, before applying any CLI overrides.
+    let network = resolve_target_network(&ramm_cfg.target_env)?;
+    ramm_cfg.gas.select_profile(&network);
+
+    // Gas budgets provided via the CLI take precedence over the TOML's.
+    ramm_cfg.gas.apply_cli_overrides(GasProfile {
+        publication: deployer_m.get_one::<u64>("publish gas").copied(),
+        create_ramm: deployer_m.get_one::<u64>("create gas").copied(),
+        populate: deployer_m.get_one::<u64>("populate gas").copied(),
+    });
+    ramm_cfg
+        .gas
+        .check_budget_ceiling(deployer_m.get_flag("strict"))?;
+    if let Some(wait_for) = deployer_m
+        .get_one::<String>("wait for")
+        .and_then(|name| ExecutionWait::from_name(name))
+    {
+        ramm_cfg.network.wait_for = wait_for;
+    }
+    if let Some(price) = deployer_m.get_one::<u64>("gas price") {
+        log::info!("Using gas price of {price} MIST provided via the CLI.");
+        ramm_cfg.gas.price = Some(*price);
+    }
+
+    // A sponsor pays for every tx with its own gas coin.
+    if ramm_cfg.sponsor.is_some() && deployer_m.get_one::<ObjectID>("gas coin").is_some() {
+        return Err(RAMMDeploymentError::SponsorWithGasCoin);
+    }
+
+    // The smoke tests' coins are minted from the faucet.
+    let smoke_test = deployer_m.get_flag("smoke test");
+    let seed_liquidity = deployer_m.get_one::<u64>("seed liquidity").copied();
+    if (smoke_test || seed_liquidity.is_some()) && ramm_cfg.faucet.is_none() {
+        return Err(RAMMDeploymentError::SmokeTestWithoutFaucet);
+    }
 
+    let resume_path = deployer_m.get_one::<PathBuf>("resume").cloned();
+    let options = DeploymentOptions {
+        assume_yes: deployer_m.get_flag("assume yes"),
+        dry_run: deployer_m.get_flag("dry run"),
+        manifest_path: deployer_m.get_one::<PathBuf>("manifest").cloned(),
+        ts_config_path: deployer_m.get_one::<PathBuf>("ts config").cloned(),
+        env_out_path: deployer_m.get_one::<PathBuf>("env out").cloned(),
+        env_append: deployer_m.get_flag("env append"),
+        unsigned_out_dir: deployer_m.get_one::<PathBuf>("unsigned out").cloned(),
+        submit_signed: deployer_m
+            .get_many::<PathBuf>("submit signed")
+            .map(|mut paths| (paths.next().unwrap().clone(), paths.next().unwrap().clone())),
+        gas_coin: deployer_m.get_one::<ObjectID>("gas coin").copied(),
+        resume_path: resume_path.clone(),
+        // A resumed deployment keeps updating its checkpoint, unless told to write it elsewhere.
+        checkpoint_path: deployer_m
+            .get_one::<PathBuf>("checkpoint")
+            .cloned()
+            .or(resume_path),
+        upgrade_cap,
+        pkg_build: PkgBuildOptions {
+            no_cache: deployer_m.get_flag("no cache"),
+            bytecode_dir: deployer_m.get_one::<PathBuf>("bytecode dir").cloned(),
+            with_unpublished_deps: deployer_m.get_flag("with unpublished deps"),
+            deny_warnings: deployer_m.get_flag("strict"),
+        },
+        keystore_path: deployer_m.get_one::<PathBuf>("keystore").cloned(),
+        keystore_from_env: deployer_m.get_flag("keystore env"),
+        signer: match (
+            deployer_m.get_one::<SuiAddress>("address"),
+            deployer_m.get_one::<String>("alias"),
+        ) {
+            (Some(address), _) => Some(SignerSelection::Address(*address)),
+            (None, Some(alias)) => Some(SignerSelection::Alias(alias.clone())),
+            (None, None) => None,
+        },
+        explorer_url: deployer_m.get_one::<String>("explorer").cloned(),
+        strict: deployer_m.get_flag("strict"),
+        require_fresh_oracles: deployer_m.get_flag("require fresh oracles"),
+        force: deployer_m.get_flag("force"),
+        lockfile_path: deployer_m
+            .get_one::<PathBuf>("lockfile")
+            .cloned()
+            .unwrap_or_else(|| PathBuf::from(lockfile::DEFAULT_LOCKFILE_PATH)),
+        smoke_test,
+        seed_liquidity,
+        output_format: deployer_m
+            .get_one::<String>("output format")
+            .and_then(|name| OutputFormat::from_name(name))
+            .unwrap_or_default(),
+        stage,
+    };
+
+    Ok((ramm_cfg, options))
+}
+
+/// The path of the config given via the CLI, and the format in which it is written.
+fn cfg_path_and_format(
+    deployer_m: &ArgMatches,
+) -> Result<(PathBuf, ConfigFormat), RAMMDeploymentError> {
     let toml_path: PathBuf = match deployer_m.get_one::<PathBuf>("TOML config") {
         None => return Err(RAMMDeploymentError::NoTOMLConfigProvided),
         Some(input) => input.to_path_buf(),
     };
 
-    // Parse the deployment config from the provided filepath.
-    let ramm_cfg = parse_ramm_cfg(toml_path)?;
+    // An explicitly requested format takes precedence over the one implied by the extension.
+    let format = deployer_m
+        .get_one::<String>("format")
+        .and_then(|name| ConfigFormat::from_name(name))
+        .unwrap_or_else(|| ConfigFormat::from_path(&toml_path));
+
+    Ok((toml_path, format))
+}
+
+/// The arguments of the `validate` subcommand, if it was the one selected.
+pub fn validate_args(deployer_m: &ArgMatches) -> Option<&ArgMatches> {
+    match deployer_m.subcommand() {
+        Some(("validate", validate_m)) => Some(validate_m),
+        _ => None,
+    }
+}
+
+/// The options of the `generate-config` subcommand, if it was the one selected.
+pub fn generate_config_opts(deployer_m: &ArgMatches) -> Option<GenerateConfigOptions> {
+    let Some(("generate-config", generate_m)) = deployer_m.subcommand() else {
+        return None;
+    };
+
+    Some(GenerateConfigOptions {
+        out_path: generate_m.get_one::<PathBuf>("out").unwrap().clone(),
+        from_coins: generate_m
+            .get_many::<String>("from coins")
+            .map(|coin_types| coin_types.cloned().collect()),
+        target_env: generate_m.get_one::<String>("target env").cloned(),
+        fee_collection_address: generate_m.get_one::<SuiAddress>("fee address").copied(),
+        ramm_pkg_addr_or_path: generate_m.get_one::<String>("package").cloned(),
+    })
+}
 
-    Ok(ramm_cfg)
+/// The arguments of the selected stage, if `--print-config` was passed to it.
+pub fn print_config_args(deployer_m: &ArgMatches) -> Option<&ArgMatches> {
+    let (_, stage_m) = stage_matches(deployer_m);
+    match stage_m.try_get_one::<bool>("print config") {
+        Ok(Some(true)) => Some(stage_m),
+        _ => None,
+    }
+}
+
+/// Parse and validate the config named in the CLI arguments, and serialize it back into canonical
+/// TOML; see `RAMMDeploymentConfig::to_toml`.
+///
+/// The config is printed as written, i.e. without any of the CLI's overrides applied.
+pub fn config_toml_from_args(stage_m: &ArgMatches) -> Result<String, RAMMDeploymentError> {
+    let (toml_path, format) = cfg_path_and_format(stage_m)?;
+    parse_ramm_cfg(toml_path, format)?.to_toml()
+}
+
+/// Read the config named in the `validate` subcommand's arguments, and check it, without
+/// accessing the network in any way.
+///
+/// Unlike `deployment_cfg_from_args`, an invalid config is not an error: every problem found in
+/// it is logged, and it is returned as far as it could be normalized, along with whether it is
+/// valid, so that it can still be shown to the user.
+pub fn validate_cfg_from_args(
+    validate_m: &ArgMatches,
+) -> Result<(RAMMDeploymentConfig, bool), RAMMDeploymentError> {
+    let (toml_path, format) = cfg_path_and_format(validate_m)?;
+    let mut ramm_cfg = read_ramm_cfg(toml_path, format)?;
+
+    // Only a normalized config can be validated; both steps log the problems they find.
+    let valid = ramm_cfg.normalize_ramm_specs() && ramm_cfg.validate_ramm_cfg();
+    Ok((ramm_cfg, valid))
+}
+
+/// Render an amount of MIST in SUI, with all 9 of its decimal places.
+fn mist_to_sui(mist: u64) -> String {
+    format!("{}.{:09}", mist / MIST_PER_SUI, mist % MIST_PER_SUI)
 }
 
 pub enum UserAssent {
@@ -112,16 +1063,21 @@ pub enum UserAssent {
 
 /// This function:
 ///
-/// 1. Prints the RAMM deployment config parsed from the TOML to the user
+/// 1. Prints the RAMM deployment config parsed from the TOML to the user, along with the sum of
+///    its transactions' gas budgets, and a warning if any of its assets' price feeds, if they were
+///    read, are stale or unreadable
 /// 2. Asks the user to check if all its information is correct
 /// 3. Returns the appropriate value to be handled by the caller on whether to proceed with
 ///    program execution
 ///
+/// If `assume_yes` is set, the config is still printed, but step 2 is skipped and the config is
+/// accepted outright.
+///
 /// Warning, this function:
-/// * Reads from `STDIN`
+/// * Reads from `STDIN`, unless `assume_yes` is set
 /// * Writes to `STDOUT`
 /// * Uses [ANSI escape codes](https://en.wikipedia.org/wiki/ANSI_escape_code)
-pub fn user_assent_interaction(cfg: &RAMMDeploymentConfig) -> UserAssent {
+pub fn user_assent_interaction(cfg: &RAMMDeploymentConfig, assume_yes: bool) -> UserAssent {
     println!(
         "The following configuration will be used to {}, {} with assets, and {} a RAMM.",
         "create".bright_blue(),
@@ -130,6 +1086,39 @@ pub fn user_assent_interaction(cfg: &RAMMDeploymentConfig) -> UserAssent {
     );
     println!("Please, {} analyze it:", "carefully".on_red());
     println!("{}", cfg);
+    let unhealthy_feeds = cfg
+        .ramms
+        .iter()
+        .flat_map(|ramm_spec| ramm_spec.assets.iter())
+        .filter(|asset| match &asset.oracle_reading {
+            Some(oracle::OracleReading::Price(price)) => price.stale,
+            Some(oracle::OracleReading::Failed(_)) => true,
+            _ => false,
+        })
+        .count();
+    if unhealthy_feeds > 0 {
+        println!(
+            "{}: {} of the assets' price feeds are stale or could not be read; see above.",
+            "Warning".on_red(),
+            unhealthy_feeds
+        );
+    }
+    let total_budget = cfg.total_gas_budget();
+    println!(
+        "{}: {} SUI ({} MIST), summing the budgets of all of the deployment's transactions.",
+        "Maximum gas cost".on_red(),
+        mist_to_sui(total_budget),
+        total_budget
+    );
+    println!("The actual cost may be lower: only the gas that is used is charged.");
+    if assume_yes {
+        println!(
+            "{} with the displayed configuration, as confirmation was skipped with {}.",
+            "Proceeding".bright_blue(),
+            "--yes".bright_green()
+        );
+        return UserAssent::Accepted;
+    }
     println!("Is this information correct?");
     println!("Reply with {} or {}.", "\"yes\"".green(), "\"no\"".red());
     let mut input = String::new();
@@ -163,11 +1152,151 @@ pub fn user_assent_interaction(cfg: &RAMMDeploymentConfig) -> UserAssent {
     UserAssent::Accepted
 }
 
+/// Deploy every RAMM specified in the given config, from client creation through each RAMM's
+/// population, as the `deployer` binary's full deployment does - but without any interaction, so
+/// that the deployment can be embedded in another program.
+///
+/// As the config is never shown for confirmation, `dplymt_opts.assume_yes` must be set to assent
+/// to it; otherwise, `DeploymentNotAssented` is returned before anything is read from the network.
+/// Only a full deployment is run: any other `dplymt_opts.stage` is a `DeployRequiresFullStage`.
+///
+/// The config's gas profile for its target network is selected, and its budgets checked, as the
+/// CLI does; it is then completed as in `deployment::prepare_deployment`, and deployed as in
+/// `deployment::ramm_deployment`, whose report is returned - or `None`, for a dry run, or if the
+/// txs were exported unsigned. Ctrl-C is left to the embedding program to handle.
+pub async fn deploy(
+    mut dplymt_cfg: RAMMDeploymentConfig,
+    dplymt_opts: &DeploymentOptions,
+) -> Result<Option<DeploymentReport>, RAMMDeploymentError> {
+    if !dplymt_opts.assume_yes {
+        return Err(RAMMDeploymentError::DeploymentNotAssented);
+    }
+    if !matches!(dplymt_opts.stage, DeploymentStage::Full) {
+        return Err(RAMMDeploymentError::DeployRequiresFullStage);
+    }
+    if dplymt_cfg.network.rpc_url.is_none() {
+        check_suibase_workdir(&dplymt_cfg.target_env)?;
+    }
+
+    let network = resolve_target_network(&dplymt_cfg.target_env)?;
+    dplymt_cfg.gas.select_profile(&network);
+    dplymt_cfg.gas.check_budget_ceiling(dplymt_opts.strict)?;
+
+    deployment::prepare_deployment(&mut dplymt_cfg, dplymt_opts).await?;
+    deployment::ramm_deployment(dplymt_cfg, dplymt_opts, false).await
+}
+
+/// Check that the path of the RAMM library to publish is a directory holding a `Move.toml`, and
+/// return the name of the package it declares.
+///
+/// Relative paths are resolved from the working directory, not the config's; should the path be
+/// wrong, or its `Move.toml` lack a `[package]` name, `InvalidPackagePath` is returned, with the
+/// directory it was resolved to.
+pub fn check_package_path(package_path: &Path) -> Result<String, RAMMDeploymentError> {
+    let invalid = |reason: &str| RAMMDeploymentError::InvalidPackagePath {
+        path: package_path.to_path_buf(),
+        resolved: std::env::current_dir()
+            .map(|cwd| cwd.join(package_path))
+            .unwrap_or_else(|_| package_path.to_path_buf()),
+        reason: reason.to_string(),
+    };
+
+    if !package_path.is_dir() {
+        return Err(invalid("is not a directory"));
+    }
+    let move_toml = fs::read_to_string(package_path.join("Move.toml"))
+        .map_err(|_| invalid("has no readable `Move.toml`, so it is not a Sui Move package"))?;
+    let manifest: toml::Value = toml::from_str(&move_toml)
+        .map_err(|err| invalid(&format!("has a malformed `Move.toml`: {err}")))?;
+
+    manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| invalid("has a `Move.toml` without a `[package]` name"))
+}
+
+/// Check that each of the named addresses to be overridden is declared in the `[addresses]` of the
+/// `Move.toml` of the package at `package_path`, whose path must already have been checked with
+/// `check_package_path`.
+///
+/// Otherwise, `UnknownNamedAddresses` is returned, with every undeclared name, e.g. a misspelt one,
+/// which would leave the package built with another address layout than the one intended.
+fn check_named_addresses(
+    package_path: &Path,
+    package_name: &str,
+    named_addresses: &BTreeMap<String, SuiAddress>,
+) -> Result<(), RAMMDeploymentError> {
+    if named_addresses.is_empty() {
+        return Ok(());
+    }
+
+    let declared: BTreeSet<String> = fs::read_to_string(package_path.join("Move.toml"))
+        .ok()
+        .and_then(|move_toml| toml::from_str::<toml::Value>(&move_toml).ok())
+        .and_then(|manifest| {
+            manifest
+                .get("addresses")
+                .and_then(|addresses| addresses.as_table())
+                .map(|addresses| addresses.keys().cloned().collect())
+        })
+        .unwrap_or_default();
+    let unknown: Vec<String> = named_addresses
+        .keys()
+        .filter(|name| !declared.contains(*name))
+        .cloned()
+        .collect();
+    if !unknown.is_empty() {
+        return Err(RAMMDeploymentError::UnknownNamedAddresses {
+            package: package_name.to_string(),
+            names: unknown,
+            declared: declared.into_iter().collect(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Resolve the target environment from the config into the name of the Suibase workdir it
+/// designates, e.g. `active` into `testnet` if the latter is Suibase's active workdir.
+pub fn resolve_target_network(target_env: &str) -> Result<String, RAMMDeploymentError> {
+    // Any other environment names its network, so that, with `--rpc-url`, Suibase is not needed.
+    if target_env != "active" {
+        return Ok(target_env.to_string());
+    }
+
+    let suibase = Helper::new();
+    suibase
+        .select_workdir(target_env)
+        .map_err(RAMMDeploymentError::SuibaseWorkdirError)?;
+    let network = suibase
+        .workdir()
+        .map_err(RAMMDeploymentError::SuibaseWorkdirError)?;
+    log::info!("Target environment `{target_env}` resolved to network `{network}`.");
+
+    Ok(network)
+}
+
 /// Given an `&str` with the target environment, create a tuple with a Suibase helper, and a
 /// Sui client.
+///
+/// If `network_cfg` has an `rpc_url`, set with `--rpc-url`, Suibase is bypassed: no helper is
+/// returned, and the client connects to that URL, once it is checked to serve the target
+/// environment's chain, when that chain is known; see `check_rpc_url_chain`.
+///
+/// Connecting to the RPC endpoint is abandoned if it takes longer than `network_cfg`'s timeout.
 pub async fn get_suibase_and_sui_client(
     target_env: &str,
-) -> Result<(Helper, SuiClient), RAMMDeploymentError> {
+    network_cfg: &NetworkConfig,
+) -> Result<(Option<Helper>, SuiClient), RAMMDeploymentError> {
+    if let Some(rpc_url) = &network_cfg.rpc_url {
+        log::info!("Connecting to {rpc_url}, bypassing Suibase.");
+        let sui_client = build_sui_client(rpc_url, network_cfg).await?;
+        check_rpc_url_chain(&sui_client, rpc_url, target_env, network_cfg).await?;
+        return Ok((None, sui_client));
+    }
+
     let suibase = Helper::new();
     suibase
         .select_workdir(target_env)
@@ -176,24 +1305,212 @@ pub async fn get_suibase_and_sui_client(
     let rpc_url = suibase
         .rpc_url()
         .map_err(RAMMDeploymentError::RpcUrlSelectionError)?;
+    let sui_client = build_sui_client(&rpc_url, network_cfg).await?;
 
-    let sui_client = SuiClientBuilder::default()
-        .build(rpc_url)
-        .await
-        .map_err(RAMMDeploymentError::BuildSuiClientFromRpcUrlError)?;
+    Ok((Some(suibase), sui_client))
+}
 
-    Ok((suibase, sui_client))
+/// Connect to the full node at `rpc_url`, abandoning it after `network_cfg`'s timeout.
+async fn build_sui_client(
+    rpc_url: &str,
+    network_cfg: &NetworkConfig,
+) -> Result<SuiClient, RAMMDeploymentError> {
+    with_timeout(
+        network_cfg,
+        "connecting to the RPC endpoint",
+        SuiClientBuilder::default().build(rpc_url),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::BuildSuiClientFromRpcUrlError)
 }
 
-/// Given a `suibase::Helper`, fetch its keystore.
+/// Chain identifiers of the networks whose genesis never changes, unlike the devnet's and a
+/// localnet's: the first 4 bytes, in hex, of their genesis checkpoint's digest.
+const CHAIN_IDENTIFIERS: [(&str, &str); 2] = [("testnet", "4c78adac"), ("mainnet", "35834a8a")];
+
+/// Check that the full node at `rpc_url`, given with `--rpc-url`, serves the chain of the target
+/// environment, if it is one listed in `CHAIN_IDENTIFIERS`, so that e.g. a mainnet node is not
+/// used to deploy a config meant for the testnet.
+async fn check_rpc_url_chain(
+    sui_client: &SuiClient,
+    rpc_url: &str,
+    target_env: &str,
+    network_cfg: &NetworkConfig,
+) -> Result<(), RAMMDeploymentError> {
+    let Some((_, expected)) = CHAIN_IDENTIFIERS
+        .iter()
+        .find(|(network, _)| *network == target_env)
+    else {
+        log::warn!("Not checking that {rpc_url} serves `{target_env}`, whose chain may be reset.");
+        return Ok(());
+    };
+
+    let chain_id = with_timeout(
+        network_cfg,
+        "fetching the RPC endpoint's chain identifier",
+        sui_client.read_api().get_chain_identifier(),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::ChainIdentifierQueryError)?;
+    if chain_id != *expected {
+        return Err(RAMMDeploymentError::RpcUrlNetworkMismatch {
+            url: rpc_url.to_string(),
+            target_env: target_env.to_string(),
+            chain_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Check that the Suibase workdir for `target_env` is set up: that it can be selected, and that it
+/// has an RPC URL.
+///
+/// This is done before anything else, so that a missing or misconfigured workdir is reported by
+/// name, instead of as a failure to connect to the network midway through.
+pub fn check_suibase_workdir(target_env: &str) -> Result<(), RAMMDeploymentError> {
+    let unavailable = |e: suibase::Error| RAMMDeploymentError::SuibaseWorkdirUnavailable {
+        workdir: target_env.to_string(),
+        reason: e.to_string(),
+    };
+
+    let suibase = Helper::new();
+    suibase.select_workdir(target_env).map_err(unavailable)?;
+    suibase.rpc_url().map_err(unavailable)?;
+
+    Ok(())
+}
+
+/// The env variable from which `--keystore-env` reads the private key to sign txs with.
+pub const KEYSTORE_ENV_VAR: &str = "RAMM_DEPLOY_KEY";
+
+/// Read the private key in the `KEYSTORE_ENV_VAR` env variable, encoded in base64 as in a Sui
+/// keystore file, i.e. its scheme's flag byte followed by the key's bytes.
+///
+/// The copy of the encoded key read from the environment is zeroized once decoded; the key pair
+/// zeroizes its own bytes when dropped.
+pub fn read_env_keypair() -> Result<SuiKeyPair, RAMMDeploymentError> {
+    let mut encoded_key =
+        std::env::var(KEYSTORE_ENV_VAR).map_err(|_| RAMMDeploymentError::EnvKeyError {
+            var: KEYSTORE_ENV_VAR,
+            reason: "it is not set, or is not valid unicode",
+        })?;
+    let keypair = SuiKeyPair::decode_base64(encoded_key.trim());
+    encoded_key.zeroize();
+
+    // The decoding error is not forwarded, lest it quote the key.
+    keypair.map_err(|_| RAMMDeploymentError::EnvKeyError {
+        var: KEYSTORE_ENV_VAR,
+        reason: "it is not a base64-encoded Sui private key",
+    })
+}
+
+/// The address that will sign the deployment's txs, and send its PTBs:
+/// * the one selected with `--address` or `--alias`, the latter resolved by the given
+///   `suibase::Helper`, if any,
+/// * otherwise, that of the key in `KEYSTORE_ENV_VAR`, if `keystore_from_env` is set,
+/// * or, failing that, the active address of the `suibase::Helper`'s workdir.
+///
+/// Without a helper, i.e. with `--rpc-url`, the address must be given with `--address` or
+/// `--keystore-env`.
+///
+/// Whether the keystore holds a key for the address is only checked by `get_keystore`.
+pub fn get_signer_address(
+    suibase: Option<&Helper>,
+    keystore_from_env: bool,
+    signer: Option<&SignerSelection>,
+) -> Result<SuiAddress, RAMMDeploymentError> {
+    match (signer, suibase) {
+        (Some(SignerSelection::Address(address)), _) => return Ok(*address),
+        (Some(SignerSelection::Alias(_)), None) => {
+            return Err(RAMMDeploymentError::SuibaseBypassed(
+                "the signer can not be selected with --alias",
+            ))
+        }
+        (Some(SignerSelection::Alias(alias)), Some(suibase)) => {
+            return suibase.client_sui_address(alias).map_err(|error| {
+                RAMMDeploymentError::SignerAliasError {
+                    alias: alias.clone(),
+                    error,
+                }
+            })
+        }
+        (None, _) => {}
+    }
+    if keystore_from_env {
+        return Ok(SuiAddress::from(&read_env_keypair()?.public()));
+    }
+
+    suibase
+        .ok_or(RAMMDeploymentError::SuibaseBypassed(
+            "the signer must be selected with --address or --keystore-env",
+        ))?
+        .client_sui_address("active")
+        .map_err(RAMMDeploymentError::SuiClientActiveAddressError)
+}
+
+/// Fetch the keystore with which to sign txs:
+/// * an in-memory keystore holding only the key in `KEYSTORE_ENV_VAR`, if `keystore_from_env` is
+///   set, so that no keystore file need be written, e.g. in an ephemeral CI runner,
+/// * otherwise, the file-based keystore at `keystore_path` if one was provided, or that of the
+///   given `suibase::Helper`'s workdir, which, with `--rpc-url`, there is not.
 ///
 /// A keystore is required, along with access to an address and its private keys,
-/// to sign transactions for execution in the network.
-pub fn get_keystore(suibase: &Helper) -> Result<Keystore, RAMMDeploymentError> {
-    let keystore_pathname = suibase
-        .keystore_pathname()
-        .map_err(RAMMDeploymentError::KeystorePathnameError)?;
-    let keystore_pathbuf = PathBuf::from(keystore_pathname);
+/// to sign transactions for execution in the network: the keystore must hold a key for
+/// `signer_address`, as returned by `get_signer_address`, or a `SignerNotInKeystore` error is
+/// returned before any tx is signed.
+pub fn get_keystore(
+    suibase: Option<&Helper>,
+    keystore_path: Option<&Path>,
+    keystore_from_env: bool,
+    signer_address: SuiAddress,
+) -> Result<Keystore, RAMMDeploymentError> {
+    let keystore = open_keystore(suibase, keystore_path, keystore_from_env)?;
+    if !keystore.addresses().contains(&signer_address) {
+        return Err(RAMMDeploymentError::SignerNotInKeystore(signer_address));
+    }
+
+    Ok(keystore)
+}
+
+/// Open the keystore selected by `get_keystore`'s arguments.
+fn open_keystore(
+    suibase: Option<&Helper>,
+    keystore_path: Option<&Path>,
+    keystore_from_env: bool,
+) -> Result<Keystore, RAMMDeploymentError> {
+    if keystore_from_env {
+        log::info!("Using the private key in the {KEYSTORE_ENV_VAR} env variable.");
+        let mut keystore = InMemKeystore::default();
+        keystore
+            .add_key(None, read_env_keypair()?)
+            .map_err(RAMMDeploymentError::KeystoreOpenError)?;
+        return Ok(Keystore::InMem(keystore));
+    }
+
+    let keystore_pathbuf = match keystore_path {
+        Some(path) => {
+            log::info!("Using keystore provided via the CLI: {}", path.display());
+            // `FileBasedKeystore::new` would silently create an empty keystore at a missing path.
+            if !path.is_file() {
+                return Err(RAMMDeploymentError::KeystoreOpenError(anyhow::anyhow!(
+                    "no keystore file at {}",
+                    path.display()
+                )));
+            }
+            path.to_path_buf()
+        }
+        None => {
+            let keystore_pathname = suibase
+                .ok_or(RAMMDeploymentError::SuibaseBypassed(
+                    "the keystore must be given with --keystore or --keystore-env",
+                ))?
+                .keystore_pathname()
+                .map_err(RAMMDeploymentError::KeystorePathnameError)?;
+            log::info!("Using Suibase workdir's keystore: {keystore_pathname}");
+            PathBuf::from(keystore_pathname)
+        }
+    };
 
     FileBasedKeystore::new(&keystore_pathbuf)
         .map(Keystore::File)
@@ -204,56 +1521,325 @@ pub fn get_keystore(suibase: &Helper) -> Result<Keystore, RAMMDeploymentError> {
 Transaction creation and signing
 */
 
-/// Given the path to a Sui Move library for the RAMM, create a Sui transaction datum
-/// to be signed and submitted to the network.
+/// Compile the Sui Move library for the RAMM at the given path, for its publication or upgrade.
+///
+/// The path is first checked with `check_package_path`, and the name of the package it holds is
+/// logged, so that the operator can confirm it is the intended one.
+///
+/// If `pkg_build.bytecode_dir` is set, the library is not compiled: its prebuilt artifacts are
+/// read from that directory instead.
+///
+/// Unless `pkg_build.no_cache` is set, the artifacts of a previous compilation of the library are
+/// reused if its sources have not changed since. Either way, freshly compiled artifacts are
+/// cached.
+///
+/// A library with unpublished dependencies is only built if `pkg_build.with_unpublished_deps` is
+/// set, in which case they are bundled into its package; otherwise, `UnpublishedDependencies` is
+/// returned.
+fn build_ramm_pkg(
+    package_path: PathBuf,
+    pkg_build: &PkgBuildOptions,
+    named_addresses: &BTreeMap<String, SuiAddress>,
+) -> Result<PackageArtifacts, RAMMDeploymentError> {
+    if let Some(bytecode_dir) = &pkg_build.bytecode_dir {
+        if !named_addresses.is_empty() {
+            log::warn!(
+                "The config's `[named_addresses]` are ignored: prebuilt bytecode is used as is."
+            );
+        }
+        return pkg_cache::read_bytecode_dir(bytecode_dir);
+    }
+
+    // Checked before building, so that a wrong path is not reported as an obscure build error.
+    let package_name = check_package_path(&package_path)?;
+    check_named_addresses(&package_path, &package_name, named_addresses)?;
+    log::info!(
+        "Building Sui Move package `{package_name}` from {}.",
+        package_path.display()
+    );
+
+    let with_unpublished_deps = pkg_build.with_unpublished_deps;
+    if with_unpublished_deps {
+        log::warn!(
+            "Publishing the RAMM library with its unpublished dependencies: this is meant for \
+            development and testing only."
+        );
+    }
+
+    if !pkg_build.no_cache {
+        if let Some(artifacts) =
+            pkg_cache::read_cached(&package_path, with_unpublished_deps, named_addresses)
+        {
+            log::info!("Reusing the cached compilation of the RAMM library.");
+            report_build_warnings(&artifacts.build_warnings, pkg_build.deny_warnings)?;
+            return Ok(artifacts);
+        }
+    }
+
+    for (name, address) in named_addresses {
+        log::info!("Building with the named address `{name}` set to {address}.");
+    }
+    let build_warnings =
+        check_build_diagnostics(ramm_build_config(named_addresses), &package_path)?;
+    report_build_warnings(&build_warnings, pkg_build.deny_warnings)?;
+
+    let compiled_ramm_package: CompiledPackage = ramm_build_config(named_addresses)
+        .build(package_path.clone())
+        .map_err(|err| RAMMDeploymentError::PkgBuildError(err.to_string()))?;
+    log::info!("Compiled RAMM library.");
+
+    let unpublished_deps = &compiled_ramm_package.dependency_ids.unpublished;
+    if !unpublished_deps.is_empty() && !with_unpublished_deps {
+        return Err(RAMMDeploymentError::UnpublishedDependencies(
+            unpublished_deps.iter().map(|dep| dep.to_string()).collect(),
+        ));
+    }
+
+    let mut artifacts =
+        PackageArtifacts::from_compiled(&compiled_ramm_package, with_unpublished_deps);
+    artifacts.build_warnings = build_warnings;
+    pkg_cache::write_cached(
+        &package_path,
+        with_unpublished_deps,
+        named_addresses,
+        &artifacts,
+    );
+
+    Ok(artifacts)
+}
+
+/// The configuration with which the RAMM library is built, with the given `named_addresses`
+/// overriding those of its `Move.toml`.
+fn ramm_build_config(named_addresses: &BTreeMap<String, SuiAddress>) -> BuildConfig {
+    let mut build_config: BuildConfig = Default::default();
+    build_config.config.additional_named_addresses = named_addresses
+        .iter()
+        .map(|(name, address)| (name.clone(), AccountAddress::from(*address)))
+        .collect();
+    build_config
+}
+
+/// Compile the RAMM library at `package_path` with `build_config`, only to collect the Move
+/// compiler's diagnostics, which `BuildConfig::build` writes to `stderr` instead of returning.
+///
+/// Warnings, e.g. of deprecations, are returned, rendered, for `report_build_warnings`; errors are
+/// returned in full, as a `PkgBuildError`, instead of a terse compilation failure.
+fn check_build_diagnostics(
+    build_config: BuildConfig,
+    package_path: &Path,
+) -> Result<String, RAMMDeploymentError> {
+    let resolution_graph = build_config
+        .resolution_graph(package_path)
+        .map_err(|err| RAMMDeploymentError::PkgBuildError(err.to_string()))?;
+    let build_plan = BuildPlan::create(resolution_graph)
+        .map_err(|err| RAMMDeploymentError::PkgBuildError(format!("{err:?}")))?;
+
+    let render = |files: &FilesSourceText, diags: Diagnostics| {
+        String::from_utf8_lossy(&report_diagnostics_to_buffer(files, diags)).into_owned()
+    };
+    let mut warnings = String::new();
+    let mut errors = String::new();
+    let compiled = build_plan.compile_with_driver(&mut io::sink(), |compiler| {
+        let (files, units) = compiler.build()?;
+        match units {
+            Ok((units, warning_diags)) => {
+                warnings = render(&files, warning_diags);
+                Ok((files, units))
+            }
+            Err(error_diags) => {
+                errors = render(&files, error_diags);
+                anyhow::bail!("Compilation error")
+            }
+        }
+    });
+
+    if let Err(err) = compiled {
+        return Err(RAMMDeploymentError::PkgBuildError(
+            match errors.trim_end() {
+                "" => format!("{err:?}"),
+                errors => format!("\n{errors}"),
+            },
+        ));
+    }
+
+    Ok(warnings.trim_end().to_string())
+}
+
+/// Log the Move compiler's warnings from building the RAMM library, if it reported any, at `warn`
+/// level even though the build succeeded - or, if `deny_warnings` is set, as under `--strict`,
+/// return them as a `PkgBuildWarnings` error.
+fn report_build_warnings(warnings: &str, deny_warnings: bool) -> Result<(), RAMMDeploymentError> {
+    if warnings.trim().is_empty() {
+        return Ok(());
+    }
+    if deny_warnings {
+        return Err(RAMMDeploymentError::PkgBuildWarnings(warnings.to_string()));
+    }
+    log::warn!("The Move compiler reported warnings while building the RAMM library:\n{warnings}");
+
+    Ok(())
+}
+
+/// Check that each of the published packages a compiled RAMM library depends on exists on the
+/// network the client is connected to.
+///
+/// Otherwise, `MissingDependency` is returned, naming the first missing package: publishing a
+/// library built against another network's dependencies would fail without saying which.
+async fn check_dependencies_exist(
+    sui_client: &SuiClient,
+    artifacts: &PackageArtifacts,
+) -> Result<(), RAMMDeploymentError> {
+    let dep_objs = sui_client
+        .read_api()
+        .multi_get_object_with_options(artifacts.dep_ids.clone(), SuiObjectDataOptions::new())
+        .await
+        .map_err(RAMMDeploymentError::DependencyQueryError)?;
+
+    for (dep_id, dep_obj) in artifacts.dep_ids.iter().zip(dep_objs) {
+        if dep_obj.object().is_err() {
+            return Err(RAMMDeploymentError::MissingDependency {
+                package: artifacts
+                    .dep_names
+                    .get(dep_id)
+                    .cloned()
+                    .unwrap_or_else(|| "<unnamed>".to_string()),
+                id: *dep_id,
+            });
+        }
+    }
+    log::info!(
+        "All {} of the RAMM library's dependencies exist on the target network.",
+        artifacts.dep_ids.len()
+    );
+
+    Ok(())
+}
+
+/// Given the path to a Sui Move library for the RAMM, and the gas budget to be used in its
+/// publication, create a Sui transaction datum to be signed and submitted to the network.
+///
+/// The library is built with the given `named_addresses` overriding those of its `Move.toml`.
+///
+/// If `gas_coin` is `None`, the client selects a gas coin on its own. With a `sponsor`, the tx is
+/// sponsored by it; see `sponsor_tx`.
 pub async fn publish_tx(
     sui_client: &SuiClient,
     package_path: PathBuf,
+    pkg_build: &PkgBuildOptions,
+    named_addresses: &BTreeMap<String, SuiAddress>,
     client_address: SuiAddress,
+    gas_budget: u64,
+    gas_coin: Option<ObjectID>,
+    sponsor: Option<&SponsorConfig>,
 ) -> Result<TransactionData, RAMMDeploymentError> {
-    let build_config: BuildConfig = Default::default();
-
-    let compiled_ramm_package: CompiledPackage = build_config
-        .build(package_path)
-        .map_err(RAMMDeploymentError::PkgBuildError)?;
-    log::info!("Compiled RAMM library.");
-
-    // The RAMM library has no unpublished deps - it depends on
-    // 1. `move_stdlib`,
-    // 2. `sui_framework`, and
-    // 3. `switchboard`
-    // which are all published.
-    let ramm_compiled_modules: Vec<Vec<u8>> =
-        compiled_ramm_package.get_package_bytes(/* with_unpublished_deps */ false);
-
-    let ramm_dep_ids: Vec<ObjectID> = compiled_ramm_package
-        .dependency_ids
-        .published
-        .values()
-        .cloned()
-        .collect::<Vec<_>>();
+    let artifacts = build_ramm_pkg(package_path, pkg_build, named_addresses)?;
+    check_dependencies_exist(sui_client, &artifacts).await?;
 
     sui_client
         .transaction_builder()
         .publish(
             client_address,
-            ramm_compiled_modules,
-            ramm_dep_ids,
+            artifacts.modules,
+            artifacts.dep_ids,
             // Recall that choosing `None` allows the client to choose a gas object instead of
             // the user.
-            None,
-            PACKAGE_PUBLICATION_GAS_BUDGET,
+            gas_coin,
+            gas_budget,
         )
         .await
+        .map(|tx_data| sponsor_tx(tx_data, sponsor))
         .map_err(RAMMDeploymentError::PublishTxError)
 }
 
-async fn new_ramm_tx(
+/// Given the ID of an `UpgradeCap`, fetch the ID of the package it can upgrade - that is, the
+/// latest version of the RAMM package.
+pub async fn get_upgradable_pkg_id(
+    sui_client: &SuiClient,
+    upgrade_cap: ObjectID,
+) -> Result<ObjectID, RAMMDeploymentError> {
+    let cap_obj = sui_client
+        .read_api()
+        .get_object_with_options(upgrade_cap, SuiObjectDataOptions::new().with_content())
+        .await
+        .map_err(RAMMDeploymentError::UpgradeCapQueryError)?;
+
+    match cap_obj.object().ok().and_then(|data| data.content.as_ref()) {
+        Some(SuiParsedData::MoveObject(cap)) => {
+            match cap.fields.read_dynamic_field_value("package") {
+                Some(SuiMoveValue::Address(pkg_addr)) => Ok(pkg_addr.into()),
+                _ => Err(RAMMDeploymentError::InvalidUpgradeCap(upgrade_cap)),
+            }
+        }
+        _ => Err(RAMMDeploymentError::InvalidUpgradeCap(upgrade_cap)),
+    }
+}
+
+/// Given the path to an updated Sui Move library for the RAMM, the ID of the package it upgrades
+/// and the `UpgradeCap` that allows it, create the transaction that upgrades the package, to be
+/// signed and submitted to the network.
+///
+/// The library at `package_path` must be compiled against the package being upgraded, i.e. its
+/// `Move.toml` must set `published-at` (and its own address) accordingly.
+///
+/// As in `publish_tx`, the library is built with the given `named_addresses` overrides.
+///
+/// If `gas_coin` is `None`, the client selects a gas coin on its own. With a `sponsor`, the tx is
+/// sponsored by it; see `sponsor_tx`.
+pub async fn upgrade_tx(
+    sui_client: &SuiClient,
+    package_path: PathBuf,
+    pkg_build: &PkgBuildOptions,
+    named_addresses: &BTreeMap<String, SuiAddress>,
+    client_address: SuiAddress,
+    package_id: ObjectID,
+    upgrade_cap: ObjectID,
+    gas_budget: u64,
+    gas_coin: Option<ObjectID>,
+    sponsor: Option<&SponsorConfig>,
+) -> Result<TransactionData, RAMMDeploymentError> {
+    let artifacts = build_ramm_pkg(package_path, pkg_build, named_addresses)?;
+    check_dependencies_exist(sui_client, &artifacts).await?;
+
+    sui_client
+        .transaction_builder()
+        .upgrade(
+            client_address,
+            package_id,
+            artifacts.modules,
+            artifacts.dep_ids,
+            upgrade_cap,
+            UpgradePolicy::COMPATIBLE as u8,
+            artifacts.digest,
+            gas_coin,
+            gas_budget,
+        )
+        .await
+        .map(|tx_data| sponsor_tx(tx_data, sponsor))
+        .map_err(RAMMDeploymentError::UpgradeTxError)
+}
+
+/// Given a `SuiClient`, a deployment config, the specification of one of its RAMMs, and the ID of
+/// a published RAMM package, create the transaction that calls `ramm_sui::new_ramm`, to be signed
+/// and submitted to the network.
+///
+/// If `gas_coin` is `None`, the client selects a gas coin on its own. If the config has a
+/// sponsor, the tx is sponsored by it; see `sponsor_tx`.
+pub async fn new_ramm_tx(
     sui_client: &SuiClient,
     dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
     client_address: &SuiAddress,
     ramm_pkg_id: ObjectID,
+    gas_coin: Option<ObjectID>,
 ) -> Result<TransactionData, RAMMDeploymentError> {
+    let fee_collection_address =
+        SuiJsonValue::from_str(&ramm_spec.fee_collection_address.to_string()).map_err(|err| {
+            RAMMDeploymentError::NewRammTxError(err.context(format!(
+                "fee collection address {}",
+                ramm_spec.fee_collection_address
+            )))
+        })?;
+
     sui_client
         .transaction_builder()
         .move_call(
@@ -262,97 +1848,435 @@ async fn new_ramm_tx(
             RAMM_MODULE_NAME.as_str(),
             "new_ramm",
             vec![],
-            vec![SuiJsonValue::from_str(&dplymt_cfg.fee_collection_address.to_string()).unwrap()],
-            None,
-            CREATE_RAMM_GAS_BUDGET,
+            vec![fee_collection_address],
+            gas_coin,
+            dplymt_cfg.gas.create_ramm_budget(),
         )
         .await
+        .map(|tx_data| sponsor_tx(tx_data, dplymt_cfg.sponsor.as_ref()))
         .map_err(RAMMDeploymentError::NewRammTxError)
 }
 
+/// Given a `SuiClient` and a transaction's structured data, dry run the transaction to estimate
+/// its gas cost, and return the same transaction with its budget set to the estimate, scaled by
+/// `safety_margin` - see `GasConfig::safety_margin`.
+///
+/// The estimate is the dry run's computation and storage costs, before its storage rebate: Sui
+/// requires the budget to cover both, however much storage the tx then frees.
+///
+/// The budget the transaction was built with is used to perform the dry run; it thus remains an
+/// upper bound on the transaction's cost, and the margin never raises the budget past it. Should
+/// the scaled estimate exceed it, a warning is logged, as the tx may then run out of gas.
+///
+/// If the dry run reports a Move abort, `RAMMDeploymentError::MoveAbort` is returned; if it
+/// reports any other failure, `RAMMDeploymentError::DryRunFailed`. Either way, no gas is spent.
+pub async fn estimate_gas_budget(
+    sui_client: &SuiClient,
+    mut tx_data: TransactionData,
+    safety_margin: f64,
+) -> Result<TransactionData, RAMMDeploymentError> {
+    let dry_run_response = sui_client
+        .read_api()
+        .dry_run_transaction_block(tx_data.clone())
+        .await
+        .map_err(RAMMDeploymentError::DryRunQueryError)?;
+
+    if let SuiExecutionStatus::Failure { error } = dry_run_response.effects.status() {
+        return Err(tx_status::parse_move_abort(error)
+            .unwrap_or_else(|| RAMMDeploymentError::DryRunFailed(error.clone())));
+    }
+
+    let gas_summary = dry_run_response.effects.gas_cost_summary();
+    let gas_cost: u64 = gas_summary.computation_cost + gas_summary.storage_cost;
+    let scaled_estimate = (gas_cost as f64 * safety_margin).ceil() as u64;
+    if scaled_estimate > tx_data.gas_budget() {
+        log::warn!(
+            "The dry run's cost of {gas_cost} MIST, with a safety margin of {safety_margin}, is \
+            {scaled_estimate} MIST, above the configured budget of {} MIST: the budget is capped \
+            there, and the tx may run out of gas. Consider raising the budget in the config.",
+            tx_data.gas_budget()
+        );
+    }
+    let estimated_budget = scaled_estimate.min(tx_data.gas_budget());
+    log::info!(
+        "Dry run cost {gas_cost} MIST before its storage rebate; with a safety margin of \
+        {safety_margin}, setting gas budget to {estimated_budget} MIST (was {}).",
+        tx_data.gas_budget()
+    );
+    tx_data.gas_data_mut().budget = estimated_budget;
+
+    Ok(tx_data)
+}
+
+/// Stand-in for `sign_and_execute_tx` used in dry-run mode: instead of signing the transaction
+/// and submitting it to the network, show what would have been executed.
+pub fn log_would_execute(tx_name: &str, tx_data: &TransactionData) {
+    log::info!(
+        "Dry run: would execute {tx_name} tx, with a gas budget of {} MIST.",
+        tx_data.gas_budget()
+    );
+    println!(
+        "{} {}:\n{:#?}",
+        tx_name.bright_blue(),
+        "tx data".bright_blue(),
+        tx_data
+    );
+}
+
+/// Await a network call, failing with `RAMMDeploymentError::NetworkTimeout` should it not
+/// complete within `network_cfg`'s timeout.
+///
+/// `operation` describes the call in the error, e.g. "fetching the reference gas price".
+async fn with_timeout<F: Future>(
+    network_cfg: &NetworkConfig,
+    operation: &str,
+    call: F,
+) -> Result<F::Output, RAMMDeploymentError> {
+    tokio::time::timeout(network_cfg.timeout(), call)
+        .await
+        .map_err(|_| RAMMDeploymentError::NetworkTimeout {
+            operation: operation.to_string(),
+            timeout_secs: network_cfg.timeout_secs,
+        })
+}
+
+/// Whether an error returned by the SDK when submitting a transaction is transient, i.e. whether
+/// resubmitting the very same transaction might succeed.
+///
+/// Only RPC failures that the request never reached - or was never answered by - the node are
+/// considered transient: transport errors (connection resets, 5xx responses), request timeouts,
+/// closed connections, and calls the node rejected because it is busy, or could not execute the tx
+/// for the time being. So are failures to confirm a tx's status.
+/// Calls the node rejected on their merits, e.g. for an invalid signature or an already used
+/// object version, are not: resubmitting the same tx would fail again.
+///
+/// Note that a Move abort is *not* an SDK error: the transaction is executed, and its failure is
+/// reported in its effects, so it will never be retried.
+fn is_transient_tx_error(err: &sui_sdk::error::Error) -> bool {
+    match err {
+        sui_sdk::error::Error::RpcError(rpc_err) => match rpc_err {
+            RpcError::Transport(_) | RpcError::RequestTimeout | RpcError::RestartNeeded(_) => true,
+            RpcError::Call(CallError::Custom(err_obj)) => {
+                err_obj.code() == ErrorCode::ServerIsBusy.code()
+                    || err_obj.code() == SUI_TRANSIENT_ERROR_CODE
+            }
+            _ => false,
+        },
+        sui_sdk::error::Error::FailToConfirmTransactionStatus(..) => true,
+        _ => false,
+    }
+}
+
 /// Given
 /// * an instance of a Sui client, through which a tx will be sent to the network,
 /// * a keystore (to access an address' private/public keys)
-/// * a transaction's structured data, and
+/// * a transaction's structured data,
 /// * the address with which the tx is to be signed,
+/// * the policy with which to retry the tx's submission, and
+/// * the network config, whose timeout applies to each submission attempt, and whose `wait_for`
+///   mode sets the submission's request type,
 ///
 /// sign the transaction with the given key, and submit it, along with its signature, to the
-/// network for validation and inclusion in the ledger
+/// network for validation and inclusion in the ledger, via `execute_tx`.
+///
+/// A sponsored transaction, i.e. one whose gas is owned by another address than `client_address`,
+/// is also signed by its sponsor, whose key must then be in the same keystore; see `sponsor_tx`.
+///
+/// The transaction is signed only once: if its submission fails with a transient error, the
+/// *same* signed transaction is resubmitted.
 pub async fn sign_and_execute_tx(
-    sui_client: &SuiClient,
+    sui_client: &impl DeploymentClient,
     keystore: &Keystore,
     tx_data: TransactionData,
     client_address: &SuiAddress,
+    retry_policy: &RetryPolicy,
+    network_cfg: &NetworkConfig,
 ) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
-    let signature = keystore
-        .sign_secure(client_address, &tx_data, Intent::sui_transaction())
+    let mut signers = vec![*client_address];
+    if tx_data.gas_owner() != *client_address {
+        signers.push(tx_data.gas_owner());
+    }
+    let signatures = signers
+        .iter()
+        .map(|signer| keystore.sign_secure(signer, &tx_data, Intent::sui_transaction()))
+        .collect::<Result<Vec<_>, _>>()
         .map_err(RAMMDeploymentError::TxSignatureError)?;
 
-    let tx = Transaction::from_data(tx_data, Intent::sui_transaction(), vec![signature]);
+    let tx = Transaction::from_data(tx_data, Intent::sui_transaction(), signatures);
 
-    sui_client
-        .quorum_driver_api()
-        .execute_transaction_block(
-            tx,
-            SuiTransactionBlockResponseOptions::new().with_effects(),
-            Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+    execute_tx(sui_client, tx, retry_policy, network_cfg).await
+}
+
+/// Have `sponsor`, if there is one, pay for the given tx's gas, by making it the owner of the tx's
+/// gas; the tx must already pay with the sponsor's gas coin, as `RAMMDeploymentConfig::gas_coin`
+/// selects it.
+///
+/// The resulting tx requires the signatures of both its sender and its sponsor, both of which
+/// `sign_and_execute_tx` collects.
+pub fn sponsor_tx(
+    mut tx_data: TransactionData,
+    sponsor: Option<&SponsorConfig>,
+) -> TransactionData {
+    if let Some(sponsor) = sponsor {
+        tx_data.gas_data_mut().owner = sponsor.address;
+    }
+    tx_data
+}
+
+/// Submit an already signed transaction to the network for validation and inclusion in the
+/// ledger.
+///
+/// If its submission fails with a transient error, or times out, the same transaction is
+/// resubmitted, with exponential backoff, as per `retry_policy`. Any other error is returned
+/// immediately.
+///
+/// Resubmitting a timed-out tx is safe even if the first submission did reach the network: a
+/// signed tx is executed at most once, and its resubmission then returns the same effects.
+///
+/// The node is then polled until the tx's effects can be read from it, so that the objects it
+/// created can be used right away - which neither `ExecutionWait` guarantees.
+pub async fn execute_tx(
+    sui_client: &impl DeploymentClient,
+    tx: Transaction,
+    retry_policy: &RetryPolicy,
+    network_cfg: &NetworkConfig,
+) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
+    let mut delay = Duration::from_millis(retry_policy.base_delay_ms);
+    let mut attempt: u32 = 1;
+    loop {
+        let response = with_timeout(
+            network_cfg,
+            &format!("executing tx {}", tx.digest()),
+            sui_client.execute_transaction_block(
+                tx.clone(),
+                SuiTransactionBlockResponseOptions::new()
+                    .with_effects()
+                    .with_events()
+                    .with_object_changes(),
+                Some(network_cfg.wait_for.request_type()),
+            ),
         )
-        .await
-        .map_err(RAMMDeploymentError::TxBlockExecutionError)
+        .await;
+        let (err, transient) = match response {
+            Ok(Ok(response)) => {
+                return await_tx_effects(sui_client, response.digest, network_cfg).await
+            }
+            Ok(Err(err)) => {
+                let transient = is_transient_tx_error(&err);
+                (RAMMDeploymentError::TxBlockExecutionError(err), transient)
+            }
+            // `NetworkTimeout`
+            Err(err) => (err, true),
+        };
+        if !transient || attempt >= retry_policy.max_attempts {
+            return Err(err);
+        }
+        log::warn!(
+            "Attempt {attempt}/{} to execute tx {} failed: {err}. Retrying in {delay:?}.",
+            retry_policy.max_attempts,
+            tx.digest()
+        );
+        tokio::time::sleep(delay).await;
+        delay = Duration::try_from_secs_f64(delay.as_secs_f64() * retry_policy.multiplier)
+            .map_or(MAX_RETRY_BACKOFF, |next| next.min(MAX_RETRY_BACKOFF));
+        attempt += 1;
+    }
 }
 
-/// Given a `SuiClient` and a path to the Sui Move RAMM library, this function
+/// Poll the node for an executed transaction, every `network_cfg.poll_interval_ms`, until its
+/// effects can be read from it, which its submission does not guarantee.
+///
+/// Polling is abandoned if the effects are not available within `network_cfg.max_wait_secs`.
+async fn await_tx_effects(
+    sui_client: &impl DeploymentClient,
+    digest: TransactionDigest,
+    network_cfg: &NetworkConfig,
+) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
+    tokio::time::timeout(network_cfg.max_wait(), async {
+        loop {
+            let response = sui_client
+                .get_transaction_with_options(
+                    digest,
+                    SuiTransactionBlockResponseOptions::new()
+                        .with_effects()
+                        .with_events()
+                        .with_object_changes(),
+                )
+                .await;
+            match response {
+                Ok(response) if response.effects.is_some() => return response,
+                _ => {
+                    log::debug!("Effects of tx {digest} not yet available; polling again.");
+                    tokio::time::sleep(network_cfg.poll_interval()).await
+                }
+            }
+        }
+    })
+    .await
+    .map_err(|_| RAMMDeploymentError::NetworkTimeout {
+        operation: format!("waiting for the effects of tx {digest}"),
+        timeout_secs: network_cfg.max_wait_secs,
+    })
+}
+
+/// Given a `SuiClient`, a path to the Sui Move RAMM library, a gas budget, a retry policy and a
+/// network config, this function
 /// 1. builds the transaction that publishes the Sui Move library
-/// 2. signs it given a `client_address` and a `Keystore`
-/// 3. sends the transaction to the network specified in the Sui client for execution
+/// 2. dry runs it to estimate its gas cost
+/// 3. signs it given a `client_address` and a `Keystore`
+/// 4. sends the transaction to the network specified in the Sui client for execution
 ///
 /// When `await`ed, it'll produce the network's response with the transaction's execution status.
 pub async fn publish_ramm_pkg_runner(
     sui_client: &SuiClient,
     keystore: &Keystore,
     package_path: PathBuf,
+    pkg_build: &PkgBuildOptions,
+    named_addresses: &BTreeMap<String, SuiAddress>,
     client_address: &SuiAddress,
+    gas_budget: u64,
+    gas_safety_margin: f64,
+    gas_coin: Option<ObjectID>,
+    sponsor: Option<&SponsorConfig>,
+    retry_policy: &RetryPolicy,
+    network_cfg: &NetworkConfig,
 ) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
-    let publish_tx = publish_tx(&sui_client, package_path, *client_address).await?;
+    let publish_tx = publish_tx(
+        &sui_client,
+        package_path,
+        pkg_build,
+        named_addresses,
+        *client_address,
+        gas_budget,
+        gas_coin,
+        sponsor,
+    )
+    .await?;
+    let publish_tx = estimate_gas_budget(&sui_client, publish_tx, gas_safety_margin).await?;
 
-    sign_and_execute_tx(&sui_client, &keystore, publish_tx, &client_address).await
+    sign_and_execute_tx(
+        &sui_client,
+        &keystore,
+        publish_tx,
+        &client_address,
+        retry_policy,
+        network_cfg,
+    )
+    .await
 }
 
-/// Given a `SuiTransactionBlockResponse` to a transaction that publishes the RAMM package, this
-/// function returns the `ObjectID` of the published package.
-pub fn get_ramm_id_from_tx_response(publish_tx_response: SuiTransactionBlockResponse) -> ObjectID {
+/// Given a `SuiClient`, a path to an updated Sui Move RAMM library, the ID of the package it
+/// upgrades, its `UpgradeCap`, a gas budget, a retry policy and a network config, this function
+/// 1. builds the package upgrade transaction
+/// 2. dry runs it to estimate its gas cost
+/// 3. signs it given a `client_address` and a `Keystore`
+/// 4. sends the transaction to the network specified in the Sui client for execution
+///
+/// When `await`ed, it'll produce the network's response with the transaction's execution status.
+pub async fn upgrade_ramm_pkg_runner(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    package_path: PathBuf,
+    pkg_build: &PkgBuildOptions,
+    named_addresses: &BTreeMap<String, SuiAddress>,
+    client_address: &SuiAddress,
+    package_id: ObjectID,
+    upgrade_cap: ObjectID,
+    gas_budget: u64,
+    gas_safety_margin: f64,
+    gas_coin: Option<ObjectID>,
+    sponsor: Option<&SponsorConfig>,
+    retry_policy: &RetryPolicy,
+    network_cfg: &NetworkConfig,
+) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
+    let upgrade_tx = upgrade_tx(
+        &sui_client,
+        package_path,
+        pkg_build,
+        named_addresses,
+        *client_address,
+        package_id,
+        upgrade_cap,
+        gas_budget,
+        gas_coin,
+        sponsor,
+    )
+    .await?;
+    let upgrade_tx = estimate_gas_budget(&sui_client, upgrade_tx, gas_safety_margin).await?;
+
+    sign_and_execute_tx(
+        &sui_client,
+        &keystore,
+        upgrade_tx,
+        &client_address,
+        retry_policy,
+        network_cfg,
+    )
+    .await
+}
+
+/// Given a `SuiTransactionBlockResponse` to a transaction that publishes or upgrades the RAMM
+/// package, this function returns the `ObjectID` of the published package.
+///
+/// Should the response lack effects, or an immutable created object - i.e. the published package -
+/// `PackageIdNotFound` is returned.
+pub fn get_ramm_id_from_tx_response(
+    publish_tx_response: SuiTransactionBlockResponse,
+) -> Result<ObjectID, RAMMDeploymentError> {
     publish_tx_response
         .effects
-        .expect("Publish Tx *should* result in non-empty effects")
-        .created()
-        .into_iter()
-        .filter(|oor| Owner::is_immutable(&oor.owner))
-        .collect::<Vec<&OwnedObjectRef>>()
-        .first()
-        .expect(
-            "Publish Tx *should* result in at least 1 immutable object i.e. the published package",
-        )
-        .reference
-        .object_id
+        .as_ref()
+        .and_then(|effects| {
+            effects
+                .created()
+                .iter()
+                .find(|oor| Owner::is_immutable(&oor.owner))
+                .map(|oor| oor.reference.object_id)
+        })
+        .ok_or(RAMMDeploymentError::PackageIdNotFound(
+            publish_tx_response.digest,
+        ))
 }
 
-/// Given a `SuiClient` and deployment data, this function
+/// Given a `SuiClient` and deployment data, including the specification of the RAMM to be created,
+/// this function
 /// 1. builds the transaction that calls the Sui Move entry function `ramm_sui::new_ramm`
-/// 2. signs it given a `client_address` and a `Keystore`
-/// 3. sends the transaction to the network specified in the Sui client for execution
+/// 2. dry runs it to estimate its gas cost
+/// 3. signs it given a `client_address` and a `Keystore`
+/// 4. sends the transaction to the network specified in the Sui client for execution
 ///
 /// When `await`ed, it'll produce the network's response with the transaction's execution status.
 pub async fn new_ramm_tx_runner(
     sui_client: &SuiClient,
     dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
     keystore: &Keystore,
     client_address: &SuiAddress,
     ramm_pkg_id: ObjectID,
+    gas_coin: Option<ObjectID>,
 ) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
-    let new_ramm_tx = new_ramm_tx(&sui_client, &dplymt_cfg, &client_address, ramm_pkg_id).await?;
+    let new_ramm_tx = new_ramm_tx(
+        &sui_client,
+        &dplymt_cfg,
+        ramm_spec,
+        &client_address,
+        ramm_pkg_id,
+        gas_coin,
+    )
+    .await?;
+    let new_ramm_tx =
+        estimate_gas_budget(&sui_client, new_ramm_tx, dplymt_cfg.gas.safety_margin()).await?;
 
     // Sign, submit and await tx
-    sign_and_execute_tx(&sui_client, &keystore, new_ramm_tx, &client_address).await
+    sign_and_execute_tx(
+        &sui_client,
+        &keystore,
+        new_ramm_tx,
+        &client_address,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await
 }
 
 /// This data structure holds the SDK representations of the Move objects created in the
@@ -360,6 +2284,7 @@ pub async fn new_ramm_tx_runner(
 /// 1. the RAMM itself
 /// 2. the RAMM's admin capability, and
 /// 3. the RAMM's new asset capability
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct RAMMObjectArgs {
     pub ramm: ObjectArg,
     pub admin_cap: ObjectArg,
@@ -370,8 +2295,8 @@ pub struct RAMMObjectArgs {
 /// creates a RAMM.
 ///
 /// At the end of the program, it is printed to the user so that they can use the Sui client to
-/// query them for themselves.
-#[derive(Debug)]
+/// query them for themselves, and recorded in the deployment manifest, if one is requested.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RAMMObjectIDs {
     /// Object ID of the created RAMM
     pub ramm: ObjectID,
@@ -399,20 +2324,22 @@ impl Display for RAMMObjectIDs {
 ///
 /// There should be exactly 1 shared object created in the tx response:
 /// 1. the RAMM itself
+///
+/// Should the response lack effects, or the shared RAMM object, an error is returned.
 async fn build_ramm_obj_arg(
     new_ramm_rx_response: &SuiTransactionBlockResponse,
 ) -> Result<ObjectArg, RAMMDeploymentError> {
     let owned_obj_refs = new_ramm_rx_response
         .effects
         .as_ref()
-        .expect("RAMM creation tx *should* result in non-empty effects")
+        .ok_or(RAMMDeploymentError::MissingRammTxEffects)?
         .created()
         .into_iter()
         .filter(|oor| oor.owner.is_shared())
         .collect::<Vec<_>>();
     let ramm_owned_obj_ref = owned_obj_refs
         .first()
-        .expect("The RAMM creation should result in *exactly* 1 new shared object");
+        .ok_or(RAMMDeploymentError::MissingSharedRammObject)?;
 
     // The above `sui_json_rpc_types::OwnedObjectRef` must be converted into a
     // `sui_types::ObjectArg`, for use in a PTB later.
@@ -420,8 +2347,13 @@ async fn build_ramm_obj_arg(
         Owner::Shared {
             initial_shared_version,
         } => initial_shared_version,
-        // This should never happened, as above the created objects are filtered to be shared
-        _ => panic!("The RAMM's `OwnedObjectRef::owner` is supposed to be shared!"),
+        // This should never happen, as above the created objects are filtered to be shared
+        owner => {
+            return Err(RAMMDeploymentError::UnexpectedRammOwner {
+                id: ramm_owned_obj_ref.object_id(),
+                owner,
+            })
+        }
     };
     let ramm_obj_arg = ObjectArg::SharedObject {
         id: ramm_owned_obj_ref.object_id(),
@@ -439,7 +2371,7 @@ async fn build_ramm_obj_arg(
 /// Given a `SuiClient`, the response to the transaction that creates a RAMM, and a client
 /// address, disambiguate between the created capability objects in the tx response.
 ///
-/// There should be exactly 2 owned objects created in the tx response:
+/// The tx should create 2 objects owned by the client address:
 /// 1. the RAMM's admin capability, and
 /// 2. the RAMM's new asset capability
 ///
@@ -449,62 +2381,86 @@ async fn build_ramm_obj_arg(
 ///
 /// 1. inspects the `OwnedObjectRef`s in the tx response,
 /// 2. transforms them into `ObjectArg`s via the `sui_types::ObjectArg::ImmOrOwnedObject` variant,
-/// 3. queries the network for data of one of the object's, chosen at random,
-/// 4. extracts the type from the queried object's information, and
-/// 5. pattern matches on the type, and then assigns the correct name to each of the two
+/// 3. queries the network for the type of each of the objects, and
+/// 4. picks each capability by its struct type
+///
+/// Any other object owned by the client address that the tx may create is ignored, so that
+/// benign changes to the Move `new_ramm` function do not break the deployment.
+/// If either capability is not created exactly once, `UnexpectedCapObjectCount` is returned.
 async fn build_ramm_cap_obj_args(
-    sui_client: &SuiClient,
+    sui_client: &impl DeploymentClient,
     new_ramm_rx_response: SuiTransactionBlockResponse,
     client_address: SuiAddress,
 ) -> Result<(ObjectArg, ObjectArg), RAMMDeploymentError> {
-    // `ObjectArg`s of both the admin cap, and the new asset cap
-    let cap_obj_args: Vec<ObjectArg> = new_ramm_rx_response
+    // `ObjectArg`s of every object created for the tx sender, which include both capabilities
+    let owned_obj_args: Vec<ObjectArg> = new_ramm_rx_response
         .effects
-        .expect("RAMM creation tx *should* result in non-empty effects")
+        .ok_or(RAMMDeploymentError::MissingRammTxEffects)?
         .created()
         .into_iter()
-        // the ramm creation tx should have created 2 objects owned by the tx sender
-        .filter(|oor| oor.owner == client_address)
-        .map(|oor| match oor.owner {
-            Owner::AddressOwner(addr) => {
-                assert!(addr == client_address);
-                ObjectArg::ImmOrOwnedObject((oor.object_id(), oor.version(), oor.reference.digest))
-            }
-            _ => {
-                panic!("RAMM Cap OwnedObjectRef::owner supposed to be AddressOwner!")
-            }
+        .filter(|oor| oor.owner == Owner::AddressOwner(client_address))
+        .map(|oor| {
+            ObjectArg::ImmOrOwnedObject((oor.object_id(), oor.version(), oor.reference.digest))
         })
         .collect::<Vec<_>>();
-    assert!(cap_obj_args.len() == 2);
 
-    // To tell both capability objects apart, the below must be done:
-    // 1. Use the SDK to query the network on one of the two object IDs in the RAMM creation
-    //      response
-    let cap_object = sui_client
-        .read_api()
-        .get_object_with_options(
-            cap_obj_args[0].id(),
+    // To tell the capability objects apart, query the network for the types of the objects.
+    let owned_objs = sui_client
+        .multi_get_object_with_options(
+            owned_obj_args.iter().map(ObjectArg::id).collect(),
             SuiObjectDataOptions::new().with_type(),
         )
         .await
         .map_err(RAMMDeploymentError::CapObjectQueryError)?;
 
-    // 2. Extract the type from the queried object's information
-    let cap_obj_ty = cap_object.object().unwrap().object_type().unwrap();
-    let cap_move_obj_ty: MoveObjectType = match cap_obj_ty {
-        ObjectType::Package => {
-            panic!("Type of cap object is `ObjectType::Package`: not supposed to happen!")
+    let mut admin_caps: Vec<ObjectArg> = Vec::new();
+    let mut new_asset_caps: Vec<ObjectArg> = Vec::new();
+    for (obj_arg, owned_obj) in owned_obj_args.into_iter().zip(owned_objs) {
+        let obj_ty = owned_obj
+            .object()
+            .ok()
+            .and_then(|obj_data| obj_data.object_type().ok())
+            .ok_or(RAMMDeploymentError::UnknownCapType(obj_arg.id()))?;
+        let move_obj_ty: MoveObjectType = match obj_ty {
+            ObjectType::Package => return Err(RAMMDeploymentError::UnknownCapType(obj_arg.id())),
+            ObjectType::Struct(mot) => mot,
+        };
+
+        if move_obj_ty.module() != RAMM_MODULE_NAME {
+            log::debug!(
+                "Ignoring object {} created in the RAMM creation tx.",
+                obj_arg.id()
+            );
+            continue;
         }
-        ObjectType::Struct(mot) => mot,
-    };
+        match move_obj_ty.name().as_str() {
+            "RAMMAdminCap" => admin_caps.push(obj_arg),
+            "RAMMNewAssetCap" => new_asset_caps.push(obj_arg),
+            _ => log::debug!(
+                "Ignoring object {} created in the RAMM creation tx.",
+                obj_arg.id()
+            ),
+        }
+    }
 
-    // 3. Pattern match on the type, and assign `ObjectID`s to be used in the later PTB
-    let (admin_cap_obj_arg, new_asset_cap_obj_arg): (ObjectArg, ObjectArg) =
-        match cap_move_obj_ty.name().as_str() {
-            "RAMMAdminCap" => (cap_obj_args[0], cap_obj_args[1]),
-            "RAMMNewAssetCap" => (cap_obj_args[1], cap_obj_args[0]),
-            _ => panic!("`MoveObjectType` must be of either capability: not supposed to happen!"),
-        };
+    let admin_cap_obj_arg = match admin_caps.as_slice() {
+        [admin_cap] => *admin_cap,
+        caps => {
+            return Err(RAMMDeploymentError::UnexpectedCapObjectCount {
+                cap: "RAMMAdminCap",
+                count: caps.len(),
+            })
+        }
+    };
+    let new_asset_cap_obj_arg = match new_asset_caps.as_slice() {
+        [new_asset_cap] => *new_asset_cap,
+        caps => {
+            return Err(RAMMDeploymentError::UnexpectedCapObjectCount {
+                cap: "RAMMNewAssetCap",
+                count: caps.len(),
+            })
+        }
+    };
 
     Ok((admin_cap_obj_arg, new_asset_cap_obj_arg))
 }
@@ -518,14 +2474,14 @@ async fn build_ramm_cap_obj_args(
 /// These `ObjectArg`s can then be used to construct a `ProgrammableTransaction` that adds
 /// the assets specified in the deployment config to the RAMM, and initialize it.
 pub async fn build_ramm_obj_args(
-    sui_client: &SuiClient,
+    sui_client: &impl DeploymentClient,
     new_ramm_rx_response: SuiTransactionBlockResponse,
     client_address: SuiAddress,
 ) -> Result<(RAMMObjectArgs, RAMMObjectIDs), RAMMDeploymentError> {
     let ramm = build_ramm_obj_arg(&new_ramm_rx_response).await?;
 
     let (admin_cap, new_asset_cap) =
-        build_ramm_cap_obj_args(&sui_client, new_ramm_rx_response, client_address).await?;
+        build_ramm_cap_obj_args(sui_client, new_ramm_rx_response, client_address).await?;
 
     let obj_args = RAMMObjectArgs {
         ramm,
@@ -541,127 +2497,585 @@ pub async fn build_ramm_obj_args(
     Ok((obj_args, obj_ids))
 }
 
+/// Given the `RAMMObjectArgs` of a RAMM created in an earlier run, e.g. as recorded in a
+/// `DeploymentCheckpoint`, re-resolve the references of its capability objects: their versions
+/// change whenever they are a transaction's input - even that of a failed transaction.
+///
+/// The RAMM's own `ObjectArg` only depends on its initial shared version, so it is kept as is.
+pub async fn refresh_ramm_obj_args(
+    sui_client: &impl DeploymentClient,
+    obj_args: RAMMObjectArgs,
+) -> Result<RAMMObjectArgs, RAMMDeploymentError> {
+    let mut cap_obj_args: Vec<ObjectArg> = Vec::with_capacity(2);
+    for cap_id in [obj_args.admin_cap.id(), obj_args.new_asset_cap.id()] {
+        let cap_obj_arg = get_cap_obj_arg(sui_client, cap_id)
+            .await?
+            .ok_or(RAMMDeploymentError::CheckpointObjectNotFound(cap_id))?;
+        cap_obj_args.push(cap_obj_arg);
+    }
+
+    Ok(RAMMObjectArgs {
+        ramm: obj_args.ramm,
+        admin_cap: cap_obj_args[0],
+        new_asset_cap: cap_obj_args[1],
+    })
+}
+
+/// Query the network for the current reference of one of a RAMM's capability objects, as an
+/// `ObjectArg`; `None` if no such object exists.
+async fn get_cap_obj_arg(
+    sui_client: &impl DeploymentClient,
+    cap_id: ObjectID,
+) -> Result<Option<ObjectArg>, RAMMDeploymentError> {
+    let cap_object = sui_client
+        .get_object_with_options(cap_id, SuiObjectDataOptions::new())
+        .await
+        .map_err(RAMMDeploymentError::CapObjectQueryError)?;
+
+    Ok(cap_object
+        .object()
+        .ok()
+        .map(|obj_data| ObjectArg::ImmOrOwnedObject(obj_data.object_ref())))
+}
+
+/// Given the IDs of an already created RAMM and of its capabilities, e.g. as printed at the end of
+/// the `create` stage, query the network for the `ObjectArg`s needed to populate the RAMM.
+pub async fn ramm_obj_args_from_ids(
+    sui_client: &impl DeploymentClient,
+    ramm_ids: &RAMMObjectIDs,
+) -> Result<RAMMObjectArgs, RAMMDeploymentError> {
+    let ramm_obj = sui_client
+        .get_object_with_options(ramm_ids.ramm, SuiObjectDataOptions::new().with_owner())
+        .await
+        .map_err(RAMMDeploymentError::RAMMObjectQueryError)?;
+    let ramm_owner = ramm_obj
+        .object()
+        .ok()
+        .and_then(|ramm_data| ramm_data.owner)
+        .ok_or(RAMMDeploymentError::ObjectNotFound(ramm_ids.ramm))?;
+    let ramm = match ramm_owner {
+        Owner::Shared {
+            initial_shared_version,
+        } => ObjectArg::SharedObject {
+            id: ramm_ids.ramm,
+            initial_shared_version,
+            mutable: true,
+        },
+        owner => {
+            return Err(RAMMDeploymentError::UnexpectedRammOwner {
+                id: ramm_ids.ramm,
+                owner,
+            })
+        }
+    };
+
+    let admin_cap = get_cap_obj_arg(sui_client, ramm_ids.admin_cap)
+        .await?
+        .ok_or(RAMMDeploymentError::ObjectNotFound(ramm_ids.admin_cap))?;
+    let new_asset_cap = get_cap_obj_arg(sui_client, ramm_ids.new_asset_cap)
+        .await?
+        .ok_or(RAMMDeploymentError::ObjectNotFound(ramm_ids.new_asset_cap))?;
+
+    Ok(RAMMObjectArgs {
+        ramm,
+        admin_cap,
+        new_asset_cap,
+    })
+}
+
 /*
 PTB-related code
 */
 
-/// Given a `SuiClient` and a `RAMMDeploymentConfig`, this function
-/// 1. collects all of the object IDs for each of the deployment config's assets
-/// 2. queries the network for the objects' data
-/// 3. builds a vector of `ObjectArg`s to be used
+/// Given a `SuiClient` and a `RAMMSpec`, this function
+/// 1. collects all of the object IDs for each of the RAMM's assets
+/// 2. queries the network for the objects' data, in a single batch
+/// 3. builds a vector of `ObjectArg`s to be used, in the order of the RAMM's assets
+///
+/// Should any entry in the batched response be an error, or lack its owner, that aggregator is
+/// queried again on its own, before failing with an error naming it. These queries are made
+/// concurrently, up to `network_cfg.max_concurrency` at a time.
 ///
 /// This `Vec<ObjectArg>` is needed to later construct a `ProgrammableTransaction`.
 pub async fn build_aggr_obj_args(
-    sui_client: &SuiClient,
-    dplymt_cfg: &RAMMDeploymentConfig,
+    sui_client: &impl DeploymentClient,
+    ramm_spec: &RAMMSpec,
+    network_cfg: &NetworkConfig,
 ) -> Result<Vec<ObjectArg>, RAMMDeploymentError> {
-    let aggr_ids = dplymt_cfg
+    let aggr_ids = ramm_spec
         .assets
         .iter()
         .map(|asset| Into::<ObjectID>::into(asset.aggregator_address))
         .collect::<Vec<_>>();
-    let aggr_objs = sui_client
-        .read_api()
-        .multi_get_object_with_options(aggr_ids.clone(), SuiObjectDataOptions::new().with_owner())
-        .await
-        .map_err(RAMMDeploymentError::AggregatorDataQueryError)?;
+    let aggr_objs = with_timeout(
+        network_cfg,
+        "fetching the aggregators' object data",
+        sui_client.multi_get_object_with_options(
+            aggr_ids.clone(),
+            SuiObjectDataOptions::new().with_owner(),
+        ),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::AggregatorDataQueryError)?;
+
+    // `buffered` yields the results in the order of the aggregators, however the queries
+    // complete.
+    let aggr_obj_args: Vec<ObjectArg> = stream::iter(aggr_ids.iter().enumerate())
+        .map(|(ix, aggr_id)| {
+            let batched_owner = aggr_objs
+                .get(ix)
+                .and_then(|aggr_obj| aggr_obj.object().ok())
+                .and_then(|aggr_data| aggr_data.owner);
+            resolve_aggr_obj_arg(sui_client, *aggr_id, batched_owner, network_cfg)
+        })
+        .buffered(network_cfg.max_concurrency)
+        .try_collect()
+        .await?;
 
-    let mut aggr_obj_args: Vec<ObjectArg> = Vec::new();
-    for (ix, aggr_obj) in aggr_objs.iter().enumerate() {
-        let aggr_owner = aggr_obj
-            .object()
-            .map_err(RAMMDeploymentError::AggregatorObjectResponseError)?
-            .owner
-            .ok_or(RAMMDeploymentError::AggregatorObjectOwnerError)?;
-        match aggr_owner {
-            Owner::Shared {
-                initial_shared_version,
-            } => {
-                let aggr_obj_arg = ObjectArg::SharedObject {
-                    id: aggr_ids[ix],
-                    initial_shared_version,
-                    mutable: false,
-                };
-                aggr_obj_args.push(aggr_obj_arg)
-            }
-            _ => {
-                // If the aggregator object isn't shared, the error should just be unrecoverable
-                panic!("`Owner` of Aggregator object must be `Shared`");
-            }
+    assert_eq!(aggr_obj_args.len(), ramm_spec.asset_count() as usize);
+
+    Ok(aggr_obj_args)
+}
+
+/// Build the `ObjectArg` of an aggregator from its owner, as read from the batched query in
+/// `build_aggr_obj_args` - or, if it could not be, as queried on its own.
+async fn resolve_aggr_obj_arg(
+    sui_client: &impl DeploymentClient,
+    aggr_id: ObjectID,
+    batched_owner: Option<Owner>,
+    network_cfg: &NetworkConfig,
+) -> Result<ObjectArg, RAMMDeploymentError> {
+    let aggr_owner = match batched_owner {
+        Some(owner) => owner,
+        None => {
+            log::warn!(
+                "The owner of aggregator {aggr_id} could not be read from the batched \
+                query's response; querying it on its own."
+            );
+            get_aggr_owner(sui_client, aggr_id, network_cfg).await?
         }
+    };
+    match aggr_owner {
+        Owner::Shared {
+            initial_shared_version,
+        } => Ok(ObjectArg::SharedObject {
+            id: aggr_id,
+            initial_shared_version,
+            mutable: false,
+        }),
+        _ => Err(RAMMDeploymentError::AggregatorNotShared(aggr_id.into())),
     }
+}
 
-    assert_eq!(aggr_obj_args.len(), dplymt_cfg.asset_count as usize);
+/// Query the owner of a single aggregator object; used when the batched query in
+/// `build_aggr_obj_args` did not return it.
+async fn get_aggr_owner(
+    sui_client: &impl DeploymentClient,
+    aggr_id: ObjectID,
+    network_cfg: &NetworkConfig,
+) -> Result<Owner, RAMMDeploymentError> {
+    let aggr_obj = with_timeout(
+        network_cfg,
+        &format!("fetching the object data of aggregator {aggr_id}"),
+        sui_client.get_object_with_options(aggr_id, SuiObjectDataOptions::new().with_owner()),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::AggregatorDataQueryError)?;
+
+    aggr_obj
+        .object()
+        .map_err(|error| RAMMDeploymentError::AggregatorObjectResponseError {
+            aggregator: aggr_id.into(),
+            error,
+        })?
+        .owner
+        .ok_or(RAMMDeploymentError::AggregatorObjectOwnerError(
+            aggr_id.into(),
+        ))
+}
 
-    Ok(aggr_obj_args)
+/// Given a `SuiClient` and a `SuiAddress`, fetch every SUI `Coin` object owned by the address,
+/// following the RPC's pagination until all pages have been read.
+async fn get_all_coins(
+    sui_client: &impl DeploymentClient,
+    client_address: SuiAddress,
+) -> Result<Vec<Coin>, RAMMDeploymentError> {
+    let mut coins: Vec<Coin> = Vec::new();
+    let mut cursor: Option<ObjectID> = None;
+    loop {
+        let coin_page = sui_client
+            .get_coins(client_address, None, cursor, None)
+            .await
+            .map_err(RAMMDeploymentError::CoinQueryError)?;
+        coins.extend(coin_page.data);
+        if !coin_page.has_next_page {
+            break;
+        }
+        cursor = coin_page.next_cursor;
+    }
+
+    Ok(coins)
 }
 
-/// Given a `SuiClient` and a `SuiAddress`, this function, returns a tuple with
-/// 1. a `Coin` object associated to the address, and
-/// 2. the gas price to be used for the PTB
+/// Check, before any tx is sent, that the total SUI balance of `client_address` covers
+/// `required` MIST - the sum of the gas budgets of the txs about to be sent.
 ///
-/// It is used to find the coin object to be used as gas for the PTB that populates that RAMM.
-async fn get_coin_and_gas(
+/// This avoids e.g. publishing the package, only to run out of gas for the population PTB.
+/// `InsufficientGasBalance` is returned, with both amounts, if the balance falls short.
+pub async fn check_gas_balance(
     sui_client: &SuiClient,
     client_address: SuiAddress,
-) -> Result<(Coin, u64), RAMMDeploymentError> {
-    let coins = sui_client
-        .coin_read_api()
-        .get_coins(client_address, None, None, None)
-        .await
-        .map_err(RAMMDeploymentError::CoinQueryError)?;
+    required: u64,
+    network_cfg: &NetworkConfig,
+) -> Result<(), RAMMDeploymentError> {
+    let balance = with_timeout(
+        network_cfg,
+        "fetching the active address' balance",
+        sui_client.coin_read_api().get_balance(client_address, None),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::CoinQueryError)?;
+    let available = u64::try_from(balance.total_balance).unwrap_or(u64::MAX);
+
+    if available < required {
+        return Err(RAMMDeploymentError::InsufficientGasBalance {
+            address: client_address,
+            required,
+            available,
+        });
+    }
+    log::info!(
+        "Pre-flight check: the balance of {available} MIST covers the gas budgets' total of \
+        {required} MIST."
+    );
+
+    Ok(())
+}
 
-    let coin = coins
-        .data
+/// Given the SUI coins owned by `client_address`, find the one with the given `ObjectID`.
+///
+/// If it is not among them, the network is queried for the object, to tell apart the cases in
+/// which it does not exist, and in which it is not a SUI coin owned by the address.
+async fn get_selected_gas_coin(
+    sui_client: &impl DeploymentClient,
+    client_address: SuiAddress,
+    coins: Vec<Coin>,
+    gas_coin_id: ObjectID,
+) -> Result<Coin, RAMMDeploymentError> {
+    if let Some(coin) = coins
         .into_iter()
-        .next()
-        .expect("No coins associated to active address!");
-    log::info!("Fetched coin object");
-    let gas_price = sui_client
-        .read_api()
-        .get_reference_gas_price()
+        .find(|coin| coin.coin_object_id == gas_coin_id)
+    {
+        return Ok(coin);
+    }
+
+    let gas_coin_obj = sui_client
+        .get_object_with_options(gas_coin_id, SuiObjectDataOptions::new().with_owner())
         .await
-        .map_err(RAMMDeploymentError::GasPriceQueryError)?;
+        .map_err(RAMMDeploymentError::CoinQueryError)?;
+    match gas_coin_obj.object() {
+        Err(_) => Err(RAMMDeploymentError::GasCoinNotFound(gas_coin_id)),
+        Ok(_) => Err(RAMMDeploymentError::GasCoinNotOwned {
+            coin: gas_coin_id,
+            signer: client_address,
+        }),
+    }
+}
+
+/// Given a list of coins owned by `client_address`, merge all of them into the first one, using
+/// the first coin to pay for the merge transactions' gas.
+///
+/// At most `MAX_COINS_PER_MERGE` coins are merged by each transaction, whose budget is
+/// `GasConfig::merge_coins_budget`; a transaction that fails stops the merge, and its error is
+/// returned.
+///
+/// Returns the `ObjectID` of the coin into which all others were merged.
+async fn merge_coins(
+    sui_client: &impl DeploymentClient,
+    keystore: &Keystore,
+    client_address: SuiAddress,
+    coins: Vec<Coin>,
+    gas_cfg: &GasConfig,
+    gas_price: u64,
+    retry_policy: &RetryPolicy,
+    network_cfg: &NetworkConfig,
+) -> Result<ObjectID, RAMMDeploymentError> {
+    let (primary_coin, other_coins) = coins
+        .split_first()
+        .ok_or(RAMMDeploymentError::NoGasCoins(client_address))?;
+
+    // The primary coin's version changes with each merge, so its latest ref is read from the
+    // effects of the merge before.
+    let mut primary_coin_ref = primary_coin.object_ref();
+    for batch in other_coins.chunks(MAX_COINS_PER_MERGE) {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let coin_args: Vec<Argument> = batch
+            .iter()
+            .map(|coin| ptb.obj(ObjectArg::ImmOrOwnedObject(coin.object_ref())))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RAMMDeploymentError::MergeCoinsTxError)?;
+        // The primary coin pays for gas, so all other coins can be merged directly into the gas
+        // coin.
+        ptb.command(transaction::Command::MergeCoins(
+            Argument::GasCoin,
+            coin_args,
+        ));
+
+        let merge_tx = TransactionData::new_programmable(
+            client_address,
+            vec![primary_coin_ref],
+            ptb.finish(),
+            gas_cfg.merge_coins_budget(batch.len(), gas_price),
+            gas_price,
+        );
+        let response = sign_and_execute_tx(
+            sui_client,
+            keystore,
+            merge_tx,
+            &client_address,
+            retry_policy,
+            network_cfg,
+        )
+        .await?;
+        tx_status::check_tx_status(&response)?;
+        log::info!(
+            "Merged {} coins into coin {}, in tx {}.",
+            batch.len(),
+            primary_coin.coin_object_id,
+            response.digest
+        );
+
+        if let Some(effects) = &response.effects {
+            primary_coin_ref = effects.gas_object().reference.to_object_ref();
+        }
+    }
+
+    Ok(primary_coin.coin_object_id)
+}
+
+/// Total gas budget of the PTBs that merge `coin_count` coins into the first of them, as
+/// `merge_coins` batches them.
+fn merge_coins_total_budget(gas_cfg: &GasConfig, coin_count: usize, gas_price: u64) -> u64 {
+    let merged_count = coin_count.saturating_sub(1);
+    (0..merged_count)
+        .step_by(MAX_COINS_PER_MERGE)
+        .map(|start| {
+            let batch_len = (merged_count - start).min(MAX_COINS_PER_MERGE);
+            gas_cfg.merge_coins_budget(batch_len, gas_price)
+        })
+        .sum()
+}
+
+/// Given a `SuiClient`, a `SuiAddress` and the gas budget of the PTB that populates the RAMM,
+/// this function returns a tuple with
+/// 1. a `Coin` object associated to the address, whose balance covers the gas budget, and
+/// 2. the gas price to be used for the PTB
+///
+/// It is used to find the coin object to be used as gas for the PTB that populates that RAMM.
+///
+/// If `gas_coin` is provided, that coin is used, after checking that it is a SUI coin owned by the
+/// address, and that its balance covers the budget.
+///
+/// Otherwise, if no single coin owned by the address can cover the budget, as many coins as needed
+/// (largest first) are merged into one, in transactions executed before the PTB; see `merge_coins`.
+/// If even the address' total balance is insufficient, `InsufficientGasBalance` is returned.
+///
+/// If `gas_price` is provided, it is used instead of the reference gas price, after checking
+/// that it is not below it; `GasPriceBelowReference` is returned otherwise.
+///
+/// Each of the queries for the address' coins and the reference gas price is abandoned if it
+/// takes longer than `network_cfg`'s timeout.
+pub(crate) async fn get_coin_and_gas(
+    sui_client: &impl DeploymentClient,
+    keystore: &Keystore,
+    client_address: SuiAddress,
+    gas_budget: u64,
+    gas_price: Option<u64>,
+    gas_coin: Option<ObjectID>,
+    gas_cfg: &GasConfig,
+    retry_policy: &RetryPolicy,
+    network_cfg: &NetworkConfig,
+) -> Result<(Coin, u64), RAMMDeploymentError> {
+    let mut coins = with_timeout(
+        network_cfg,
+        "fetching the active address' coins",
+        get_all_coins(sui_client, client_address),
+    )
+    .await??;
+    // Largest coins first, so that the fewest possible coins need to be merged.
+    coins.sort_by(|c1, c2| c2.balance.cmp(&c1.balance));
+    log::info!("Fetched coin objects");
+
+    let reference_gas_price = with_timeout(
+        network_cfg,
+        "fetching the reference gas price",
+        sui_client.get_reference_gas_price(),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::GasPriceQueryError)?;
     log::info!("Fetched reference gas price");
 
-    Ok((coin, gas_price))
+    let gas_price = match gas_price {
+        None => reference_gas_price,
+        Some(price) if price < reference_gas_price => {
+            return Err(RAMMDeploymentError::GasPriceBelowReference {
+                price,
+                reference: reference_gas_price,
+            })
+        }
+        Some(price) => {
+            if price > reference_gas_price.saturating_mul(GAS_PRICE_WARNING_FACTOR) {
+                log::warn!(
+                    "The gas price of {price} MIST is over {GAS_PRICE_WARNING_FACTOR} times the \
+                    reference gas price of {reference_gas_price} MIST."
+                );
+            }
+            price
+        }
+    };
+
+    if let Some(gas_coin_id) = gas_coin {
+        let coin = get_selected_gas_coin(sui_client, client_address, coins, gas_coin_id).await?;
+        if coin.balance < gas_budget {
+            return Err(RAMMDeploymentError::InsufficientGasBalance {
+                address: client_address,
+                required: gas_budget,
+                available: coin.balance,
+            });
+        }
+        log::info!("Using gas coin {gas_coin_id}, as selected via the CLI.");
+        return Ok((coin, gas_price));
+    }
+
+    let largest_coin = coins
+        .first()
+        .ok_or(RAMMDeploymentError::NoGasCoins(client_address))?;
+    if largest_coin.balance >= gas_budget {
+        return Ok((largest_coin.clone(), gas_price));
+    }
+
+    // No single coin suffices: merge enough coins to cover both the PTB and the merge txs
+    // themselves, whose budgets grow with the number of coins merged.
+    let available: u64 = coins.iter().map(|coin| coin.balance).sum();
+    let mut required = gas_budget;
+    let mut merged_balance: u64 = 0;
+    let mut coins_to_merge: Vec<Coin> = Vec::new();
+    for coin in coins {
+        if merged_balance >= required {
+            break;
+        }
+        merged_balance += coin.balance;
+        coins_to_merge.push(coin);
+        required = gas_budget + merge_coins_total_budget(gas_cfg, coins_to_merge.len(), gas_price);
+    }
+    if merged_balance < required {
+        return Err(RAMMDeploymentError::InsufficientGasBalance {
+            address: client_address,
+            required,
+            available,
+        });
+    }
+    log::info!(
+        "No single coin covers the gas budget of {gas_budget} MIST; merging {} coins to cover it.",
+        coins_to_merge.len()
+    );
+
+    let merged_coin_id = merge_coins(
+        sui_client,
+        keystore,
+        client_address,
+        coins_to_merge,
+        gas_cfg,
+        gas_price,
+        retry_policy,
+        network_cfg,
+    )
+    .await?;
+
+    // The merged coin's version and balance changed, so it must be fetched anew.
+    let merged_coin = with_timeout(
+        network_cfg,
+        "fetching the active address' coins",
+        get_all_coins(sui_client, client_address),
+    )
+    .await??
+    .into_iter()
+    .find(|coin| coin.coin_object_id == merged_coin_id)
+    .filter(|coin| coin.balance >= gas_budget)
+    .ok_or(RAMMDeploymentError::InsufficientGasBalance {
+        address: client_address,
+        required: gas_budget,
+        available,
+    })?;
+
+    Ok((merged_coin, gas_price))
 }
 
-/// Given a `RAMMDeploymentConfig`, a `SuiAddress`, and the `ObjectID` of the RAMM package,
-/// plus other data
+/// Given a `RAMMDeploymentConfig`, the `RAMMSpec` of one of its RAMMs, a `SuiAddress`, and the
+/// `ObjectID` of the RAMM package, plus other data
 /// Create PTB to perform the following actions:
-/// 1. Add assets specified in the RAMM deployment config
+/// 1. Add assets specified in the RAMM's specification
 /// 2. Initialize it
+/// 3. Deposit the given initial liquidity into it, once it is initialized; see
+///    `initial_liquidity::prepare_deposits`
+///
+/// Should any of the PTB's inputs fail to be added, `PtbBuildError` is returned, naming the input
+/// and, if applicable, the asset it belongs to.
 pub async fn add_assets_and_init_ramm(
     dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
     client_address: SuiAddress,
     ramm_package_id: ObjectID,
     ramm_obj_args: RAMMObjectArgs,
     aggr_obj_args: Vec<ObjectArg>,
+    deposits: &[initial_liquidity::LiquidityDeposit],
     coin: Coin,
     gas_price: u64,
 ) -> Result<TransactionData, RAMMDeploymentError> {
     // 1. Build the PTB object via the `sui-sdk` builder API
     let mut ptb = ProgrammableTransactionBuilder::new();
-    let ramm_arg: Argument = ptb.obj(ramm_obj_args.ramm).unwrap();
+    let ptb_build_error = |input: String, err: anyhow::Error| {
+        RAMMDeploymentError::PtbBuildError(format!("could not add {input} as input: {err}"))
+    };
+    let ramm_arg: Argument = ptb
+        .obj(ramm_obj_args.ramm)
+        .map_err(|err| ptb_build_error(format!("RAMM {}", ramm_obj_args.ramm.id()), err))?;
     // Add the cap objects as inputs to the PTB. Recall: inputs to PTBs are added before it is
     // built, and accessible to all subsequent commands.
-    let admin_cap_arg: Argument = ptb.obj(ramm_obj_args.admin_cap).unwrap();
-    let new_asset_cap_arg: Argument = ptb.obj(ramm_obj_args.new_asset_cap).unwrap();
+    let admin_cap_arg: Argument = ptb.obj(ramm_obj_args.admin_cap).map_err(|err| {
+        ptb_build_error(format!("admin cap {}", ramm_obj_args.admin_cap.id()), err)
+    })?;
+    let new_asset_cap_arg: Argument = ptb.obj(ramm_obj_args.new_asset_cap).map_err(|err| {
+        ptb_build_error(
+            format!("new asset cap {}", ramm_obj_args.new_asset_cap.id()),
+            err,
+        )
+    })?;
     log::info!("PTB: Added RAMM, and admin/new asset caps as inputs");
 
     // 2. Add all of the assets specified in the TOML config
-    for ix in 0..(dplymt_cfg.asset_count as usize) {
-        // `N`-th asset to be added to the RAMM
-        let asset_data: &AssetConfig = &dplymt_cfg.assets[ix];
-        let aggr_arg = ptb.obj(aggr_obj_args[ix]).unwrap();
+    // Each asset is paired with its aggregator, and added to the RAMM in the order given.
+    for (asset_data, aggr_obj_arg) in ramm_spec.assets.iter().zip(aggr_obj_args) {
+        let asset_type = asset_data.label();
+        let aggr_arg = ptb.obj(aggr_obj_arg).map_err(|err| {
+            ptb_build_error(
+                format!("aggregator {} of asset {asset_type}", aggr_obj_arg.id()),
+                err,
+            )
+        })?;
+        let min_trade_amount_arg = ptb.pure(asset_data.minimum_trade_amount).map_err(|err| {
+            ptb_build_error(format!("minimum trade amount of asset {asset_type}"), err)
+        })?;
+        let decimal_places_arg = ptb
+            .pure(asset_data.resolved_decimal_places()?)
+            .map_err(|err| ptb_build_error(format!("decimal places of asset {asset_type}"), err))?;
 
         // Arguments for the `add_asset_to_ramm` Move call
         let move_call_args: Vec<Argument> = vec![
             ramm_arg,
             aggr_arg,
-            ptb.pure(asset_data.minimum_trade_amount).unwrap(),
-            ptb.pure(asset_data.decimal_places).unwrap(),
+            min_trade_amount_arg,
+            decimal_places_arg,
             admin_cap_arg,
             new_asset_cap_arg,
         ];
@@ -672,7 +3086,7 @@ pub async fn add_assets_and_init_ramm(
         ptb.programmable_move_call(
             ramm_package_id,
             RAMM_MODULE_NAME.to_owned(),
-            Identifier::new("add_asset_to_ramm").unwrap(),
+            ident_str!("add_asset_to_ramm").to_owned(),
             vec![asset_type_tag],
             move_call_args,
         );
@@ -683,28 +3097,32 @@ pub async fn add_assets_and_init_ramm(
     ptb.programmable_move_call(
         ramm_package_id,
         RAMM_MODULE_NAME.to_owned(),
-        Identifier::new("initialize_ramm").unwrap(),
+        ident_str!("initialize_ramm").to_owned(),
         vec![],
         vec![ramm_arg, admin_cap_arg, new_asset_cap_arg],
     );
     log::info!("PTB: Initialized the RAMM");
 
+    // Fund the RAMM, now that it accepts deposits.
+    initial_liquidity::add_deposits(&mut ptb, ramm_package_id, ramm_arg, deposits)?;
+
     // 3. Finalize the PTB object
     let pt: ProgrammableTransaction = ptb.finish();
 
     // 4. Convert PTB into tx data to be signed and sent to the network for execution
-    Ok(TransactionData::new_programmable(
+    let tx_data = TransactionData::new_programmable(
         client_address,
         vec![coin.object_ref()],
         pt,
-        RAMM_PTB_GAS_BUDGET,
+        dplymt_cfg.gas.populate_budget(),
         gas_price,
-    ))
+    );
+    Ok(sponsor_tx(tx_data, dplymt_cfg.sponsor.as_ref()))
 }
 
 /// Given
 /// * a `SuiClient`,
-/// * a `RAMMDeploymentConfig`,
+/// * a `RAMMDeploymentConfig`, and the `RAMMSpec` of the RAMM to be populated,
 /// * a `Keystore`,
 ///
 /// and further data: the client's active `SuiAddress`, a `Keystore` for tx-signing, and also
@@ -713,40 +3131,482 @@ pub async fn add_assets_and_init_ramm(
 ///   to be added to the RAMM
 ///
 /// this function
+/// 0. selects a gas coin - the one given in `gas_coin`, if any - and fetches the gas price,
 /// 1. creates a `ProgrammableTransactionBlock` that adds the assets specified in the deployment config
 ///   to the RAMM,
 /// 2. adds a command to the PTB that initializes it,
-/// 3. creates a `TransactionData` object from the PTB, and
-/// 4. signs and submits the transaction to the network for execution, and
-/// 5. awaits the network's response
+/// 3. creates a `TransactionData` object from the PTB,
+/// 4. dry runs it to estimate its gas cost,
+/// 5. signs and submits the transaction to the network for execution, and
+/// 6. awaits the network's response
+///
+/// If the config has `[initial_liquidity]` for any of the RAMM's assets, it is minted from the
+/// faucet, or checked to be held in the coin given for it, before the PTB is built, which then
+/// also deposits it; once the PTB succeeds, each deposit is checked to have minted LP tokens. See
+/// `initial_liquidity`.
+///
+/// The response to the PTB is returned, whether or not it succeeded, along with what it records of
+/// the RAMM's population, which is logged; see `PopulationOutcome`.
 pub async fn add_assets_and_init_ramm_runner(
     sui_client: &SuiClient,
     keystore: &Keystore,
     dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
     client_address: SuiAddress,
     ramm_package_id: ObjectID,
     ramm_obj_args: RAMMObjectArgs,
     aggr_obj_args: Vec<ObjectArg>,
-) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
-    let (coin, gas_price) = get_coin_and_gas(&sui_client, client_address).await?;
+    gas_coin: Option<ObjectID>,
+) -> Result<PopulationOutcome, RAMMDeploymentError> {
+    // The initial liquidity is minted before the PTB's gas coin is selected, as minting spends gas.
+    let deposits = initial_liquidity::prepare_deposits(
+        sui_client,
+        keystore,
+        dplymt_cfg,
+        ramm_spec,
+        client_address,
+        &aggr_obj_args,
+        gas_coin,
+    )
+    .await?;
+
+    let (coin, gas_price) = get_coin_and_gas(
+        &sui_client,
+        &keystore,
+        dplymt_cfg.gas_owner(client_address),
+        dplymt_cfg.gas.populate_budget(),
+        dplymt_cfg.gas.price,
+        gas_coin,
+        &dplymt_cfg.gas,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await?;
 
     let add_assets_and_init_tx = add_assets_and_init_ramm(
         dplymt_cfg,
+        ramm_spec,
         client_address,
         ramm_package_id,
         ramm_obj_args,
         aggr_obj_args,
+        &deposits,
         coin,
         gas_price,
     )
     .await?;
+    let add_assets_and_init_tx = estimate_gas_budget(
+        &sui_client,
+        add_assets_and_init_tx,
+        dplymt_cfg.gas.safety_margin(),
+    )
+    .await?;
+
+    // Show what the PTB will do before any gas is spent on it.
+    let population_preview =
+        preview::dev_inspect_population(sui_client, &add_assets_and_init_tx, &ramm_spec.assets)
+            .await?;
+    println!("{}", population_preview);
 
     // Sign, submit and await tx
-    sign_and_execute_tx(
+    let response = sign_and_execute_tx(
         &sui_client,
         &keystore,
         add_assets_and_init_tx,
         &client_address,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
     )
-    .await
+    .await?;
+
+    let tx = report::TxReport::from_response(&response);
+    if response.status_ok() != Some(true) {
+        return Ok(PopulationOutcome {
+            response,
+            events: events::RAMMEvents::default(),
+            tx,
+        });
+    }
+    let events = events::RAMMEvents::from_tx_response(&response)?;
+    events.log();
+    initial_liquidity::check_lp_tokens(
+        sui_client,
+        &response,
+        client_address,
+        ramm_package_id,
+        &deposits,
+    )
+    .await?;
+
+    Ok(PopulationOutcome {
+        response,
+        events,
+        tx,
+    })
+}
+
+/// Outcome of a RAMM's population; see `add_assets_and_init_ramm_runner`.
+pub struct PopulationOutcome {
+    /// Response to the population PTB.
+    pub response: SuiTransactionBlockResponse,
+    /// What the PTB records of the RAMM's population, if it succeeded; see `events::RAMMEvents`.
+    pub events: events::RAMMEvents,
+    /// The population PTB, as reported.
+    pub tx: report::TxReport,
+}
+
+/// After the RAMM has been populated and initialized, query its on-chain contents, and check that
+/// it is initialized with as many assets as its specification requires.
+///
+/// A successful tx status alone does not guarantee this, so this check is performed before the
+/// RAMM is reported to the user as deployed.
+pub async fn verify_ramm(
+    sui_client: &SuiClient,
+    ramm_id: ObjectID,
+    ramm_spec: &RAMMSpec,
+) -> Result<(), RAMMDeploymentError> {
+    let ramm_obj = sui_client
+        .read_api()
+        .get_object_with_options(ramm_id, SuiObjectDataOptions::new().with_content())
+        .await
+        .map_err(RAMMDeploymentError::RAMMObjectQueryError)?;
+
+    let ramm_fields = match ramm_obj
+        .object()
+        .ok()
+        .and_then(|data| data.content.as_ref())
+    {
+        Some(SuiParsedData::MoveObject(ramm)) => &ramm.fields,
+        _ => {
+            return Err(RAMMDeploymentError::VerificationFailed(format!(
+                "the contents of RAMM {ramm_id} could not be read"
+            )))
+        }
+    };
+
+    let asset_count = match ramm_fields.read_dynamic_field_value("asset_count") {
+        Some(SuiMoveValue::Number(asset_count)) => asset_count,
+        _ => {
+            return Err(RAMMDeploymentError::VerificationFailed(format!(
+                "RAMM {ramm_id} has no `asset_count` field"
+            )))
+        }
+    };
+    if asset_count != u32::from(ramm_spec.asset_count()) {
+        return Err(RAMMDeploymentError::VerificationFailed(format!(
+            "RAMM {ramm_id} has {asset_count} assets, but {} were specified",
+            ramm_spec.asset_count()
+        )));
+    }
+
+    match ramm_fields.read_dynamic_field_value("is_initialized") {
+        Some(SuiMoveValue::Bool(true)) => {}
+        _ => {
+            return Err(RAMMDeploymentError::VerificationFailed(format!(
+                "RAMM {ramm_id} is not initialized"
+            )))
+        }
+    }
+
+    log::info!("Verified RAMM {ramm_id}: it is initialized, with {asset_count} assets.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use move_core_types::{identifier::Identifier, language_storage::StructTag};
+    use sui_json_rpc_types::{
+        OwnedObjectRef, SuiTransactionBlockEffects, SuiTransactionBlockEffectsV1,
+    };
+    use sui_types::{base_types::random_object_ref, gas::GasCostSummary};
+
+    use super::*;
+    use crate::client::mock::{coin, object_data, MockClient};
+
+    /// A reference to a new object with the given owner.
+    fn owned_obj_ref(owner: Owner) -> OwnedObjectRef {
+        OwnedObjectRef {
+            owner,
+            reference: random_object_ref().into(),
+        }
+    }
+
+    /// A response to a successful tx, with effects that only record the creation of `created`.
+    fn response_creating(created: Vec<OwnedObjectRef>) -> SuiTransactionBlockResponse {
+        let digest = TransactionDigest::random();
+        let mut response = SuiTransactionBlockResponse::new(digest);
+        response.effects = Some(SuiTransactionBlockEffects::V1(
+            SuiTransactionBlockEffectsV1 {
+                status: SuiExecutionStatus::Success,
+                executed_epoch: 0,
+                gas_used: GasCostSummary::default(),
+                modified_at_versions: Vec::new(),
+                shared_objects: Vec::new(),
+                transaction_digest: digest,
+                created,
+                mutated: Vec::new(),
+                unwrapped: Vec::new(),
+                deleted: Vec::new(),
+                unwrapped_then_deleted: Vec::new(),
+                wrapped: Vec::new(),
+                gas_object: owned_obj_ref(Owner::AddressOwner(SuiAddress::ZERO)),
+                events_digest: None,
+                dependencies: Vec::new(),
+            },
+        ));
+        response
+    }
+
+    #[test]
+    fn ramm_obj_arg_is_the_shared_object_created() {
+        let signer = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+        let ramm = owned_obj_ref(Owner::Shared {
+            initial_shared_version: 3.into(),
+        });
+        let response = response_creating(vec![
+            owned_obj_ref(signer),
+            ramm.clone(),
+            owned_obj_ref(signer),
+        ]);
+
+        let ramm_obj_arg = block_on(build_ramm_obj_arg(&response)).unwrap();
+        assert_eq!(
+            ramm_obj_arg,
+            ObjectArg::SharedObject {
+                id: ramm.object_id(),
+                initial_shared_version: 3.into(),
+                mutable: true,
+            }
+        );
+    }
+
+    #[test]
+    fn ramm_obj_arg_requires_a_shared_object() {
+        let signer = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+        let response = response_creating(vec![owned_obj_ref(signer), owned_obj_ref(signer)]);
+
+        assert!(matches!(
+            block_on(build_ramm_obj_arg(&response)),
+            Err(RAMMDeploymentError::MissingSharedRammObject)
+        ));
+    }
+
+    #[test]
+    fn ramm_obj_arg_requires_effects() {
+        let response = SuiTransactionBlockResponse::new(TransactionDigest::random());
+
+        assert!(matches!(
+            block_on(build_ramm_obj_arg(&response)),
+            Err(RAMMDeploymentError::MissingRammTxEffects)
+        ));
+    }
+
+    #[test]
+    fn package_id_is_the_immutable_object_created() {
+        let signer = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+        let package = owned_obj_ref(Owner::Immutable);
+        let response = response_creating(vec![owned_obj_ref(signer), package.clone()]);
+
+        assert_eq!(
+            get_ramm_id_from_tx_response(response).unwrap(),
+            package.object_id()
+        );
+    }
+
+    #[test]
+    fn package_id_requires_a_published_package() {
+        let signer = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+        let response = response_creating(vec![
+            owned_obj_ref(signer),
+            owned_obj_ref(Owner::Shared {
+                initial_shared_version: 1.into(),
+            }),
+        ]);
+        let digest = response.digest;
+
+        assert!(matches!(
+            get_ramm_id_from_tx_response(response),
+            Err(RAMMDeploymentError::PackageIdNotFound(not_found)) if not_found == digest
+        ));
+    }
+
+    /// Select a gas coin and price for a tx with the given budget, as `get_coin_and_gas` does for
+    /// `SuiAddress::ZERO`, with the default gas, retry and network configs.
+    async fn coin_and_gas(
+        sui_client: &MockClient,
+        gas_budget: u64,
+        gas_price: Option<u64>,
+        gas_coin: Option<ObjectID>,
+    ) -> Result<(Coin, u64), RAMMDeploymentError> {
+        get_coin_and_gas(
+            sui_client,
+            &Keystore::InMem(InMemKeystore::default()),
+            SuiAddress::ZERO,
+            gas_budget,
+            gas_price,
+            gas_coin,
+            &GasConfig::default(),
+            &RetryPolicy::default(),
+            &NetworkConfig::default(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn gas_coin_is_the_largest_one() {
+        // More coins than fit in a page, with the largest on the last one.
+        let largest = coin(5_000_000);
+        let sui_client = MockClient {
+            coins: vec![coin(10), coin(300), coin(20), largest.clone()],
+            reference_gas_price: 750,
+            ..Default::default()
+        };
+
+        let (gas_coin, gas_price) = coin_and_gas(&sui_client, 1_000_000, None, None)
+            .await
+            .unwrap();
+        assert_eq!(gas_coin.coin_object_id, largest.coin_object_id);
+        assert_eq!(gas_price, 750);
+    }
+
+    #[tokio::test]
+    async fn gas_price_below_reference_is_rejected() {
+        let sui_client = MockClient {
+            coins: vec![coin(5_000_000)],
+            reference_gas_price: 750,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            coin_and_gas(&sui_client, 1_000_000, Some(749), None).await,
+            Err(RAMMDeploymentError::GasPriceBelowReference {
+                price: 749,
+                reference: 750
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn selected_gas_coin_must_be_the_signers() {
+        let others_coin = ObjectID::random();
+        let sui_client = MockClient {
+            objects: [(
+                others_coin,
+                object_data(
+                    others_coin,
+                    ObjectType::Struct(MoveObjectType::gas_coin()),
+                    Owner::AddressOwner(SuiAddress::random_for_testing_only()),
+                ),
+            )]
+            .into(),
+            coins: vec![coin(5_000_000)],
+            reference_gas_price: 750,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            coin_and_gas(&sui_client, 1_000_000, None, Some(others_coin)).await,
+            Err(RAMMDeploymentError::GasCoinNotOwned { coin, .. }) if coin == others_coin
+        ));
+        let missing_coin = ObjectID::random();
+        assert!(matches!(
+            coin_and_gas(&sui_client, 1_000_000, None, Some(missing_coin)).await,
+            Err(RAMMDeploymentError::GasCoinNotFound(coin)) if coin == missing_coin
+        ));
+    }
+
+    #[tokio::test]
+    async fn insufficient_balance_is_reported_before_merging() {
+        let sui_client = MockClient {
+            coins: vec![coin(300_000), coin(200_000)],
+            reference_gas_price: 750,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            coin_and_gas(&sui_client, 1_000_000, None, None).await,
+            Err(RAMMDeploymentError::InsufficientGasBalance {
+                available: 500_000,
+                ..
+            })
+        ));
+    }
+
+    /// The type of a struct of the RAMM library's `ramm` module.
+    fn ramm_struct_type(name: &str) -> ObjectType {
+        ObjectType::Struct(MoveObjectType::from(StructTag {
+            address: AccountAddress::random(),
+            module: RAMM_MODULE_NAME.to_owned(),
+            name: Identifier::new(name).unwrap(),
+            type_params: Vec::new(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn caps_are_told_apart_by_type_in_either_order() {
+        let signer = SuiAddress::random_for_testing_only();
+        let new_asset_cap = owned_obj_ref(Owner::AddressOwner(signer));
+        let other = owned_obj_ref(Owner::AddressOwner(signer));
+        let admin_cap = owned_obj_ref(Owner::AddressOwner(signer));
+        let objects = [
+            (&new_asset_cap, ramm_struct_type("RAMMNewAssetCap")),
+            (&other, ObjectType::Struct(MoveObjectType::gas_coin())),
+            (&admin_cap, ramm_struct_type("RAMMAdminCap")),
+        ];
+        let sui_client = MockClient {
+            objects: objects
+                .into_iter()
+                .map(|(obj_ref, type_)| {
+                    let id = obj_ref.object_id();
+                    (id, object_data(id, type_, obj_ref.owner))
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        // The new asset cap first, and an object that is no cap between the two.
+        let response = response_creating(vec![new_asset_cap.clone(), other, admin_cap.clone()]);
+        let (admin_cap_arg, new_asset_cap_arg) =
+            build_ramm_cap_obj_args(&sui_client, response, signer)
+                .await
+                .unwrap();
+        assert_eq!(admin_cap_arg.id(), admin_cap.object_id());
+        assert_eq!(new_asset_cap_arg.id(), new_asset_cap.object_id());
+
+        let response = response_creating(vec![admin_cap.clone(), new_asset_cap.clone()]);
+        let (admin_cap_arg, new_asset_cap_arg) =
+            build_ramm_cap_obj_args(&sui_client, response, signer)
+                .await
+                .unwrap();
+        assert_eq!(admin_cap_arg.id(), admin_cap.object_id());
+        assert_eq!(new_asset_cap_arg.id(), new_asset_cap.object_id());
+    }
+
+    #[tokio::test]
+    async fn missing_cap_is_reported() {
+        let signer = SuiAddress::random_for_testing_only();
+        let admin_cap = owned_obj_ref(Owner::AddressOwner(signer));
+        let sui_client = MockClient {
+            objects: [(
+                admin_cap.object_id(),
+                object_data(
+                    admin_cap.object_id(),
+                    ramm_struct_type("RAMMAdminCap"),
+                    admin_cap.owner,
+                ),
+            )]
+            .into(),
+            ..Default::default()
+        };
+
+        let response = response_creating(vec![admin_cap]);
+        assert!(matches!(
+            build_ramm_cap_obj_args(&sui_client, response, signer).await,
+            Err(RAMMDeploymentError::UnexpectedCapObjectCount {
+                cap: "RAMMNewAssetCap",
+                count: 0
+            })
+        ));
+    }
 }
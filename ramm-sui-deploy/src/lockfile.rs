@@ -0,0 +1,166 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sui_types::base_types::ObjectID;
+
+use crate::{
+    error::RAMMDeploymentError,
+    types::{RAMMDeploymentConfig, RAMMPkgAddrSrc},
+};
+
+/// Path of the lockfile used unless another is given via the CLI, relative to the working
+/// directory.
+pub const DEFAULT_LOCKFILE_PATH: &str = "ramm_deploy.lock";
+
+/// A successful deployment, as recorded in a `DeploymentLock`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LockEntry {
+    /// Hash of the deployed config; see `config_hash`.
+    pub config_hash: String,
+    /// The network the config was deployed to.
+    pub network: String,
+    pub package_id: ObjectID,
+    /// IDs of the RAMMs the deployment created.
+    pub ramm_ids: Vec<ObjectID>,
+    /// Path of the deployment's manifest, if one was written.
+    pub manifest_path: Option<PathBuf>,
+    /// When the deployment completed, in seconds since the Unix epoch.
+    pub deployed_at_secs: u64,
+}
+
+/// Record of every successful deployment, kept in a JSON lockfile, so that running the tool twice
+/// with the same config does not deploy a duplicate set of RAMMs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DeploymentLock {
+    pub deployments: Vec<LockEntry>,
+}
+
+impl DeploymentLock {
+    /// Read the lockfile at the given path; if there is none, no deployment has been recorded.
+    pub fn read_from_file(path: &Path) -> Result<Self, RAMMDeploymentError> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(RAMMDeploymentError::LockfileReadError(err)),
+        };
+
+        serde_json::from_str(&json).map_err(RAMMDeploymentError::LockfileParseError)
+    }
+
+    /// Write the lockfile, as pretty-printed JSON, to the file at the given path.
+    ///
+    /// If the file already exists, it is overwritten.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), RAMMDeploymentError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(RAMMDeploymentError::LockfileSerializationError)?;
+
+        fs::write(path, json).map_err(RAMMDeploymentError::LockfileWriteError)
+    }
+
+    /// Check that no deployment of the config with the given hash has been recorded.
+    ///
+    /// If one has, `AlreadyDeployed` is returned, pointing the user to its manifest - unless
+    /// `force` is set, in which case only a warning is logged.
+    pub fn check_not_deployed(
+        &self,
+        config_hash: &str,
+        force: bool,
+    ) -> Result<(), RAMMDeploymentError> {
+        let Some(prior) = self
+            .deployments
+            .iter()
+            .rev()
+            .find(|entry| entry.config_hash == config_hash)
+        else {
+            return Ok(());
+        };
+
+        let prior_description = match &prior.manifest_path {
+            Some(manifest_path) => format!("its manifest, {}", manifest_path.display()),
+            None => format!(
+                "its RAMM(s), {}",
+                prior
+                    .ramm_ids
+                    .iter()
+                    .map(ObjectID::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        if force {
+            log::warn!(
+                "This config was already deployed to {}, per the lockfile - see \
+                {prior_description}; deploying it again, as `--force` was passed.",
+                prior.network
+            );
+            return Ok(());
+        }
+
+        Err(RAMMDeploymentError::AlreadyDeployed {
+            config_hash: config_hash.to_string(),
+            network: prior.network.clone(),
+            prior: prior_description,
+        })
+    }
+
+    /// Record a successful deployment.
+    pub fn record(
+        &mut self,
+        config_hash: String,
+        network: String,
+        package_id: ObjectID,
+        ramm_ids: Vec<ObjectID>,
+        manifest_path: Option<PathBuf>,
+    ) {
+        let deployed_at_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since_epoch| since_epoch.as_secs());
+
+        self.deployments.push(LockEntry {
+            config_hash,
+            network,
+            package_id,
+            ramm_ids,
+            manifest_path,
+            deployed_at_secs,
+        });
+    }
+}
+
+/// Stable SHA-256 hash, in hex, of what a deployment creates on-chain: the network it targets,
+/// the RAMM package used, and each RAMM's fee collection address and assets.
+///
+/// Fields that do not affect the RAMMs themselves, such as gas budgets or retry policies, are not
+/// hashed.
+pub fn config_hash(dplymt_cfg: &RAMMDeploymentConfig, network: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("network: {network}\n"));
+    match &dplymt_cfg.ramm_pkg_addr_or_path {
+        RAMMPkgAddrSrc::FromTomlConfig(package_id) => {
+            hasher.update(format!("package: {package_id}\n"))
+        }
+        RAMMPkgAddrSrc::FromPkgPublication(path) => {
+            hasher.update(format!("package path: {}\n", path.display()))
+        }
+    }
+    for ramm_spec in &dplymt_cfg.ramms {
+        hasher.update(format!("ramm: {}\n", ramm_spec.fee_collection_address));
+        for asset in &ramm_spec.assets {
+            hasher.update(format!(
+                "asset: {} {} {} {} {:?}\n",
+                asset.asset_type,
+                asset.aggregator_address,
+                asset.oracle_provider,
+                asset.minimum_trade_amount,
+                asset.decimal_places
+            ));
+        }
+    }
+
+    format!("{:x}", hasher.finalize())
+}
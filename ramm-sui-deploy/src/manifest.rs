@@ -0,0 +1,150 @@
+use std::{fs, path::Path};
+
+use serde::Serialize;
+use sui_types::{
+    base_types::{ObjectID, SuiAddress},
+    digests::TransactionDigest,
+};
+
+use crate::{
+    error::RAMMDeploymentError,
+    events::RAMMEvents,
+    report::DeploymentReport,
+    types::{OracleProvider, RAMMDeploymentConfig, RAMMSpec},
+    RAMMObjectIDs,
+};
+
+/// An asset of a deployed RAMM, as recorded in a `DeploymentManifest`.
+#[derive(Debug, Serialize)]
+pub struct AssetManifest {
+    /// The asset's type, in the form `<package-id>::<module>::<type-name>`.
+    pub asset_type: String,
+    /// The coin's symbol, per its `CoinMetadata`; omitted if it was not read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Address of the price feed object used to price the asset.
+    pub aggregator_address: SuiAddress,
+    pub oracle_provider: OracleProvider,
+}
+
+/// A RAMM created during the deployment, as recorded in a `DeploymentManifest`.
+#[derive(Debug, Serialize)]
+pub struct RAMMManifest {
+    /// IDs of the RAMM and its capabilities.
+    pub ramm: RAMMObjectIDs,
+    /// The RAMM's initial fee collection address.
+    pub fee_collection_address: SuiAddress,
+    /// The RAMM's assets, in the order in which they were added to it.
+    pub assets: Vec<AssetManifest>,
+    /// Digest of the RAMM creation tx.
+    pub create_tx_digest: TransactionDigest,
+    /// Digest of the PTB that populated and initialized the RAMM.
+    pub populate_tx_digest: TransactionDigest,
+    /// What the RAMM's creation and population txs record of it; omitted if it was deployed in an
+    /// earlier run, resumed from a checkpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<RAMMEvents>,
+    /// The address the RAMM's admin cap was transferred to, if it was transferred away from the
+    /// signer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_cap_recipient: Option<SuiAddress>,
+    /// Digest of the tx that transferred the RAMM's admin cap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap_transfer_tx_digest: Option<TransactionDigest>,
+}
+
+/// Machine-readable record of a successful RAMM deployment, written to a JSON file so that
+/// other tooling (e.g. the TS SDK) can consume it without scraping the tool's output.
+///
+/// Besides the IDs of the created objects, it holds the digests of the transactions that created
+/// them, so that the deployment can be audited.
+#[derive(Debug, Serialize)]
+pub struct DeploymentManifest {
+    /// The Sui network environment the RAMMs were deployed to.
+    pub network: String,
+    /// ID of the RAMM package used in the deployment.
+    pub package_id: ObjectID,
+    /// Digest of the package publication or upgrade tx; `None` if an already published package
+    /// was used.
+    pub publish_tx_digest: Option<TransactionDigest>,
+    /// If the package was upgraded rather than published, the ID of the package it upgraded.
+    pub upgraded_from: Option<ObjectID>,
+    /// Each of the successfully deployed RAMMs, in the order in which they were deployed.
+    pub ramms: Vec<RAMMManifest>,
+    /// Report of the deployment, with the gas used by each of its txs, and its duration; omitted
+    /// for manifests written by a single stage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<DeploymentReport>,
+}
+
+impl DeploymentManifest {
+    /// Build a deployment manifest, without any RAMMs, from the config used in the deployment
+    /// and the package it used.
+    pub fn new(
+        dplymt_cfg: &RAMMDeploymentConfig,
+        package_id: ObjectID,
+        publish_tx_digest: Option<TransactionDigest>,
+        upgraded_from: Option<ObjectID>,
+    ) -> Self {
+        DeploymentManifest {
+            network: dplymt_cfg.target_env.clone(),
+            package_id,
+            publish_tx_digest,
+            upgraded_from,
+            ramms: Vec::new(),
+            report: None,
+        }
+    }
+
+    /// Record a successfully deployed RAMM in the manifest, along with the digests of the
+    /// transactions that created and populated it, and what they record of it, if known.
+    pub fn add_ramm(
+        &mut self,
+        ramm_spec: &RAMMSpec,
+        ramm: RAMMObjectIDs,
+        create_tx_digest: TransactionDigest,
+        populate_tx_digest: TransactionDigest,
+        events: Option<RAMMEvents>,
+    ) {
+        let assets = ramm_spec
+            .assets
+            .iter()
+            .map(|asset| AssetManifest {
+                asset_type: asset.asset_type.to_string(),
+                symbol: asset.symbol().map(str::to_string),
+                aggregator_address: asset.aggregator_address,
+                oracle_provider: asset.oracle_provider,
+            })
+            .collect();
+
+        self.ramms.push(RAMMManifest {
+            ramm,
+            fee_collection_address: ramm_spec.fee_collection_address,
+            assets,
+            create_tx_digest,
+            populate_tx_digest,
+            events,
+            admin_cap_recipient: None,
+            cap_transfer_tx_digest: None,
+        });
+    }
+
+    /// Record that the admin cap of the RAMM last added to the manifest was transferred to
+    /// `recipient` in the tx with the given digest.
+    pub fn record_cap_transfer(&mut self, recipient: SuiAddress, tx_digest: TransactionDigest) {
+        if let Some(ramm) = self.ramms.last_mut() {
+            ramm.admin_cap_recipient = Some(recipient);
+            ramm.cap_transfer_tx_digest = Some(tx_digest);
+        }
+    }
+
+    /// Write the manifest, as pretty-printed JSON, to the file at the given path.
+    ///
+    /// If the file already exists, it is overwritten.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), RAMMDeploymentError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(RAMMDeploymentError::ManifestSerializationError)?;
+
+        fs::write(path, json).map_err(RAMMDeploymentError::ManifestWriteError)
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use shared_crypto::intent::{Intent, IntentMessage};
+use sui_json_rpc_types::SuiTransactionBlockResponse;
+use sui_sdk::SuiClient;
+use sui_types::{
+    crypto::{EncodeDecodeBase64, Signature, SuiSignature},
+    transaction::{Transaction, TransactionData, TransactionDataAPI},
+};
+
+use crate::{
+    error::RAMMDeploymentError,
+    execute_tx,
+    types::{NetworkConfig, RetryPolicy},
+};
+
+/// Extension of the files to which unsigned transactions are written.
+const UNSIGNED_TX_EXTENSION: &str = "tx";
+
+/// Write a transaction's data, unsigned, to `<dir>/<name>.tx`, so that it can be signed offline,
+/// e.g. on an air-gapped machine.
+///
+/// The file holds the BCS serialization of the `TransactionData`, encoded in base64 - the format
+/// `sui keytool sign --data` expects. `dir` is created if it does not exist.
+///
+/// Returns the path of the written file.
+pub fn write_unsigned_tx(
+    dir: &Path,
+    name: &str,
+    tx_data: &TransactionData,
+) -> Result<PathBuf, RAMMDeploymentError> {
+    let tx_bytes = bcs::to_bytes(tx_data).map_err(RAMMDeploymentError::TxSerializationError)?;
+
+    fs::create_dir_all(dir).map_err(RAMMDeploymentError::UnsignedTxWriteError)?;
+    let path = dir.join(name).with_extension(UNSIGNED_TX_EXTENSION);
+    fs::write(&path, BASE64.encode(tx_bytes)).map_err(RAMMDeploymentError::UnsignedTxWriteError)?;
+
+    Ok(path)
+}
+
+/// Read back a transaction's data from a file written by `write_unsigned_tx`.
+pub fn read_unsigned_tx(path: &Path) -> Result<TransactionData, RAMMDeploymentError> {
+    let encoded = fs::read_to_string(path).map_err(RAMMDeploymentError::UnsignedTxReadError)?;
+    let tx_bytes = BASE64
+        .decode(encoded.trim())
+        .map_err(|e| RAMMDeploymentError::UnsignedTxParseError(e.to_string()))?;
+
+    bcs::from_bytes(&tx_bytes).map_err(|e| RAMMDeploymentError::UnsignedTxParseError(e.to_string()))
+}
+
+/// Read a signature, serialized in base64 as output by `sui keytool sign`, from a file.
+pub fn read_signature(path: &Path) -> Result<Signature, RAMMDeploymentError> {
+    let encoded = fs::read_to_string(path).map_err(RAMMDeploymentError::SignatureReadError)?;
+
+    Signature::decode_base64(encoded.trim())
+        .map_err(|e| RAMMDeploymentError::InvalidSignature(e.to_string()))
+}
+
+/// Submit a transaction that was exported with `write_unsigned_tx` and signed offline.
+///
+/// Before anything is sent to the network, the signature is checked to have been produced, over
+/// this very transaction, by its sender - so that a mismatched pair of files fails early, instead
+/// of being rejected by validators.
+///
+/// The keystore is never used.
+pub async fn submit_signed_tx(
+    sui_client: &SuiClient,
+    tx_path: &Path,
+    sig_path: &Path,
+    retry_policy: &RetryPolicy,
+    network_cfg: &NetworkConfig,
+) -> Result<SuiTransactionBlockResponse, RAMMDeploymentError> {
+    let tx_data = read_unsigned_tx(tx_path)?;
+    let signature = read_signature(sig_path)?;
+
+    let signer = tx_data.sender();
+    let intent_msg = IntentMessage::new(Intent::sui_transaction(), tx_data.clone());
+    signature
+        .verify_secure(&intent_msg, signer, signature.scheme())
+        .map_err(|_| RAMMDeploymentError::SignatureMismatch { signer })?;
+
+    let tx = Transaction::from_data(tx_data, Intent::sui_transaction(), vec![signature]);
+
+    execute_tx(sui_client, tx, retry_policy, network_cfg).await
+}
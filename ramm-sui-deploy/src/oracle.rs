@@ -0,0 +1,235 @@
+use std::{
+    fmt::Display,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures::{stream, StreamExt};
+use serde_json::Value;
+use sui_json_rpc_types::{SuiObjectDataOptions, SuiParsedData};
+use sui_sdk::SuiClient;
+use sui_types::base_types::{ObjectID, SuiAddress};
+
+use crate::{
+    error::RAMMDeploymentError,
+    types::{NetworkConfig, RAMMDeploymentConfig},
+    with_timeout,
+};
+
+/// The latest result of a Switchboard `Aggregator`, as read from its `update_data` field.
+///
+/// Mirrors Switchboard's `SwitchboardDecimal`: the price is `value / 10^decimals`, negated if
+/// `negative` is set.
+#[derive(Clone, Debug)]
+pub struct OraclePrice {
+    pub value: u128,
+    pub decimals: u8,
+    pub negative: bool,
+    /// When the result was last updated, in seconds since the Unix epoch.
+    pub timestamp_secs: u64,
+    /// How long before it was read the result was last updated, in seconds.
+    pub age_secs: u64,
+    /// Whether `age_secs` exceeds the config's `[oracle] max_staleness_secs`.
+    pub stale: bool,
+}
+
+impl Display for OraclePrice {
+    /// Display the price in decimal notation, with all of its decimal places.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        let scale = 10u128.checked_pow(u32::from(self.decimals));
+        match scale {
+            Some(scale) if self.decimals > 0 => write!(
+                f,
+                "{sign}{}.{:0width$}",
+                self.value / scale,
+                self.value % scale,
+                width = usize::from(self.decimals)
+            ),
+            _ => write!(f, "{sign}{}e-{}", self.value, self.decimals),
+        }
+    }
+}
+
+/// What could be read of an asset's price feed before the deployment.
+#[derive(Clone, Debug)]
+pub enum OracleReading {
+    /// The feed's latest result.
+    Price(OraclePrice),
+    /// The feed could not be read; kept as a message, so that it can be shown next to the asset
+    /// rather than abort the deployment.
+    Failed(String),
+}
+
+/// Read the latest result of each Switchboard asset's aggregator, and record it in the asset's
+/// `oracle_reading`, to be shown to the user along with the config.
+///
+/// A feed whose result is older than the config's `max_staleness_secs` is marked stale. Neither it
+/// nor one that cannot be read, which is recorded as `OracleReading::Failed`, is an error here: a
+/// warning is logged, and it is up to the user - or `check_oracles_fresh` - to decide whether to
+/// proceed. Unless `check_staleness` is set, no feed is marked stale, e.g. on a localnet, whose
+/// feeds are test fixtures.
+///
+/// The aggregators are queried concurrently, up to the network config's `max_concurrency`.
+pub async fn read_oracle_prices(
+    sui_client: &SuiClient,
+    dplymt_cfg: &mut RAMMDeploymentConfig,
+    check_staleness: bool,
+) {
+    let network_cfg = &dplymt_cfg.network;
+    let max_staleness_secs = dplymt_cfg.oracle.max_staleness_secs;
+    let feeds: Vec<SuiAddress> = dplymt_cfg
+        .ramms
+        .iter()
+        .flat_map(|ramm_spec| ramm_spec.assets.iter())
+        .map(|asset| asset.aggregator_address)
+        .collect();
+    let readings: Vec<OracleReading> = stream::iter(feeds)
+        .map(|aggr_addr| async move {
+            match read_switchboard_price(sui_client, aggr_addr.into(), network_cfg).await {
+                Ok(mut price) => {
+                    price.stale = check_staleness && price.age_secs > max_staleness_secs;
+                    if price.stale {
+                        log::warn!(
+                            "The latest result of aggregator {aggr_addr} is {}s old: its feed may \
+                            be dead.",
+                            price.age_secs
+                        );
+                    }
+                    OracleReading::Price(price)
+                }
+                Err(e) => {
+                    log::warn!("Could not read the latest result of aggregator {aggr_addr}: {e}");
+                    OracleReading::Failed(e.to_string())
+                }
+            }
+        })
+        .buffered(network_cfg.max_concurrency)
+        .collect()
+        .await;
+
+    let assets = dplymt_cfg
+        .ramms
+        .iter_mut()
+        .flat_map(|ramm_spec| ramm_spec.assets.iter_mut());
+    for (asset, reading) in assets.zip(readings) {
+        asset.oracle_reading = Some(reading);
+    }
+}
+
+/// Query a Switchboard `Aggregator`, and parse the latest result and timestamp from its
+/// `update_data`.
+pub async fn read_switchboard_price(
+    sui_client: &SuiClient,
+    aggr_id: ObjectID,
+    network_cfg: &NetworkConfig,
+) -> Result<OraclePrice, RAMMDeploymentError> {
+    let aggr_obj = with_timeout(
+        network_cfg,
+        &format!("fetching the latest result of aggregator {aggr_id}"),
+        sui_client
+            .read_api()
+            .get_object_with_options(aggr_id, SuiObjectDataOptions::new().with_content()),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::AggregatorDataQueryError)?;
+
+    let fields = match aggr_obj
+        .object()
+        .map_err(|error| RAMMDeploymentError::AggregatorObjectResponseError {
+            aggregator: aggr_id.into(),
+            error,
+        })?
+        .content
+        .as_ref()
+    {
+        Some(SuiParsedData::MoveObject(aggr)) => aggr.fields.clone().to_json_value(),
+        _ => return Err(malformed(aggr_id, "it is not a Move object")),
+    };
+
+    let update_data = &fields["update_data"];
+    let latest_result = &update_data["latest_result"];
+    let value = number(&latest_result["value"])
+        .ok_or_else(|| malformed(aggr_id, "`latest_result.value` is missing"))?;
+    let decimals = number(&latest_result["dec"])
+        .and_then(|dec| u8::try_from(dec).ok())
+        .ok_or_else(|| malformed(aggr_id, "`latest_result.dec` is missing"))?;
+    let negative = latest_result["neg"]
+        .as_bool()
+        .ok_or_else(|| malformed(aggr_id, "`latest_result.neg` is missing"))?;
+    let timestamp_secs = number(&update_data["latest_timestamp"])
+        .and_then(|timestamp| u64::try_from(timestamp).ok())
+        .ok_or_else(|| malformed(aggr_id, "`latest_timestamp` is missing"))?;
+
+    let now_secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs());
+
+    Ok(OraclePrice {
+        value,
+        decimals,
+        negative,
+        timestamp_secs,
+        age_secs: now_secs.saturating_sub(timestamp_secs),
+        stale: false,
+    })
+}
+
+/// Check that every asset's price feed, as read by `read_oracle_prices`, was updated within the
+/// config's `[oracle] max_staleness_secs`.
+///
+/// Every stale or unreadable feed is logged, so that all of them are reported at once, before the
+/// first is returned as `StaleOracle` or `UnreadableOracle`.
+pub fn check_oracles_fresh(dplymt_cfg: &RAMMDeploymentConfig) -> Result<(), RAMMDeploymentError> {
+    let max_staleness_secs = dplymt_cfg.oracle.max_staleness_secs;
+    let mut first_error = None;
+    let assets = dplymt_cfg
+        .ramms
+        .iter()
+        .flat_map(|ramm_spec| ramm_spec.assets.iter());
+    for asset in assets {
+        let aggregator = asset.aggregator_address;
+        let error = match &asset.oracle_reading {
+            Some(OracleReading::Price(price)) if price.stale => RAMMDeploymentError::StaleOracle {
+                aggregator,
+                age_secs: price.age_secs,
+                max_staleness_secs,
+            },
+            Some(OracleReading::Failed(reason)) => RAMMDeploymentError::UnreadableOracle {
+                aggregator,
+                reason: reason.clone(),
+            },
+            Some(OracleReading::Price(_)) => continue,
+            None => RAMMDeploymentError::UnreadableOracle {
+                aggregator,
+                reason: "it was not read before the deployment".to_string(),
+            },
+        };
+        log::error!("Price feed of asset {}: {error}", asset.label());
+        first_error.get_or_insert(error);
+    }
+
+    match first_error {
+        Some(error) => Err(error),
+        None => {
+            log::info!("Every read price feed was updated in the last {max_staleness_secs}s.");
+            Ok(())
+        }
+    }
+}
+
+/// The error for an aggregator whose fields are not those of a Switchboard `Aggregator`.
+fn malformed(aggr_id: ObjectID, reason: &str) -> RAMMDeploymentError {
+    RAMMDeploymentError::AggregatorResultParseError {
+        aggregator: aggr_id.into(),
+        reason: reason.to_string(),
+    }
+}
+
+/// An unsigned integer field, which the RPC renders as a string if it is wider than 32 bits.
+fn number(value: &Value) -> Option<u128> {
+    match value {
+        Value::String(number) => number.parse().ok(),
+        Value::Number(number) => number.as_u64().map(u128::from),
+        _ => None,
+    }
+}
@@ -0,0 +1,247 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    env, fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use sui_move_build::CompiledPackage;
+use sui_types::base_types::{ObjectID, SuiAddress};
+
+use crate::error::RAMMDeploymentError;
+
+/// Name of the directory, within the system's temporary directory, in which compiled packages are
+/// cached.
+const CACHE_DIR_NAME: &str = "ramm-sui-deploy-pkg-cache";
+
+/// Name of the file, within a prebuilt bytecode directory, holding the output of
+/// `sui move build --dump-bytecode-as-base64`.
+pub const BYTECODE_FILE_NAME: &str = "bytecode.json";
+
+/// The parts of a compiled RAMM library that the txs publishing or upgrading it need.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PackageArtifacts {
+    /// Bytecode of each of the package's modules, without its unpublished dependencies.
+    pub modules: Vec<Vec<u8>>,
+    /// IDs of the published packages the library depends on.
+    pub dep_ids: Vec<ObjectID>,
+    /// Names of the dependencies in `dep_ids`, as given in the library's `Move.toml`; unknown for
+    /// prebuilt bytecode.
+    ///
+    /// Cache entries written before this field was added no longer deserialize, and are merely
+    /// treated as cache misses.
+    pub dep_names: BTreeMap<ObjectID, String>,
+    /// Digest of the package, required by upgrades.
+    pub digest: Vec<u8>,
+    /// The Move compiler's warnings from building the library, so that they are reported again
+    /// when its cached compilation is reused; empty for prebuilt bytecode.
+    pub build_warnings: String,
+}
+
+impl PackageArtifacts {
+    /// Extract the artifacts from a freshly compiled package.
+    ///
+    /// The RAMM library has no unpublished deps - it depends on
+    /// 1. `move_stdlib`,
+    /// 2. `sui_framework`, and
+    /// 3. `switchboard`
+    /// which are all published. Should a development build depend on unpublished packages,
+    /// `with_unpublished_deps` bundles their modules into the package's own, in which case only
+    /// the published deps are listed in `dep_ids`, as the others are published along with it.
+    pub fn from_compiled(compiled_package: &CompiledPackage, with_unpublished_deps: bool) -> Self {
+        PackageArtifacts {
+            modules: compiled_package.get_package_bytes(with_unpublished_deps),
+            dep_ids: compiled_package
+                .dependency_ids
+                .published
+                .values()
+                .cloned()
+                .collect(),
+            dep_names: compiled_package
+                .dependency_ids
+                .published
+                .iter()
+                .map(|(name, id)| (*id, name.to_string()))
+                .collect(),
+            digest: compiled_package
+                .get_package_digest(with_unpublished_deps)
+                .to_vec(),
+            build_warnings: String::new(),
+        }
+    }
+}
+
+/// The JSON output of `sui move build --dump-bytecode-as-base64`.
+#[derive(Deserialize)]
+struct DumpedBytecode {
+    modules: Vec<String>,
+    dependencies: Vec<ObjectID>,
+    digest: Vec<u8>,
+}
+
+/// Read the artifacts of a library compiled beforehand, from the `bytecode.json` file in `dir`.
+///
+/// The directory's structure is checked, and every module decoded, before anything is returned.
+pub fn read_bytecode_dir(dir: &Path) -> Result<PackageArtifacts, RAMMDeploymentError> {
+    let err = |reason: String| RAMMDeploymentError::BytecodeDirError {
+        dir: dir.to_path_buf(),
+        reason,
+    };
+
+    if !dir.is_dir() {
+        return Err(err("it is not a directory".to_string()));
+    }
+    let bytecode_path = dir.join(BYTECODE_FILE_NAME);
+    let contents = fs::read_to_string(&bytecode_path).map_err(|e| {
+        err(format!(
+            "could not read `{BYTECODE_FILE_NAME}`, which should hold the output of \
+             `sui move build --dump-bytecode-as-base64`: {e}"
+        ))
+    })?;
+    let dumped: DumpedBytecode = serde_json::from_str(&contents)
+        .map_err(|e| err(format!("`{BYTECODE_FILE_NAME}` is malformed: {e}")))?;
+
+    if dumped.modules.is_empty() {
+        return Err(err(format!("`{BYTECODE_FILE_NAME}` holds no modules")));
+    }
+    let modules = dumped
+        .modules
+        .iter()
+        .enumerate()
+        .map(|(ix, module)| {
+            BASE64
+                .decode(module)
+                .map_err(|e| err(format!("module #{ix} is not valid base64: {e}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if dumped.digest.len() != 32 {
+        return Err(err(format!(
+            "the package digest has {} bytes, instead of 32",
+            dumped.digest.len()
+        )));
+    }
+
+    log::info!(
+        "Loaded {} prebuilt RAMM library modules from {}.",
+        modules.len(),
+        bytecode_path.display()
+    );
+
+    Ok(PackageArtifacts {
+        modules,
+        dep_ids: dumped.dependencies,
+        dep_names: BTreeMap::new(),
+        digest: dumped.digest,
+        build_warnings: String::new(),
+    })
+}
+
+/// Read the artifacts cached for the library at `package_path`, if its sources have not changed
+/// since they were cached, and they were compiled with the same `with_unpublished_deps` and named
+/// address overrides.
+///
+/// Any failure to read the cache is logged, and treated as a cache miss.
+pub fn read_cached(
+    package_path: &Path,
+    with_unpublished_deps: bool,
+    named_addresses: &BTreeMap<String, SuiAddress>,
+) -> Option<PackageArtifacts> {
+    let entry_path = match cache_entry_path(package_path, with_unpublished_deps, named_addresses) {
+        Ok(entry_path) => entry_path,
+        Err(e) => {
+            log::warn!("Could not hash the sources of the RAMM library: {e}");
+            return None;
+        }
+    };
+    let bytes = fs::read(&entry_path).ok()?;
+
+    match bcs::from_bytes(&bytes) {
+        Ok(artifacts) => Some(artifacts),
+        Err(e) => {
+            log::warn!(
+                "Ignoring the unreadable cached package at {}: {e}",
+                entry_path.display()
+            );
+            None
+        }
+    }
+}
+
+/// Cache the artifacts of the library at `package_path`, keyed on its current sources and
+/// `with_unpublished_deps` and named address overrides.
+///
+/// Caching is only an optimization, so any failure is logged rather than returned.
+pub fn write_cached(
+    package_path: &Path,
+    with_unpublished_deps: bool,
+    named_addresses: &BTreeMap<String, SuiAddress>,
+    artifacts: &PackageArtifacts,
+) {
+    let written = cache_entry_path(package_path, with_unpublished_deps, named_addresses).and_then(
+        |entry_path| {
+            let bytes =
+                bcs::to_bytes(artifacts).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            fs::create_dir_all(cache_dir())?;
+            fs::write(&entry_path, bytes)?;
+            Ok(entry_path)
+        },
+    );
+
+    match written {
+        Ok(entry_path) => log::debug!("Cached the compiled package at {}", entry_path.display()),
+        Err(e) => log::warn!("Could not cache the compiled RAMM library: {e}"),
+    }
+}
+
+fn cache_dir() -> PathBuf {
+    env::temp_dir().join(CACHE_DIR_NAME)
+}
+
+/// Path of the cache entry for the library at `package_path`, named after a hash of the library's
+/// canonical path, its `Move.toml` and `Move.lock`, every file under its `sources`, whether its
+/// unpublished deps are bundled, and the named addresses it is built with.
+///
+/// The hash is not stable across Rust releases; a toolchain upgrade merely causes a cache miss.
+fn cache_entry_path(
+    package_path: &Path,
+    with_unpublished_deps: bool,
+    named_addresses: &BTreeMap<String, SuiAddress>,
+) -> io::Result<PathBuf> {
+    let package_path = package_path.canonicalize()?;
+    let mut hasher = DefaultHasher::new();
+    package_path.hash(&mut hasher);
+    with_unpublished_deps.hash(&mut hasher);
+    named_addresses.hash(&mut hasher);
+
+    for manifest in ["Move.toml", "Move.lock"] {
+        // `Move.lock` is absent until the package's dependencies are first resolved.
+        if let Ok(contents) = fs::read(package_path.join(manifest)) {
+            manifest.hash(&mut hasher);
+            contents.hash(&mut hasher);
+        }
+    }
+    hash_dir(&package_path.join("sources"), &mut hasher)?;
+
+    Ok(cache_dir().join(format!("{:016x}.bcs", hasher.finish())))
+}
+
+/// Hash the path and contents of every file under `dir`, in a deterministic order.
+fn hash_dir(dir: &Path, hasher: &mut DefaultHasher) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            hash_dir(&path, hasher)?;
+        } else {
+            path.hash(hasher);
+            fs::read(&path)?.hash(hasher);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use std::fmt::Display;
+
+use colored::Colorize;
+use sui_json_rpc_types::{SuiExecutionStatus, SuiTransactionBlockEffectsAPI};
+use sui_sdk::SuiClient;
+use sui_types::{
+    base_types::{ObjectID, SuiAddress},
+    transaction::{ObjectArg, TransactionData, TransactionDataAPI},
+};
+
+use crate::{
+    add_assets_and_init_ramm,
+    error::RAMMDeploymentError,
+    get_all_coins, tx_status,
+    types::{AssetConfig, RAMMDeploymentConfig, RAMMSpec},
+    with_timeout, RAMMObjectArgs,
+};
+
+/// An asset the population PTB registers with the RAMM, as shown in its preview.
+#[derive(Debug)]
+struct PreviewedAsset {
+    /// The asset's index in the RAMM, once added.
+    index: usize,
+    asset_type: String,
+    symbol: Option<String>,
+    minimum_trade_amount: u64,
+    decimal_places: Option<u8>,
+}
+
+/// Summary of a population PTB's simulated effects, obtained by dev-inspecting it: which assets it
+/// registers, and what it would cost.
+#[derive(Debug)]
+pub struct PopulationPreview {
+    assets: Vec<PreviewedAsset>,
+    gas_used: u64,
+    created_count: usize,
+    mutated_count: usize,
+}
+
+/// Dev-inspect a population PTB, built by `add_assets_and_init_ramm` for the given assets, and
+/// summarize its simulated effects.
+///
+/// Should the simulation fail, the failure is returned as `MoveAbort` if it is one, or as
+/// `DevInspectFailed` otherwise: nothing is ever executed.
+pub async fn dev_inspect_population(
+    sui_client: &SuiClient,
+    tx_data: &TransactionData,
+    assets: &[AssetConfig],
+) -> Result<PopulationPreview, RAMMDeploymentError> {
+    let results = sui_client
+        .read_api()
+        .dev_inspect_transaction_block(
+            tx_data.sender(),
+            tx_data.kind().clone(),
+            Some(tx_data.gas_price().into()),
+            None,
+        )
+        .await
+        .map_err(RAMMDeploymentError::DevInspectQueryError)?;
+
+    if let SuiExecutionStatus::Failure { error } = results.effects.status() {
+        return Err(tx_status::parse_move_abort(error)
+            .unwrap_or_else(|| RAMMDeploymentError::DevInspectFailed(error.clone())));
+    }
+    if let Some(error) = results.error {
+        return Err(RAMMDeploymentError::DevInspectFailed(error));
+    }
+
+    Ok(PopulationPreview {
+        assets: assets
+            .iter()
+            .enumerate()
+            .map(|(ix, asset)| PreviewedAsset {
+                index: ix,
+                asset_type: asset.asset_type.to_string(),
+                symbol: asset
+                    .coin_name_and_symbol
+                    .as_ref()
+                    .map(|(_, symbol)| symbol.clone()),
+                minimum_trade_amount: asset.minimum_trade_amount,
+                decimal_places: asset.decimal_places,
+            })
+            .collect(),
+        gas_used: results.effects.gas_cost_summary().gas_used(),
+        created_count: results.effects.created().len(),
+        mutated_count: results.effects.mutated().len(),
+    })
+}
+
+/// Preview the population of a RAMM that already exists, without executing anything: its
+/// population PTB is built, and dev-inspected.
+///
+/// Initial liquidity is not previewed, as it has yet to be minted.
+///
+/// No gas is spent, so rather than merging coins to cover the budget, the largest coin of the
+/// signer - or of the sponsor, if the config has one - is used as the PTB's gas coin.
+pub async fn preview_population(
+    sui_client: &SuiClient,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
+    client_address: SuiAddress,
+    ramm_package_id: ObjectID,
+    ramm_obj_args: RAMMObjectArgs,
+    aggr_obj_args: Vec<ObjectArg>,
+) -> Result<PopulationPreview, RAMMDeploymentError> {
+    let gas_owner = dplymt_cfg.gas_owner(client_address);
+    let coin = with_timeout(
+        &dplymt_cfg.network,
+        "fetching the gas owner's coins",
+        get_all_coins(sui_client, gas_owner),
+    )
+    .await??
+    .into_iter()
+    .max_by_key(|coin| coin.balance)
+    .ok_or(RAMMDeploymentError::NoGasCoins(gas_owner))?;
+    let gas_price = with_timeout(
+        &dplymt_cfg.network,
+        "fetching the reference gas price",
+        sui_client.read_api().get_reference_gas_price(),
+    )
+    .await?
+    .map_err(RAMMDeploymentError::GasPriceQueryError)?;
+
+    let tx_data = add_assets_and_init_ramm(
+        dplymt_cfg,
+        ramm_spec,
+        client_address,
+        ramm_package_id,
+        ramm_obj_args,
+        aggr_obj_args,
+        &[],
+        coin,
+        gas_price,
+    )
+    .await?;
+
+    dev_inspect_population(sui_client, &tx_data, &ramm_spec.assets).await
+}
+
+impl Display for PopulationPreview {
+    /// Display the preview as a table of the assets to be registered, followed by the PTB's
+    /// simulated costs.
+    ///
+    /// This function uses [ANSI escape codes](https://en.wikipedia.org/wiki/ANSI_escape_code)
+    /// to color-code the output.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:\n", "Population PTB preview".on_bright_black())?;
+        write!(
+            f,
+            "\t{:<6} {:<8} {:<22} {:<9} {}\n",
+            "index".green(),
+            "symbol".green(),
+            "minimum trade amount".green(),
+            "decimals".green(),
+            "asset type".green()
+        )?;
+        for asset in &self.assets {
+            write!(
+                f,
+                "\t{:<6} {:<8} {:<22} {:<9} {}\n",
+                asset.index,
+                asset.symbol.as_deref().unwrap_or("-"),
+                asset.minimum_trade_amount,
+                asset
+                    .decimal_places
+                    .map_or("-".to_string(), |decimals| decimals.to_string()),
+                asset.asset_type
+            )?;
+        }
+        write!(
+            f,
+            "\t{}: {} MIST\n",
+            "Simulated gas used".green(),
+            self.gas_used
+        )?;
+        write!(
+            f,
+            "\t{}: {} created, {} mutated\n",
+            "Objects".green(),
+            self.created_count,
+            self.mutated_count
+        )
+    }
+}
@@ -0,0 +1,204 @@
+use std::{fmt::Display, time::Duration};
+
+use colored::Colorize;
+use serde::Serialize;
+use sui_json_rpc_types::{SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse};
+use sui_types::{base_types::ObjectID, digests::TransactionDigest};
+
+use crate::{mist_to_sui, RAMMObjectIDs};
+
+/// A transaction sent during the deployment, and the gas it used.
+#[derive(Clone, Debug, Serialize)]
+pub struct TxReport {
+    pub digest: TransactionDigest,
+    /// Gas used by the tx, in MIST: its computation and storage costs, before any storage rebate.
+    ///
+    /// `None` for txs executed in an earlier run, whose digests were read from a checkpoint.
+    pub gas_used: Option<u64>,
+    /// Storage rebate of the tx, in MIST, for the objects it deleted or shrank; `None` exactly when
+    /// `gas_used` is.
+    pub storage_rebate: Option<u64>,
+}
+
+impl TxReport {
+    /// Record an executed tx from the network's response to it.
+    pub fn from_response(response: &SuiTransactionBlockResponse) -> Self {
+        let gas_cost = response
+            .effects
+            .as_ref()
+            .map(|effects| effects.gas_cost_summary());
+        TxReport {
+            digest: response.digest,
+            gas_used: gas_cost.map(|gas_cost| gas_cost.gas_used()),
+            storage_rebate: gas_cost.map(|gas_cost| gas_cost.storage_rebate),
+        }
+    }
+
+    /// Record a tx executed in an earlier run, of which only the digest is known.
+    pub fn from_checkpoint(digest: TransactionDigest) -> Self {
+        TxReport {
+            digest,
+            gas_used: None,
+            storage_rebate: None,
+        }
+    }
+
+    /// What the tx actually cost, in MIST: its computation and storage costs, less its storage
+    /// rebate, which may exceed them.
+    pub fn net_gas_used(&self) -> Option<i64> {
+        Some(self.gas_used? as i64 - self.storage_rebate? as i64)
+    }
+}
+
+impl Display for TxReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.gas_used {
+            Some(gas_used) => write!(f, "{} ({} SUI)", self.digest, mist_to_sui(gas_used)),
+            None => write!(f, "{} (from the checkpoint)", self.digest),
+        }
+    }
+}
+
+/// A RAMM handled during the deployment, and the txs that created and populated it.
+#[derive(Clone, Debug, Serialize)]
+pub struct RAMMReport {
+    pub ramm: RAMMObjectIDs,
+    pub create_tx: TxReport,
+    /// The population PTB, which initialized the RAMM unless `populated` is `false`.
+    pub populate_tx: TxReport,
+    /// Whether the RAMM was populated and initialized.
+    pub populated: bool,
+}
+
+/// What each kind of tx executed in a deployment's run cost altogether, in MIST, net of storage
+/// rebates; see `TxReport::net_gas_used`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GasBreakdown {
+    pub publication: i64,
+    pub creation: i64,
+    pub population: i64,
+}
+
+impl GasBreakdown {
+    pub fn total(&self) -> i64 {
+        self.publication + self.creation + self.population
+    }
+}
+
+/// Format an amount of MIST that may be negative, e.g. a net gas cost, in SUI.
+pub fn signed_mist_to_sui(mist: i64) -> String {
+    let sign = if mist < 0 { "-" } else { "" };
+    format!("{sign}{}", mist_to_sui(mist.unsigned_abs()))
+}
+
+/// Summary of a RAMM deployment: the package used, the objects created, each tx executed to create
+/// them, with the gas it used, and how long the deployment took.
+#[derive(Clone, Debug, Serialize)]
+pub struct DeploymentReport {
+    pub network: String,
+    pub package_id: ObjectID,
+    /// The package publication or upgrade tx; `None` if an already published package was used.
+    pub publish_tx: Option<TxReport>,
+    /// If the package was upgraded rather than published, the ID of the package it upgraded.
+    pub upgraded_from: Option<ObjectID>,
+    pub ramms: Vec<RAMMReport>,
+    /// Wall-clock duration of the deployment, in seconds, from the connection to the network to
+    /// the last tx's execution.
+    pub duration_secs: f64,
+}
+
+impl DeploymentReport {
+    pub fn new(
+        network: String,
+        package_id: ObjectID,
+        publish_tx: Option<TxReport>,
+        upgraded_from: Option<ObjectID>,
+    ) -> Self {
+        DeploymentReport {
+            network,
+            package_id,
+            publish_tx,
+            upgraded_from,
+            ramms: Vec::new(),
+            duration_secs: 0.0,
+        }
+    }
+
+    /// Record the deployment's duration, once it is over.
+    pub fn finish(&mut self, duration: Duration) {
+        self.duration_secs = duration.as_secs_f64();
+    }
+
+    /// Gas used by all of the txs executed in this run, in MIST.
+    pub fn total_gas_used(&self) -> u64 {
+        self.publish_tx
+            .iter()
+            .chain(
+                self.ramms
+                    .iter()
+                    .flat_map(|ramm| [&ramm.create_tx, &ramm.populate_tx]),
+            )
+            .filter_map(|tx| tx.gas_used)
+            .sum()
+    }
+
+    /// What the package's publication, the RAMMs' creation, and their population, executed in
+    /// this run, cost, net of storage rebates.
+    pub fn gas_breakdown(&self) -> GasBreakdown {
+        let net = |tx: &TxReport| tx.net_gas_used().unwrap_or_default();
+        GasBreakdown {
+            publication: self.publish_tx.iter().map(net).sum(),
+            creation: self.ramms.iter().map(|ramm| net(&ramm.create_tx)).sum(),
+            population: self.ramms.iter().map(|ramm| net(&ramm.populate_tx)).sum(),
+        }
+    }
+}
+
+impl Display for DeploymentReport {
+    /// Display the report as a summary of the deployment, one RAMM at a time.
+    ///
+    /// This function uses [ANSI escape codes](https://en.wikipedia.org/wiki/ANSI_escape_code)
+    /// to color-code the output.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}\n", "Network".green(), self.network)?;
+        write!(f, "{}: {}\n", "RAMM package ID".green(), self.package_id)?;
+        if let Some(upgraded_from) = &self.upgraded_from {
+            write!(f, "{}: {}\n", "Upgraded from".green(), upgraded_from)?;
+        }
+        if let Some(publish_tx) = &self.publish_tx {
+            write!(f, "{}: {}\n", "Publication tx".green(), publish_tx)?;
+        }
+
+        for (ix, ramm) in self.ramms.iter().enumerate() {
+            write!(
+                f,
+                "{}:\n{}\n",
+                format!("RAMM #{}", ix + 1).on_bright_black(),
+                ramm.ramm
+            )?;
+            write!(f, "\t{}: {}\n", "Creation tx".green(), ramm.create_tx)?;
+            write!(f, "\t{}: {}\n", "Population tx".green(), ramm.populate_tx)?;
+            if !ramm.populated {
+                write!(f, "\t{}\n", "Population did not succeed".red())?;
+            }
+        }
+
+        write!(
+            f,
+            "{}: {} SUI\n",
+            "Total gas used, before storage rebates".green(),
+            mist_to_sui(self.total_gas_used())
+        )?;
+        let gas = self.gas_breakdown();
+        write!(f, "{}:\n", "Gas cost, net of storage rebates".green())?;
+        for (name, mist) in [
+            ("Publication", gas.publication),
+            ("RAMM creation", gas.creation),
+            ("RAMM population", gas.population),
+            ("Total", gas.total()),
+        ] {
+            write!(f, "\t{}: {} SUI\n", name.green(), signed_mist_to_sui(mist))?;
+        }
+        write!(f, "{}: {:.1}s", "Duration".green(), self.duration_secs)
+    }
+}
@@ -0,0 +1,205 @@
+use serde::Serialize;
+
+use crate::{
+    error::RAMMDeploymentError,
+    mist_to_sui,
+    report::{signed_mist_to_sui, DeploymentReport, TxReport},
+    types::OutputFormat,
+};
+
+/// Version of the schema of `JsonReport`, increased whenever a field is removed, renamed, or has
+/// its meaning changed; adding a field does not change it.
+pub const JSON_REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// The JSON form of a `DeploymentReport`: its fields, alongside the version of their schema.
+///
+/// The schema is:
+/// * `schema_version`: `JSON_REPORT_SCHEMA_VERSION`
+/// * `network`: the name of the network deployed to, e.g. `"testnet"`
+/// * `package_id`: the RAMM package's ID, as a `0x`-prefixed hex string
+/// * `publish_tx`: the package's publication or upgrade tx, as a tx object, or `null`
+/// * `upgraded_from`: the ID of the package that was upgraded, or `null`
+/// * `ramms`: an array with, for each RAMM, an object with
+///     - `ramm`: an object with the `ramm`, `admin_cap` and `new_asset_cap` IDs
+///     - `create_tx`: a tx object
+///     - `populate_tx`: a tx object
+///     - `populated`: whether the RAMM was populated and initialized
+/// * `duration_secs`: the deployment's duration, in seconds, as a float
+///
+/// A tx object has a `digest`, in base 58, and `gas_used` and `storage_rebate`, in MIST, which are
+/// `null` for txs executed in an earlier run; the tx's net cost is the former less the latter.
+#[derive(Serialize)]
+pub struct JsonReport<'a> {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub report: &'a DeploymentReport,
+}
+
+/// A row of the tabular forms of a report: an object or tx, its ID or digest, and, for txs, the
+/// gas it used.
+struct ReportRow {
+    item: String,
+    id: String,
+    gas: String,
+}
+
+impl ReportRow {
+    fn cells(&self) -> [&str; 3] {
+        [&self.item, &self.id, &self.gas]
+    }
+}
+
+/// Render a deployment's report in the given format.
+pub fn render_report(
+    report: &DeploymentReport,
+    format: OutputFormat,
+) -> Result<String, RAMMDeploymentError> {
+    match format {
+        OutputFormat::Human => Ok(format!("Success!\n{report}")),
+        OutputFormat::Json => serde_json::to_string_pretty(&JsonReport {
+            schema_version: JSON_REPORT_SCHEMA_VERSION,
+            report,
+        })
+        .map_err(RAMMDeploymentError::ReportSerializationError),
+        OutputFormat::Table => Ok(render_table(report)),
+        OutputFormat::Markdown => Ok(render_markdown(report)),
+    }
+}
+
+/// The report's objects and txs, one per row, in the order they were created.
+fn report_rows(report: &DeploymentReport) -> Vec<ReportRow> {
+    let object_row = |item: String, id: String| ReportRow {
+        item,
+        id,
+        gas: String::new(),
+    };
+    let tx_row = |item: String, tx: &TxReport| ReportRow {
+        item,
+        id: tx.digest.to_string(),
+        gas: tx.gas_used.map(mist_to_sui).unwrap_or_default(),
+    };
+
+    let mut rows = Vec::new();
+    if let Some(publish_tx) = &report.publish_tx {
+        rows.push(tx_row("Publication tx".to_string(), publish_tx));
+    }
+    rows.push(object_row(
+        "RAMM package".to_string(),
+        report.package_id.to_string(),
+    ));
+    if let Some(upgraded_from) = &report.upgraded_from {
+        rows.push(object_row(
+            "Upgraded from".to_string(),
+            upgraded_from.to_string(),
+        ));
+    }
+    for (ix, ramm) in report.ramms.iter().enumerate() {
+        let ramm_name = format!("RAMM #{}", ix + 1);
+        rows.push(tx_row(format!("{ramm_name} creation tx"), &ramm.create_tx));
+        rows.push(object_row(ramm_name.clone(), ramm.ramm.ramm.to_string()));
+        rows.push(object_row(
+            format!("{ramm_name} admin cap"),
+            ramm.ramm.admin_cap.to_string(),
+        ));
+        rows.push(object_row(
+            format!("{ramm_name} new asset cap"),
+            ramm.ramm.new_asset_cap.to_string(),
+        ));
+        rows.push(tx_row(
+            format!("{ramm_name} population tx"),
+            &ramm.populate_tx,
+        ));
+        if !ramm.populated {
+            rows.push(object_row(
+                format!("{ramm_name} population did not succeed"),
+                String::new(),
+            ));
+        }
+    }
+    rows.push(ReportRow {
+        item: "Total".to_string(),
+        id: String::new(),
+        gas: mist_to_sui(report.total_gas_used()),
+    });
+    let gas = report.gas_breakdown();
+    for (name, mist) in [
+        ("publication", gas.publication),
+        ("RAMM creation", gas.creation),
+        ("RAMM population", gas.population),
+        ("total", gas.total()),
+    ] {
+        rows.push(ReportRow {
+            item: format!("Net cost of {name}, after storage rebates"),
+            id: String::new(),
+            gas: signed_mist_to_sui(mist),
+        });
+    }
+
+    rows
+}
+
+/// Headers of the columns of the tabular forms of a report.
+const HEADER: [&str; 3] = ["Object or tx", "ID or digest", "Gas used (SUI)"];
+
+/// Render the report as a plain-text table, with its columns aligned.
+fn render_table(report: &DeploymentReport) -> String {
+    let rows = report_rows(report);
+    let mut widths = HEADER.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row.cells()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let line = |cells: [&str; 3]| {
+        format!(
+            "{:<w0$}  {:<w1$}  {:>w2$}",
+            cells[0],
+            cells[1],
+            cells[2],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        )
+        .trim_end()
+        .to_string()
+    };
+
+    let mut table = vec![
+        line(HEADER),
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("  "),
+    ];
+    table.extend(rows.iter().map(|row| line(row.cells())));
+    table.push(format!("Network: {}", report.network));
+    table.push(format!("Duration: {:.1}s", report.duration_secs));
+
+    table.join("\n")
+}
+
+/// Render the report as a Markdown table, with IDs and digests as inline code.
+fn render_markdown(report: &DeploymentReport) -> String {
+    let code = |text: &str| match text {
+        "" => String::new(),
+        text => format!("`{text}`"),
+    };
+
+    let mut markdown = vec![
+        format!(
+            "Deployment to **{}**, in {:.1}s.",
+            report.network, report.duration_secs
+        ),
+        String::new(),
+        format!("| {} | {} | {} |", HEADER[0], HEADER[1], HEADER[2]),
+        "| --- | --- | ---: |".to_string(),
+    ];
+    markdown.extend(
+        report_rows(report)
+            .iter()
+            .map(|row| format!("| {} | {} | {} |", row.item, code(&row.id), row.gas)),
+    );
+
+    markdown.join("\n")
+}
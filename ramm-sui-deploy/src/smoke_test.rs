@@ -0,0 +1,435 @@
+use sui_json_rpc_types::{
+    SuiExecutionStatus, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse, SuiTypeTag,
+};
+use sui_keys::keystore::Keystore;
+use sui_sdk::{json::SuiJsonValue, SuiClient};
+use sui_types::{
+    base_types::{ObjectID, SuiAddress},
+    object::Owner,
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    transaction::{Argument, ObjectArg, TransactionData},
+    Identifier, TypeTag,
+};
+
+use crate::{
+    error::RAMMDeploymentError,
+    estimate_gas_budget, get_coin_and_gas, sign_and_execute_tx, sponsor_tx,
+    types::{FaucetData, RAMMDeploymentConfig, RAMMSpec, SUPPORTED_ASSET_COUNTS},
+    RAMMObjectArgs, RAMM_MODULE_NAME,
+};
+
+/// Gas budget for each of the smoke tests' transactions, before it is refined by a dry run.
+const SMOKE_TEST_GAS_BUDGET: u64 = 100_000_000;
+
+/// Check that a smoke test's tx succeeded; if it did not, its failure is returned as
+/// `SmokeTestFailed`.
+fn check_tx_status(
+    response: &SuiTransactionBlockResponse,
+    tx_name: &str,
+) -> Result<(), RAMMDeploymentError> {
+    match response.effects.as_ref().map(|effects| effects.status()) {
+        Some(SuiExecutionStatus::Success) => Ok(()),
+        Some(SuiExecutionStatus::Failure { error }) => Err(RAMMDeploymentError::SmokeTestFailed(
+            format!("{tx_name} tx {}: {error}", response.digest),
+        )),
+        None => Err(RAMMDeploymentError::SmokeTestFailed(format!(
+            "{tx_name} tx {} has no effects",
+            response.digest
+        ))),
+    }
+}
+
+/// Name the module and function of the RAMM's public interface for RAMMs of the given size,
+/// e.g. `interface2::trade_amount_in_2` for a `function` of `trade_amount_in` and 2 assets.
+///
+/// The RAMM package only has interfaces for the `SUPPORTED_ASSET_COUNTS`, which the config's
+/// validation already enforces.
+pub(crate) fn interface_function(
+    asset_count: u8,
+    function: &str,
+) -> Result<(Identifier, Identifier), RAMMDeploymentError> {
+    if !SUPPORTED_ASSET_COUNTS.contains(&asset_count) {
+        return Err(RAMMDeploymentError::SmokeTestUnsupportedAssetCount {
+            count: asset_count,
+            supported: SUPPORTED_ASSET_COUNTS.to_vec(),
+        });
+    }
+    let to_identifier = |name: String| {
+        Identifier::new(name).map_err(|err| RAMMDeploymentError::PtbBuildError(err.to_string()))
+    };
+
+    Ok((
+        to_identifier(format!("interface{asset_count}"))?,
+        to_identifier(format!("{function}_{asset_count}"))?,
+    ))
+}
+
+/// Mint `amount` coins of type `coin_type` from the faucet, and return the `ObjectArg` of the
+/// minted coin, which is owned by `client_address`.
+pub async fn mint_test_coins(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    faucet: &FaucetData,
+    client_address: SuiAddress,
+    coin_type: &TypeTag,
+    amount: u64,
+    gas_coin: Option<ObjectID>,
+) -> Result<ObjectArg, RAMMDeploymentError> {
+    // `u64`s are passed to Move calls as JSON strings.
+    let amount_arg = SuiJsonValue::new(serde_json::Value::String(amount.to_string()))
+        .map_err(RAMMDeploymentError::FaucetMintTxError)?;
+    let mint_tx = sui_client
+        .transaction_builder()
+        .move_call(
+            client_address,
+            faucet.package_id,
+            &faucet.module_name,
+            "mint_test_coins",
+            vec![SuiTypeTag::from(coin_type.clone())],
+            vec![SuiJsonValue::from_object_id(faucet.faucet_id), amount_arg],
+            gas_coin,
+            SMOKE_TEST_GAS_BUDGET,
+        )
+        .await
+        .map(|tx_data| sponsor_tx(tx_data, dplymt_cfg.sponsor.as_ref()))
+        .map_err(RAMMDeploymentError::FaucetMintTxError)?;
+    let mint_tx = estimate_gas_budget(sui_client, mint_tx, dplymt_cfg.gas.safety_margin()).await?;
+
+    let response = sign_and_execute_tx(
+        sui_client,
+        keystore,
+        mint_tx,
+        &client_address,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await?;
+    check_tx_status(&response, "Faucet mint")?;
+    log::info!("Minted {amount} of {coin_type} from the faucet.");
+
+    let coin_ref = response
+        .effects
+        .as_ref()
+        .and_then(|effects| {
+            effects
+                .created()
+                .iter()
+                .find(|oor| oor.owner == Owner::AddressOwner(client_address))
+                .map(|oor| oor.reference.to_object_ref())
+        })
+        .ok_or(RAMMDeploymentError::MintedCoinNotFound)?;
+
+    Ok(ObjectArg::ImmOrOwnedObject(coin_ref))
+}
+
+/// Submit a minimal trade against a deployed RAMM, to check that it can actually be traded with:
+/// 1. the first asset's minimum trade amount is minted from the faucet, and
+/// 2. traded in for the second asset via the RAMM's `trade_amount_in_<N>`, accepting any amount
+///    out.
+///
+/// The trade aborts unless the RAMM holds liquidity for the first asset, and a balance of the
+/// second; any failure of the trade is returned as `SmokeTestFailed`.
+pub async fn smoke_trade(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
+    client_address: SuiAddress,
+    ramm_package_id: ObjectID,
+    ramm_obj_args: RAMMObjectArgs,
+    aggr_obj_args: &[ObjectArg],
+    gas_coin: Option<ObjectID>,
+) -> Result<(), RAMMDeploymentError> {
+    let faucet = dplymt_cfg
+        .faucet
+        .as_ref()
+        .ok_or(RAMMDeploymentError::SmokeTestWithoutFaucet)?;
+    let (module, function) = interface_function(ramm_spec.asset_count(), "trade_amount_in")?;
+
+    let asset_in = &ramm_spec.assets[0];
+    let coin_in = mint_test_coins(
+        sui_client,
+        keystore,
+        dplymt_cfg,
+        faucet,
+        client_address,
+        &asset_in.asset_type,
+        asset_in.minimum_trade_amount,
+        gas_coin,
+    )
+    .await?;
+
+    // The interface's type arguments, and its aggregators, are those of the inbound asset, then
+    // the outbound one, then the rest - which is the order in which the RAMM's assets are given.
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    let ptb_build_error =
+        |err: anyhow::Error| RAMMDeploymentError::PtbBuildError(format!("smoke trade: {err}"));
+    let mut move_call_args: Vec<Argument> = vec![
+        ptb.obj(ramm_obj_args.ramm).map_err(ptb_build_error)?,
+        ptb.obj(coin_in).map_err(ptb_build_error)?,
+        // Minimum amount out
+        ptb.pure(0u64).map_err(ptb_build_error)?,
+    ];
+    for aggr_obj_arg in aggr_obj_args {
+        move_call_args.push(ptb.obj(*aggr_obj_arg).map_err(ptb_build_error)?);
+    }
+    let type_args: Vec<TypeTag> = ramm_spec
+        .assets
+        .iter()
+        .map(|asset| asset.asset_type.clone())
+        .collect();
+    ptb.programmable_move_call(ramm_package_id, module, function, type_args, move_call_args);
+
+    let (coin, gas_price) = get_coin_and_gas(
+        sui_client,
+        keystore,
+        dplymt_cfg.gas_owner(client_address),
+        SMOKE_TEST_GAS_BUDGET,
+        dplymt_cfg.gas.price,
+        gas_coin,
+        &dplymt_cfg.gas,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await?;
+    let trade_tx = sponsor_tx(
+        TransactionData::new_programmable(
+            client_address,
+            vec![coin.object_ref()],
+            ptb.finish(),
+            SMOKE_TEST_GAS_BUDGET,
+            gas_price,
+        ),
+        dplymt_cfg.sponsor.as_ref(),
+    );
+    let trade_tx =
+        estimate_gas_budget(sui_client, trade_tx, dplymt_cfg.gas.safety_margin()).await?;
+
+    let response = sign_and_execute_tx(
+        sui_client,
+        keystore,
+        trade_tx,
+        &client_address,
+        &dplymt_cfg.retry,
+        &dplymt_cfg.network,
+    )
+    .await?;
+
+    check_tx_status(&response, "Smoke trade")
+}
+
+/// Seed a deployed RAMM with liquidity, `whole_units` of each of its assets, and check that LP
+/// tokens are minted back for each deposit.
+///
+/// For each asset, in turn:
+/// 1. `whole_units * 10^decimal_places` of it are minted from the faucet,
+/// 2. deposited into the RAMM via its `liquidity_deposit_<N>`, and
+/// 3. the `Coin<LP<Asset>>` created by the deposit is checked to hold a non-zero amount.
+///
+/// The first failure is returned as `SmokeTestFailed`, and the remaining assets are not seeded.
+pub async fn seed_liquidity(
+    sui_client: &SuiClient,
+    keystore: &Keystore,
+    dplymt_cfg: &RAMMDeploymentConfig,
+    ramm_spec: &RAMMSpec,
+    client_address: SuiAddress,
+    ramm_package_id: ObjectID,
+    ramm_obj_args: RAMMObjectArgs,
+    aggr_obj_args: &[ObjectArg],
+    whole_units: u64,
+    gas_coin: Option<ObjectID>,
+) -> Result<(), RAMMDeploymentError> {
+    let faucet = dplymt_cfg
+        .faucet
+        .as_ref()
+        .ok_or(RAMMDeploymentError::SmokeTestWithoutFaucet)?;
+    let (module, function) = interface_function(ramm_spec.asset_count(), "liquidity_deposit")?;
+
+    for (ix, asset) in ramm_spec.assets.iter().enumerate() {
+        let amount = 10u64
+            .checked_pow(asset.resolved_decimal_places()?.into())
+            .and_then(|unit| unit.checked_mul(whole_units))
+            .ok_or_else(|| {
+                RAMMDeploymentError::SmokeTestFailed(format!(
+                    "{whole_units} units of asset {} overflow a `u64`",
+                    asset.label()
+                ))
+            })?;
+        let coin_in = mint_test_coins(
+            sui_client,
+            keystore,
+            dplymt_cfg,
+            faucet,
+            client_address,
+            &asset.asset_type,
+            amount,
+            gas_coin,
+        )
+        .await?;
+
+        // The interface's type arguments, and its aggregators, are those of the deposited asset,
+        // followed by those of the others, in the order in which the RAMM's assets are given.
+        let order: Vec<usize> = std::iter::once(ix)
+            .chain((0..ramm_spec.assets.len()).filter(|other| *other != ix))
+            .collect();
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let ptb_build_error = |err: anyhow::Error| {
+            RAMMDeploymentError::PtbBuildError(format!("liquidity deposit: {err}"))
+        };
+        let mut move_call_args: Vec<Argument> = vec![
+            ptb.obj(ramm_obj_args.ramm).map_err(ptb_build_error)?,
+            ptb.obj(coin_in).map_err(ptb_build_error)?,
+        ];
+        for &asset_ix in &order {
+            move_call_args.push(ptb.obj(aggr_obj_args[asset_ix]).map_err(ptb_build_error)?);
+        }
+        let type_args: Vec<TypeTag> = order
+            .iter()
+            .map(|&asset_ix| ramm_spec.assets[asset_ix].asset_type.clone())
+            .collect();
+        ptb.programmable_move_call(
+            ramm_package_id,
+            module.clone(),
+            function.clone(),
+            type_args,
+            move_call_args,
+        );
+
+        let (coin, gas_price) = get_coin_and_gas(
+            sui_client,
+            keystore,
+            dplymt_cfg.gas_owner(client_address),
+            SMOKE_TEST_GAS_BUDGET,
+            dplymt_cfg.gas.price,
+            gas_coin,
+            &dplymt_cfg.gas,
+            &dplymt_cfg.retry,
+            &dplymt_cfg.network,
+        )
+        .await?;
+        let deposit_tx = sponsor_tx(
+            TransactionData::new_programmable(
+                client_address,
+                vec![coin.object_ref()],
+                ptb.finish(),
+                SMOKE_TEST_GAS_BUDGET,
+                gas_price,
+            ),
+            dplymt_cfg.sponsor.as_ref(),
+        );
+        let deposit_tx =
+            estimate_gas_budget(sui_client, deposit_tx, dplymt_cfg.gas.safety_margin()).await?;
+
+        let response = sign_and_execute_tx(
+            sui_client,
+            keystore,
+            deposit_tx,
+            &client_address,
+            &dplymt_cfg.retry,
+            &dplymt_cfg.network,
+        )
+        .await?;
+        check_tx_status(&response, "Liquidity deposit")?;
+
+        let lp_tokens = minted_lp_tokens(
+            sui_client,
+            &response,
+            client_address,
+            ramm_package_id,
+            &asset.asset_type,
+        )
+        .await?
+        .ok_or_else(|| {
+            RAMMDeploymentError::SmokeTestFailed(format!(
+                "the liquidity deposit tx {} created no LP token coin",
+                response.digest
+            ))
+        })?;
+        if lp_tokens == 0 {
+            return Err(RAMMDeploymentError::SmokeTestFailed(format!(
+                "depositing {amount} of {} minted no LP tokens",
+                asset.asset_type
+            )));
+        }
+        log::info!(
+            "Deposited {amount} of {} into the RAMM, for {lp_tokens} LP tokens.",
+            asset.asset_type
+        );
+    }
+
+    Ok(())
+}
+
+/// Find the amount of the `Coin<LP<Asset>>` created by a liquidity deposit tx, owned by
+/// `client_address`; `None` if the tx created no such coin.
+///
+/// A tx's effects only hold references to the objects it created, so the coins of the LP token's
+/// type owned by the address are queried, and matched against them.
+pub(crate) async fn minted_lp_tokens(
+    sui_client: &SuiClient,
+    deposit_response: &SuiTransactionBlockResponse,
+    client_address: SuiAddress,
+    ramm_package_id: ObjectID,
+    asset_type: &TypeTag,
+) -> Result<Option<u64>, RAMMDeploymentError> {
+    let created_ids: Vec<ObjectID> = deposit_response
+        .effects
+        .as_ref()
+        .map(|effects| {
+            effects
+                .created()
+                .iter()
+                .filter(|oor| oor.owner == Owner::AddressOwner(client_address))
+                .map(|oor| oor.object_id())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let lp_token_type = format!("{ramm_package_id}::{RAMM_MODULE_NAME}::LP<{asset_type}>");
+    let mut cursor: Option<ObjectID> = None;
+    loop {
+        let coin_page = sui_client
+            .coin_read_api()
+            .get_coins(client_address, Some(lp_token_type.clone()), cursor, None)
+            .await
+            .map_err(RAMMDeploymentError::CoinQueryError)?;
+        if let Some(lp_coin) = coin_page
+            .data
+            .iter()
+            .find(|coin| created_ids.contains(&coin.coin_object_id))
+        {
+            return Ok(Some(lp_coin.balance));
+        }
+        if !coin_page.has_next_page {
+            return Ok(None);
+        }
+        cursor = coin_page.next_cursor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interface_function_names_the_sized_interface() {
+        for asset_count in [2, 3] {
+            let (module, function) = interface_function(asset_count, "trade_amount_in").unwrap();
+            assert_eq!(module.as_str(), format!("interface{asset_count}"));
+            assert_eq!(function.as_str(), format!("trade_amount_in_{asset_count}"));
+        }
+    }
+
+    #[test]
+    fn interface_function_rejects_unsupported_asset_counts() {
+        for asset_count in [1, 4] {
+            assert!(matches!(
+                interface_function(asset_count, "trade_amount_in"),
+                Err(RAMMDeploymentError::SmokeTestUnsupportedAssetCount { count, .. })
+                    if count == asset_count
+            ));
+        }
+    }
+}
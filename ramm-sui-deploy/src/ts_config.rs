@@ -0,0 +1,109 @@
+use std::{fs, path::Path};
+
+use serde::Serialize;
+use sui_types::base_types::{ObjectID, SuiAddress};
+
+use crate::{
+    error::RAMMDeploymentError,
+    types::{OracleProvider, RAMMDeploymentConfig, RAMMSpec},
+    RAMMObjectIDs, RAMM_MODULE_NAME,
+};
+
+/// An asset of a deployed RAMM, with the data the TS SDK needs to interact with it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TsAssetConfig {
+    /// The asset's type, in the form `<package-id>::<module>::<type-name>`.
+    pub asset_type: String,
+    /// The type of the LP tokens the RAMM issues for the asset, i.e. `ramm::LP<asset_type>`.
+    pub lp_token_type: String,
+    /// ID of the price feed object used to price the asset, of the kind given by
+    /// `oracle_provider`.
+    pub aggregator_id: SuiAddress,
+    pub oracle_provider: OracleProvider,
+    /// Always present once the deployment has resolved it; see `AssetConfig::decimal_places`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimal_places: Option<u8>,
+    /// Kept as a string, as it may not fit in a JS `number`.
+    pub minimum_trade_amount: String,
+}
+
+/// A deployed RAMM, with the data the TS SDK needs to interact with it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TsRAMMConfig {
+    pub ramm_id: ObjectID,
+    pub admin_cap_id: ObjectID,
+    pub fee_collection_address: SuiAddress,
+    pub assets: Vec<TsAssetConfig>,
+}
+
+/// The constants the TS SDK expects for a deployment, so that the frontend can be pointed at newly
+/// deployed RAMMs without transcribing their IDs from the tool's logs.
+///
+/// It is written either as a `.ts` module exporting a single `RAMM_SDK_CONFIG` constant, or, if
+/// the path ends in `.json`, as plain JSON, which TS can import as well.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TsSdkConfig {
+    pub network: String,
+    pub package_id: ObjectID,
+    pub ramms: Vec<TsRAMMConfig>,
+}
+
+impl TsSdkConfig {
+    /// Build a TS SDK config, without any RAMMs, for the given deployment and package.
+    pub fn new(dplymt_cfg: &RAMMDeploymentConfig, package_id: ObjectID) -> Self {
+        TsSdkConfig {
+            network: dplymt_cfg.target_env.clone(),
+            package_id,
+            ramms: Vec::new(),
+        }
+    }
+
+    /// Add a successfully deployed RAMM to the config, from its specification and the IDs of its
+    /// objects.
+    pub fn add_ramm(&mut self, ramm_spec: &RAMMSpec, ramm: &RAMMObjectIDs) {
+        let assets = ramm_spec
+            .assets
+            .iter()
+            .map(|asset| TsAssetConfig {
+                asset_type: asset.asset_type.to_string(),
+                lp_token_type: format!(
+                    "{}::{}::LP<{}>",
+                    self.package_id, RAMM_MODULE_NAME, asset.asset_type
+                ),
+                aggregator_id: asset.aggregator_address,
+                oracle_provider: asset.oracle_provider,
+                decimal_places: asset.decimal_places,
+                minimum_trade_amount: asset.minimum_trade_amount.to_string(),
+            })
+            .collect();
+
+        self.ramms.push(TsRAMMConfig {
+            ramm_id: ramm.ramm,
+            admin_cap_id: ramm.admin_cap,
+            fee_collection_address: ramm_spec.fee_collection_address,
+            assets,
+        });
+    }
+
+    /// Write the config to the file at the given path, as a TS module, or as JSON if the path
+    /// ends in `.json`.
+    ///
+    /// If the file already exists, it is overwritten.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), RAMMDeploymentError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(RAMMDeploymentError::TsConfigSerializationError)?;
+
+        let contents = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => json,
+            _ => format!(
+                "// Generated by ramm-sui-deploy - do not edit by hand.\n\
+                export const RAMM_SDK_CONFIG = {json} as const;\n"
+            ),
+        };
+
+        fs::write(path, contents).map_err(RAMMDeploymentError::TsConfigWriteError)
+    }
+}
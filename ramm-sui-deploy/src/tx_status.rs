@@ -0,0 +1,94 @@
+use sui_json_rpc_types::{
+    SuiExecutionStatus, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse,
+};
+
+use crate::error::RAMMDeploymentError;
+
+/// Check that an executed tx succeeded.
+///
+/// If it failed with a Move abort, `MoveAbort` is returned, describing the abort code if it is
+/// one of the RAMM library's; any other failure is returned as `TxExecutionFailed`.
+pub fn check_tx_status(response: &SuiTransactionBlockResponse) -> Result<(), RAMMDeploymentError> {
+    match response.effects.as_ref().map(|effects| effects.status()) {
+        Some(SuiExecutionStatus::Success) => Ok(()),
+        Some(SuiExecutionStatus::Failure { error }) => Err(parse_move_abort(error).unwrap_or_else(
+            || RAMMDeploymentError::TxExecutionFailed {
+                digest: response.digest,
+                error: error.clone(),
+            },
+        )),
+        None => Err(RAMMDeploymentError::TxExecutionFailed {
+            digest: response.digest,
+            error: "the tx response has no effects".to_string(),
+        }),
+    }
+}
+
+/// Extract the module and abort code from a failed tx's execution status error, if it is a Move
+/// abort, and return them as `MoveAbort`.
+///
+/// The RPC renders such errors as
+/// ```text
+/// MoveAbort(MoveLocation { module: ModuleId { address: <address>, name: Identifier("ramm") },
+///     function: 3, instruction: 10, function_name: Some("add_asset_to_ramm") }, 4) in command 1
+/// ```
+/// with the abort code being the last argument of `MoveAbort`.
+pub fn parse_move_abort(error: &str) -> Option<RAMMDeploymentError> {
+    let location = error.strip_prefix("MoveAbort(")?;
+    let module = quoted_after(location, "name: Identifier(\"")?;
+    let function = quoted_after(location, "function_name: Some(\"");
+
+    // The abort code follows the `MoveLocation`'s closing brace.
+    let (_, code) = location.rsplit_once("}, ")?;
+    let code: u64 = code.split(')').next()?.trim().parse().ok()?;
+
+    let mut message = abort_code_message(module, code)
+        .unwrap_or("unknown abort code")
+        .to_string();
+    if let Some(function) = function {
+        message.push_str(&format!(", in `{module}::{function}`"));
+    }
+
+    Some(RAMMDeploymentError::MoveAbort {
+        module: module.to_string(),
+        code,
+        message,
+    })
+}
+
+/// The text between `prefix` and the next `"` in `s`.
+fn quoted_after<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let (_, rest) = s.split_once(prefix)?;
+    rest.split('"').next()
+}
+
+/// Human-readable meaning of the RAMM library's abort codes, as declared in each of its modules'
+/// `E*` constants.
+fn abort_code_message(module: &str, code: u64) -> Option<&'static str> {
+    let message = match (module, code) {
+        ("ramm", 0) => "the RAMM is not in a valid state for initialization",
+        ("ramm", 1) => "the aggregator does not match the one registered for the asset",
+        ("ramm", 2) => "the admin cap does not belong to this RAMM",
+        ("ramm", 3) => "the RAMM has no assets",
+        ("ramm", 4) => "the asset could not be added to the RAMM",
+        ("ramm", 5) => "the RAMM is not initialized",
+        ("ramm", 6) => "the new asset cap does not belong to this RAMM",
+        ("ramm", 7) => "the RAMM's invariants would be broken",
+        ("ramm", 8) => "the trade's inbound amount exceeds the set maximum",
+        ("ramm", 9) => "the trade's outbound amount exceeds the set maximum",
+        ("ramm", 10) => "the RAMM is already initialized",
+        ("interface2" | "interface3", 0) => "the RAMM has the wrong asset count for this interface",
+        ("interface2" | "interface3", 1) => "deposits of the asset are disabled",
+        ("interface2" | "interface3", 2) => "the deposit is invalid",
+        ("interface2" | "interface3", 3) => "there are no LP tokens in circulation",
+        ("interface2" | "interface3", 4) => "the RAMM's balance is insufficient",
+        ("interface2" | "interface3", 5) => "the RAMM could not redeem circulating LP tokens",
+        ("interface2" | "interface3", 6) => "the trade amount is below the asset's minimum",
+        ("interface2" | "interface3", 7) => "the admin cap does not belong to this RAMM",
+        ("interface2" | "interface3", 8) => "the LP tokens could not be burned",
+        ("interface2" | "interface3", 9) => "the withdrawal is invalid",
+        _ => return None,
+    };
+
+    Some(message)
+}
@@ -1,40 +1,732 @@
-use std::{fmt::Display, path::PathBuf, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
 
 use colored::Colorize;
-use serde::{de, Deserialize, Deserializer};
+use schemars::JsonSchema;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use sui_types::{
     base_types::{ObjectID, SuiAddress},
+    quorum_driver_types::ExecuteTransactionRequestType,
     TypeTag,
 };
 
+use crate::{
+    error::{ConfigValidationError, RAMMDeploymentError},
+    oracle::OracleReading,
+    RAMMObjectIDs,
+};
+
 /// Minimum number of decimal places assets in Sui are allowed to have - no exact reasoning here,
 /// just a heuristic in case a user writes something bad into the TOML config.
 const ASSET_MIN_DECIMAL_PLACES: u8 = 4;
 
+/// Maximum number of decimal places an asset may have.
+///
+/// This is the RAMM's `PRECISION_DECIMAL_PLACES` in Sui Move: `add_asset_to_ramm` scales balances
+/// by `10^(PRECISION_DECIMAL_PLACES - decimal_places)`, which underflows for assets with more.
+const ASSET_MAX_DECIMAL_PLACES: u8 = 12;
+
+/// Default factor by which the gas used by a transaction in its dry run is multiplied, to obtain
+/// the budget with which it is then executed.
+///
+/// This leaves some leeway for variations in on-chain state between the dry run and the execution.
+pub const DEFAULT_GAS_SAFETY_MARGIN: f64 = 1.2;
+
+/// Smallest gas safety margin allowed: budgets are never set below the gas used in the dry run.
+pub const MIN_GAS_SAFETY_MARGIN: f64 = 1.0;
+
+/// Largest gas safety margin allowed; larger margins are clamped to it.
+pub const MAX_GAS_SAFETY_MARGIN: f64 = 3.0;
+
+/// A minimum trade amount smaller than `10^-MIN_TRADE_WARNING_DECIMAL_PLACES` units of its asset is
+/// considered implausibly small, and a warning is logged - another heuristic, as there can be
+/// legitimate reasons for it.
+const MIN_TRADE_WARNING_DECIMAL_PLACES: u8 = 6;
+
+/// The numbers of assets a RAMM may have: those for which the RAMM package has a trading and
+/// liquidity interface, `interface2` and `interface3`.
+///
+/// The package creates and initializes RAMMs of every size with the same `new_ramm` and
+/// `initialize_ramm` functions; a RAMM of any other size could be deployed, but not traded with.
+pub const SUPPORTED_ASSET_COUNTS: [u8; 2] = [2, 3];
+
+/// The target environment, and Suibase workdir, of a local network for development.
+pub const LOCALNET: &str = "localnet";
+
+/// The target environments a config may name: Suibase's workdirs, and its `active` shorthand.
+pub(crate) const TARGET_ENVS: [&str; 5] = ["active", "localnet", "devnet", "testnet", "mainnet"];
+
+/// Maximum gas budget, in MIST, that any single deployment transaction may be given.
+///
+/// This mirrors the Sui protocol's maximum transaction gas budget of 50 SUI; anything above it
+/// would be rejected by the network anyway, and is most likely a typo in the TOML config.
+pub(crate) const MAX_GAS_BUDGET: u64 = 50_000_000_000;
+
+/// Gas budget, in MIST, above which a deployment transaction's budget is likely to be a typo, e.g.
+/// an extra zero, rather than intended; such a budget is warned about, or, under `--strict`,
+/// refused.
+///
+/// At 5 SUI, it is 10 times the compiled-in default for the package's publication, the costliest
+/// of the deployment's transactions.
+pub(crate) const GAS_BUDGET_SANITY_CEILING: u64 = 5_000_000_000;
+
+/// Gas budgets, in MIST, for each of the transactions sent to the network during the RAMM's
+/// deployment.
+///
+/// All fields are optional: if a field is absent from the TOML config, the compiled-in default
+/// for that transaction is used instead.
+///
+/// The `[gas.testnet]`, `[gas.mainnet]` and `[gas.localnet]` tables hold per-network profiles;
+/// once the target network is known, `select_profile` layers the matching one over the `[gas]`
+/// table's budgets.
+#[derive(Debug, Default, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GasConfig {
+    /// Gas budget for the transaction that publishes the RAMM package.
+    pub publication: Option<u64>,
+    /// Gas budget for the transaction that creates the RAMM.
+    pub create_ramm: Option<u64>,
+    /// Gas budget for the PTB that adds assets to the RAMM, and initializes it.
+    pub populate: Option<u64>,
+    /// Gas price, in MIST per unit of gas, for the PTB that adds assets to the RAMM; if absent,
+    /// the network's reference gas price is used.
+    ///
+    /// Bidding above the reference price can help get the PTB included during congestion.
+    pub price: Option<u64>,
+    /// Factor by which the gas a transaction used in its dry run is multiplied, to obtain the
+    /// budget with which it is then executed; if absent, `DEFAULT_GAS_SAFETY_MARGIN` is used.
+    ///
+    /// It is clamped to `MIN_GAS_SAFETY_MARGIN..=MAX_GAS_SAFETY_MARGIN`.
+    pub safety_margin: Option<f64>,
+    /// Budgets to use when deploying to the testnet.
+    pub testnet: Option<GasProfile>,
+    /// Budgets to use when deploying to the mainnet.
+    pub mainnet: Option<GasProfile>,
+    /// Budgets to use when deploying to a localnet.
+    pub localnet: Option<GasProfile>,
+    /// Whether the compiled-in defaults are those for a localnet; set by `select_profile`.
+    #[serde(skip)]
+    localnet_defaults: bool,
+}
+
+/// A set of gas budgets, in MIST, for a single network, or provided via the CLI.
+///
+/// Its fields mirror those of `GasConfig`, and are just as optional.
+#[derive(Debug, Default, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GasProfile {
+    pub publication: Option<u64>,
+    pub create_ramm: Option<u64>,
+    pub populate: Option<u64>,
+}
+
+impl GasProfile {
+    /// Check that none of the profile's budgets are either `0`, or larger than the network's
+    /// maximum transaction gas budget.
+    ///
+    /// Each offending budget is recorded, so that all of them are reported at once.
+    fn validate_gas_profile(&self, table: &'static str, errors: &mut Vec<ConfigValidationError>) {
+        let budgets = [
+            ("publication", self.publication),
+            ("create_ramm", self.create_ramm),
+            ("populate", self.populate),
+        ];
+        for (name, budget) in budgets {
+            match budget {
+                Some(budget) if budget == 0 || budget > MAX_GAS_BUDGET => {
+                    errors.push(ConfigValidationError::GasBudgetOutOfRange {
+                        table,
+                        name,
+                        budget,
+                        max: MAX_GAS_BUDGET,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl GasConfig {
+    /// Gas budget to be used when publishing the RAMM package.
+    pub fn publication_budget(&self) -> u64 {
+        self.publication
+            .unwrap_or(self.default_publication_budget())
+    }
+
+    /// Gas budget to be used in the transaction that creates the RAMM.
+    pub fn create_ramm_budget(&self) -> u64 {
+        self.create_ramm
+            .unwrap_or(self.default_create_ramm_budget())
+    }
+
+    /// Gas budget to be used in the PTB that populates and initializes the RAMM.
+    pub fn populate_budget(&self) -> u64 {
+        self.populate.unwrap_or(self.default_populate_budget())
+    }
+
+    /// Compiled-in default for the package publication's gas budget, lower for a localnet.
+    fn default_publication_budget(&self) -> u64 {
+        if self.localnet_defaults {
+            crate::LOCALNET_PACKAGE_PUBLICATION_GAS_BUDGET
+        } else {
+            crate::PACKAGE_PUBLICATION_GAS_BUDGET
+        }
+    }
+
+    /// Compiled-in default for the RAMM creation's gas budget, lower for a localnet.
+    fn default_create_ramm_budget(&self) -> u64 {
+        if self.localnet_defaults {
+            crate::LOCALNET_CREATE_RAMM_GAS_BUDGET
+        } else {
+            crate::CREATE_RAMM_GAS_BUDGET
+        }
+    }
+
+    /// Compiled-in default for the RAMM population's gas budget, lower for a localnet.
+    fn default_populate_budget(&self) -> u64 {
+        if self.localnet_defaults {
+            crate::LOCALNET_RAMM_PTB_GAS_BUDGET
+        } else {
+            crate::RAMM_PTB_GAS_BUDGET
+        }
+    }
+
+    /// Gas budget of a PTB that merges `coin_count` coins into the gas coin, at `gas_price`: its
+    /// computation, which grows with the number of coins, and its storage cost, scaled by the
+    /// safety margin, and capped at `MAX_GAS_BUDGET`.
+    pub fn merge_coins_budget(&self, coin_count: usize, gas_price: u64) -> u64 {
+        let gas_units = crate::MERGE_COINS_BASE_GAS_UNITS
+            + crate::MERGE_COINS_GAS_UNITS_PER_COIN * coin_count as u64;
+        let cost = gas_price
+            .saturating_mul(gas_units)
+            .saturating_add(crate::MERGE_COINS_STORAGE_COST);
+        ((cost as f64 * self.safety_margin()).ceil() as u64).min(MAX_GAS_BUDGET)
+    }
+
+    /// Safety margin to apply to the gas budgets estimated from dry runs, clamped to
+    /// `MIN_GAS_SAFETY_MARGIN..=MAX_GAS_SAFETY_MARGIN`.
+    pub fn safety_margin(&self) -> f64 {
+        self.safety_margin
+            .unwrap_or(DEFAULT_GAS_SAFETY_MARGIN)
+            .clamp(MIN_GAS_SAFETY_MARGIN, MAX_GAS_SAFETY_MARGIN)
+    }
+
+    /// Layer the gas profile matching `network` - the name of the Suibase workdir the deployment
+    /// targets, with `active` already resolved - over the `[gas]` table's budgets.
+    ///
+    /// Budgets absent from the profile, or every budget if there is no matching profile, are left
+    /// as they were, and thus fall back to the `[gas]` table, and then to the compiled-in defaults.
+    /// All profiles are discarded afterwards, as they are of no further use.
+    ///
+    /// For a localnet, the compiled-in defaults are also replaced with lower ones.
+    pub(crate) fn select_profile(&mut self, network: &str) {
+        let (testnet, mainnet, localnet) = (
+            self.testnet.take(),
+            self.mainnet.take(),
+            self.localnet.take(),
+        );
+        let profile = match network {
+            "testnet" => testnet,
+            "mainnet" => mainnet,
+            LOCALNET => localnet,
+            _ => None,
+        };
+        self.localnet_defaults = network == LOCALNET;
+
+        match profile {
+            Some(profile) => {
+                log::info!("Using the `[gas.{network}]` gas profile.");
+                self.publication = profile.publication.or(self.publication);
+                self.create_ramm = profile.create_ramm.or(self.create_ramm);
+                self.populate = profile.populate.or(self.populate);
+            }
+            None => log::info!(
+                "No gas profile for network `{network}`; using the `[gas]` table's budgets, or \
+                the compiled-in defaults."
+            ),
+        }
+    }
+
+    /// Override this config's budgets with those the user provided via the CLI, if any.
+    ///
+    /// Precedence of each budget's source is: CLI > TOML > compiled-in default.
+    /// The source from which each of the resulting budgets was obtained is logged.
+    pub(crate) fn apply_cli_overrides(&mut self, overrides: GasProfile) {
+        self.publication = resolve_gas_budget(
+            "publication",
+            overrides.publication,
+            self.publication,
+            self.default_publication_budget(),
+        );
+        self.create_ramm = resolve_gas_budget(
+            "RAMM creation",
+            overrides.create_ramm,
+            self.create_ramm,
+            self.default_create_ramm_budget(),
+        );
+        self.populate = resolve_gas_budget(
+            "RAMM population",
+            overrides.populate,
+            self.populate,
+            self.default_populate_budget(),
+        );
+    }
+
+    /// Check the budgets resolved from the CLI, the TOML config and the defaults against
+    /// `GAS_BUDGET_SANITY_CEILING`, logging a warning for each that exceeds it.
+    ///
+    /// If `strict` is set, the first such budget is returned as an error instead, once all of them
+    /// are logged.
+    pub(crate) fn check_budget_ceiling(&self, strict: bool) -> Result<(), RAMMDeploymentError> {
+        let budgets = [
+            ("publication", self.publication_budget()),
+            ("RAMM creation", self.create_ramm_budget()),
+            ("RAMM population", self.populate_budget()),
+        ];
+
+        let mut first_error = None;
+        for (tx_name, budget) in budgets {
+            if budget <= GAS_BUDGET_SANITY_CEILING {
+                continue;
+            }
+            let error = RAMMDeploymentError::GasBudgetAboveCeiling {
+                tx_name,
+                budget: crate::mist_to_sui(budget),
+                ceiling: crate::mist_to_sui(GAS_BUDGET_SANITY_CEILING),
+            };
+            if strict {
+                log::error!("{error}");
+                first_error.get_or_insert(error);
+            } else {
+                log::warn!("{error} It will be used nonetheless.");
+            }
+        }
+
+        match first_error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Check that none of the budgets specified in the TOML config, including those in its
+    /// per-network profiles, are either `0`, or larger than the network's maximum transaction gas
+    /// budget.
+    pub(crate) fn validate_gas_cfg(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.price == Some(0) {
+            errors.push(ConfigValidationError::ZeroGasPrice);
+        }
+        match self.safety_margin {
+            Some(margin) if margin.is_nan() => {
+                errors.push(ConfigValidationError::NaNGasSafetyMargin);
+            }
+            Some(margin) if !(MIN_GAS_SAFETY_MARGIN..=MAX_GAS_SAFETY_MARGIN).contains(&margin) => {
+                log::warn!(
+                    "The gas safety margin, `safety_margin`, is {margin}; it will be clamped to \
+                    {}.",
+                    self.safety_margin()
+                );
+            }
+            _ => {}
+        }
+        let top_level = GasProfile {
+            publication: self.publication,
+            create_ramm: self.create_ramm,
+            populate: self.populate,
+        };
+        let profiles = [
+            ("gas", Some(&top_level)),
+            ("gas.testnet", self.testnet.as_ref()),
+            ("gas.mainnet", self.mainnet.as_ref()),
+            ("gas.localnet", self.localnet.as_ref()),
+        ];
+        for (table, profile) in profiles {
+            if let Some(profile) = profile {
+                profile.validate_gas_profile(table, errors);
+            }
+        }
+    }
+}
+
+/// Policy with which the submission of a signed transaction is retried, should it fail with a
+/// transient error, e.g. a network timeout.
+///
+/// The delay before the `n`-th retry is `base_delay_ms * multiplier^(n - 1)` milliseconds.
+/// All fields are optional in the TOML config's `[retry]` table, falling back to `Default`.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryPolicy {
+    /// Maximum number of submission attempts, including the first one; must be at least `1`.
+    pub max_attempts: u32,
+    /// Delay before the first retry, in milliseconds.
+    pub base_delay_ms: u64,
+    /// Factor by which the delay grows after each retry; must be a finite number, at least `1.0`.
+    /// Delays are capped at one minute.
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay_ms: 1_000,
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Check that the policy allows at least one attempt, and that delays never shrink.
+    pub(crate) fn validate_retry_policy(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.max_attempts < 1 {
+            errors.push(ConfigValidationError::ZeroRetryAttempts);
+        }
+        if !self.multiplier.is_finite() || self.multiplier < 1.0 {
+            errors.push(ConfigValidationError::RetryMultiplierBelowOne);
+        }
+    }
+}
+
+/// Settings for the tool's communication with the Sui network, read from the TOML config's
+/// `[network]` table.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// Seconds after which an RPC call - or a transaction's execution - that has not completed is
+    /// abandoned; must be at least `1`.
+    pub timeout_secs: u64,
+    /// See `ExecutionWait`.
+    pub wait_for: ExecutionWait,
+    /// Maximum number of RPC calls made concurrently, e.g. when resolving a RAMM's aggregators;
+    /// must be at least `1`.
+    pub max_concurrency: usize,
+    /// Milliseconds between two queries for a submitted transaction's effects, while waiting for
+    /// them to be available from the node; must be at least `1`.
+    pub poll_interval_ms: u64,
+    /// Seconds after which a submitted transaction whose effects are still not available from the
+    /// node is given up on; must be at least `1`.
+    pub max_wait_secs: u64,
+    /// If present, the URL of the full node to connect to, bypassing Suibase; never part of the
+    /// config itself, but set with the CLI's `--rpc-url`.
+    #[serde(skip)]
+    pub rpc_url: Option<String>,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        NetworkConfig {
+            timeout_secs: 60,
+            wait_for: ExecutionWait::default(),
+            max_concurrency: 8,
+            poll_interval_ms: 500,
+            max_wait_secs: 30,
+            rpc_url: None,
+        }
+    }
+}
+
+/// What the full node must wait for before responding to a transaction's submission.
+///
+/// * `local-execution` (the default): the node waits until it has executed the transaction
+///   itself - but on a busy node, this can take long enough for the request to time out.
+/// * `effects-cert`: the node responds as soon as the transaction's effects are certified by the
+///   validators, which is faster.
+///
+/// Under either, the tool then polls the node for the transaction until its effects are
+/// available, before reading any objects it created: even a locally executed transaction's
+/// effects are not always queryable right away.
+#[derive(Clone, Copy, Debug, Default, Deserialize, JsonSchema, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExecutionWait {
+    #[default]
+    LocalExecution,
+    EffectsCert,
+}
+
+impl ExecutionWait {
+    /// Parse a wait mode from its name, as given to the CLI's `--wait-for` flag.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "local-execution" => Some(ExecutionWait::LocalExecution),
+            "effects-cert" => Some(ExecutionWait::EffectsCert),
+            _ => None,
+        }
+    }
+
+    /// The request type with which transactions are submitted under this wait mode.
+    pub fn request_type(&self) -> ExecuteTransactionRequestType {
+        match self {
+            ExecutionWait::LocalExecution => ExecuteTransactionRequestType::WaitForLocalExecution,
+            ExecutionWait::EffectsCert => ExecuteTransactionRequestType::WaitForEffectsCert,
+        }
+    }
+}
+
+impl NetworkConfig {
+    /// The timeout as a `Duration`, for use with `tokio::time::timeout`.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+
+    /// The interval between two queries for a transaction's effects, as a `Duration`.
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    /// The longest wait for a transaction's effects, as a `Duration`.
+    pub fn max_wait(&self) -> Duration {
+        Duration::from_secs(self.max_wait_secs)
+    }
+
+    pub(crate) fn validate_network_cfg(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.timeout_secs == 0 {
+            errors.push(ConfigValidationError::ZeroNetworkTimeout);
+        }
+        if self.max_concurrency == 0 {
+            errors.push(ConfigValidationError::ZeroMaxConcurrency);
+        }
+        if self.poll_interval_ms == 0 {
+            errors.push(ConfigValidationError::ZeroPollInterval);
+        }
+        if self.max_wait_secs == 0 {
+            errors.push(ConfigValidationError::ZeroMaxWait);
+        }
+    }
+}
+
+/// Settings for the checks made on the assets' price feeds before a deployment, read from the TOML
+/// config's `[oracle]` table.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OracleConfig {
+    /// Age, in seconds, past which a feed's latest result is considered stale; must be at least
+    /// `1`.
+    pub max_staleness_secs: u64,
+}
+
+impl Default for OracleConfig {
+    fn default() -> Self {
+        OracleConfig {
+            max_staleness_secs: 600,
+        }
+    }
+}
+
+impl OracleConfig {
+    pub(crate) fn validate_oracle_cfg(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.max_staleness_secs == 0 {
+            errors.push(ConfigValidationError::ZeroOracleStaleness);
+        }
+    }
+}
+
+/// Pick a gas budget from either the CLI, the TOML config, or the compiled-in default - in that
+/// order of precedence - and log which of the sources was used.
+///
+/// `None` is returned if neither the CLI nor the TOML specified the budget, so that the default
+/// continues to be applied lazily by `GasConfig`'s getters.
+fn resolve_gas_budget(
+    tx_name: &str,
+    from_cli: Option<u64>,
+    from_toml: Option<u64>,
+    default: u64,
+) -> Option<u64> {
+    match (from_cli, from_toml) {
+        (Some(budget), _) => {
+            log::info!("Gas budget for {tx_name} tx: {budget} MIST, from the CLI.");
+            Some(budget)
+        }
+        (None, Some(budget)) => {
+            log::info!("Gas budget for {tx_name} tx: {budget} MIST, from the TOML config.");
+            Some(budget)
+        }
+        (None, None) => {
+            log::info!(
+                "Gas budget for {tx_name} tx: {default} MIST, from the compiled-in default."
+            );
+            None
+        }
+    }
+}
+
+/// A sponsor paying for the deployment's gas, read from the TOML config's optional `[sponsor]`
+/// table, so that the signer's key authorizes the deployment without having to hold any SUI.
+///
+/// Every tx of the deployment is then paid for with `gas_coin`, and signed by both the signer and
+/// the sponsor, whose key must be in the same keystore; see `sponsor_tx`.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SponsorConfig {
+    /// The sponsor's address, which owns `gas_coin`.
+    #[schemars(with = "String")]
+    pub address: SuiAddress,
+    /// The SUI coin, owned by the sponsor, with which each tx's gas is paid.
+    #[schemars(with = "String")]
+    pub gas_coin: ObjectID,
+}
+
+/// An asset's initial liquidity, read from an entry of the TOML config's optional
+/// `[initial_liquidity]` table, which is keyed on the asset's type, e.g. `"0x2::sui::SUI"`.
+///
+/// Each RAMM holding the asset has `amount` of it deposited by its population PTB, right after it is
+/// initialized, so that it can be traded with as soon as it is deployed.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InitialLiquidity {
+    /// The amount to deposit into each RAMM, in the asset's smallest unit, as
+    /// `minimum_trade_amount` is.
+    pub amount: u64,
+    /// If present, a coin of the asset, owned by the signer, from which `amount` is split for each
+    /// RAMM; otherwise, `amount` is minted from the `[faucet]` for each RAMM.
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    pub coin: Option<ObjectID>,
+}
+
+/// Data of the `ramm_misc` test coin faucet, read from the TOML config's optional `[faucet]`
+/// table.
+///
+/// It is only needed by the post-deployment smoke tests, which mint the coins they use from it.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FaucetData {
+    /// ID of the package containing the faucet.
+    #[schemars(with = "String")]
+    pub package_id: ObjectID,
+    /// Name of the module with the faucet's `mint_test_coins` function, e.g. `test_coin_faucet`.
+    pub module_name: String,
+    /// ID of the shared `Faucet` object.
+    #[schemars(with = "String")]
+    pub faucet_id: ObjectID,
+}
+
+/// Provider of the price feed an asset is priced with.
+///
+/// Written in the config as a lowercase string; if absent, `Switchboard` is assumed.
+#[derive(Clone, Copy, Debug, Default, Deserialize, JsonSchema, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OracleProvider {
+    /// The asset's `aggregator_address` is that of a Switchboard `Aggregator`.
+    #[default]
+    Switchboard,
+}
+
+impl Display for OracleProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OracleProvider::Switchboard => write!(f, "switchboard"),
+        }
+    }
+}
+
 /// Asset data required to add said asset to the RAMM, using its Sui Move API and the
 /// Sui Rust SDK via programmable transaction blocks (PTBs).
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct AssetConfig {
-    #[serde(deserialize_with = "de_from_str")]
+    /// The asset's coin type, which must be a struct type; see `parse_coin_type`.
+    #[serde(deserialize_with = "de_coin_type", serialize_with = "ser_to_str")]
+    #[schemars(with = "String")]
     pub asset_type: TypeTag,
+    /// Address of the price feed object, whose kind depends on `oracle_provider`.
+    #[schemars(with = "String")]
     pub aggregator_address: SuiAddress,
     pub minimum_trade_amount: u64,
-    pub decimal_places: u8,
+    /// If absent, it is read from the asset's on-chain `CoinMetadata` before the deployment; see
+    /// `coin_metadata::resolve_decimal_places`.
+    #[serde(default)]
+    #[schemars(range(min = "ASSET_MIN_DECIMAL_PLACES", max = "ASSET_MAX_DECIMAL_PLACES"))]
+    pub decimal_places: Option<u8>,
+    #[serde(default)]
+    pub oracle_provider: OracleProvider,
+    /// The asset's coin name and symbol, e.g. `("USD Coin", "USDC")`, shown next to its type so
+    /// the user can confirm it is the intended coin. Read from its `CoinMetadata`, if it has one;
+    /// never part of the config itself.
+    #[serde(skip)]
+    pub coin_name_and_symbol: Option<(String, String)>,
+    /// The latest result of the asset's price feed, shown next to its aggregator so the user can
+    /// spot a dead feed. Read before the deployment; see `oracle::read_oracle_prices`.
+    #[serde(skip)]
+    pub oracle_reading: Option<OracleReading>,
 }
 
 /// Deserialize a `TypeTag` from `&str/String`, instead of the usual way in which
 /// `struct`s like it would be - field by field.
-fn de_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+fn de_coin_type<'de, D>(deserializer: D) -> Result<TypeTag, D::Error>
 where
     D: Deserializer<'de>,
-    T: FromStr,
-    <T as FromStr>::Err: Display,
 {
     let s = String::deserialize(deserializer)?;
-    T::from_str(&s).map_err(de::Error::custom)
+    parse_coin_type(&s).map_err(|reason| de::Error::custom(format!("invalid asset type: {reason}")))
+}
+
+/// Parse a coin type, e.g. `0x2::sui::SUI`, rejecting any type that is not a struct, such as `u64`,
+/// which can not be a coin's.
+///
+/// Addresses are held as their 32 bytes, so that a type parses to the same `TypeTag` whether its
+/// address is written in short or long form, with or without leading zeros; the tag is displayed in
+/// the canonical short form.
+pub(crate) fn parse_coin_type(coin_type: &str) -> Result<TypeTag, String> {
+    match TypeTag::from_str(coin_type.trim()) {
+        Ok(type_tag @ TypeTag::Struct(_)) => Ok(type_tag),
+        Ok(type_tag) => Err(format!(
+            "`{type_tag}` is not a struct type, so it can not be a coin's"
+        )),
+        Err(e) => Err(format!("`{coin_type}` is not a Move type: {e}")),
+    }
+}
+
+/// Serialize a value like a `TypeTag` into its string form, so that `de_coin_type` can read it
+/// back.
+fn ser_to_str<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Display,
+{
+    serializer.collect_str(value)
+}
+
+/// The smallest minimum trade amount of an asset with the given decimal places that is not
+/// considered implausibly small; see `MIN_TRADE_WARNING_DECIMAL_PLACES`.
+pub(crate) fn plausible_minimum_trade_amount(decimal_places: u8) -> u64 {
+    10u64.pow(
+        decimal_places
+            .saturating_sub(MIN_TRADE_WARNING_DECIMAL_PLACES)
+            .into(),
+    )
 }
 
 impl AssetConfig {
+    /// The asset's decimal places, either as set in the config, or as read from the chain.
+    ///
+    /// `DecimalPlacesUnresolved` is returned if neither has happened yet.
+    pub fn resolved_decimal_places(&self) -> Result<u8, RAMMDeploymentError> {
+        self.decimal_places.ok_or_else(|| {
+            RAMMDeploymentError::DecimalPlacesUnresolved(self.asset_type.to_string())
+        })
+    }
+
+    /// The asset's coin symbol, as read from its `CoinMetadata`, if it has been.
+    pub fn symbol(&self) -> Option<&str> {
+        self.coin_name_and_symbol
+            .as_ref()
+            .map(|(_, symbol)| symbol.as_str())
+    }
+
+    /// The asset as named in logs: its symbol, followed by its type, e.g.
+    /// `USDC (0x...::usdc::USDC)`, or its bare type if its symbol is unknown.
+    pub fn label(&self) -> String {
+        match self.symbol() {
+            Some(symbol) => format!("{symbol} ({})", self.asset_type),
+            None => self.asset_type.to_string(),
+        }
+    }
+
     /// Display an asset's data in human readable format, with a variable number of
     /// tabs as leftmost indentation.
     pub(self) fn asset_cfg_fmt(
@@ -47,6 +739,9 @@ impl AssetConfig {
             aggregator_address,
             minimum_trade_amount,
             decimal_places,
+            oracle_provider,
+            coin_name_and_symbol,
+            oracle_reading,
         } = &self;
 
         let first_pad: String = '\t'.to_string().repeat(tab_count - 1);
@@ -56,27 +751,74 @@ impl AssetConfig {
         // This left pads each of the lines in `AssetConfig` to a variable number of `\t`
         // (tabs).
         write!(f, "{}{}: {}\n", padding, "asset type".cyan(), asset_type)?;
+        // Without on-chain metadata, the asset type alone identifies the coin.
+        if let Some((name, symbol)) = coin_name_and_symbol {
+            write!(
+                f,
+                "{}{}: {} ({})\n",
+                padding,
+                "coin".cyan(),
+                symbol.bold(),
+                name
+            )?;
+        }
         write!(
             f,
             "{}{}: {}\n",
             padding,
-            "aggregator address".cyan(),
-            aggregator_address
+            "oracle provider".cyan(),
+            oracle_provider
         )?;
         write!(
             f,
             "{}{}: {}\n",
             padding,
-            "minimum trade amount".cyan(),
-            minimum_trade_amount
+            "aggregator address".cyan(),
+            aggregator_address
         )?;
+        // Stale or unreadable feeds are highlighted, as a RAMM should not be deployed with them.
+        match oracle_reading {
+            Some(OracleReading::Price(price)) => {
+                let reading = format!("{} (updated {}s ago)", price, price.age_secs);
+                let reading = if price.stale {
+                    format!("{} - STALE", reading).red()
+                } else {
+                    reading.normal()
+                };
+                write!(f, "{}{}: {}\n", padding, "latest price".cyan(), reading)?;
+            }
+            Some(OracleReading::Failed(error)) => write!(
+                f,
+                "{}{}: {}\n",
+                padding,
+                "latest price".cyan(),
+                format!("unavailable - {}", error).red()
+            )?,
+            None => {}
+        }
         write!(
             f,
             "{}{}: {}\n",
             padding,
-            "decimal places".cyan(),
-            decimal_places
-        )
+            "minimum trade amount".cyan(),
+            minimum_trade_amount
+        )?;
+        match decimal_places {
+            Some(decimal_places) => write!(
+                f,
+                "{}{}: {}\n",
+                padding,
+                "decimal places".cyan(),
+                decimal_places
+            ),
+            None => write!(
+                f,
+                "{}{}: {}\n",
+                padding,
+                "decimal places".cyan(),
+                "from on-chain CoinMetadata"
+            ),
+        }
     }
 }
 
@@ -104,52 +846,527 @@ where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
-    match ObjectID::from_str(&s) {
-        Ok(obj) => Ok(RAMMPkgAddrSrc::FromTomlConfig(obj)),
-        Err(_) => PathBuf::from_str(&s)
-            .map_err(de::Error::custom)
-            .map(RAMMPkgAddrSrc::FromPkgPublication),
+    Ok(RAMMPkgAddrSrc::from_addr_or_path(&s))
+}
+
+impl RAMMPkgAddrSrc {
+    /// Read a package's source from a string that is either its ID or, failing that, the path of
+    /// its library.
+    pub fn from_addr_or_path(s: &str) -> Self {
+        match ObjectID::from_str(s) {
+            Ok(obj) => RAMMPkgAddrSrc::FromTomlConfig(obj),
+            Err(_) => RAMMPkgAddrSrc::FromPkgPublication(PathBuf::from(s)),
+        }
+    }
+}
+
+impl Serialize for RAMMPkgAddrSrc {
+    /// Serialize the package's source as a single string, either its ID or its path, mirroring
+    /// `de_addr_or_path`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RAMMPkgAddrSrc::FromTomlConfig(package_id) => serializer.collect_str(package_id),
+            RAMMPkgAddrSrc::FromPkgPublication(path) => match path.to_str() {
+                Some(path) => serializer.serialize_str(path),
+                None => Err(ser::Error::custom(format!(
+                    "the package path {} is not valid UTF-8",
+                    path.display()
+                ))),
+            },
+        }
+    }
+}
+
+/// Specification of a single RAMM pool to be created, populated and initialized.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RAMMSpec {
+    /// Optional; if absent, it is derived from `assets.len()`. If present, it must match it.
+    #[schemars(range(min = 2, max = 3))]
+    pub asset_count: Option<u8>,
+    #[schemars(with = "String")]
+    pub fee_collection_address: SuiAddress,
+    pub assets: Vec<AssetConfig>,
+}
+
+impl RAMMSpec {
+    /// The RAMM's number of assets: the one given in the config, or, if absent, the length of its
+    /// list of assets.
+    ///
+    /// A list too long for its length to be a `u8` counts as `u8::MAX` assets, rather than
+    /// wrapping around; `validate_ramm_spec` reports it.
+    pub fn asset_count(&self) -> u8 {
+        self.asset_count
+            .unwrap_or_else(|| u8::try_from(self.assets.len()).unwrap_or(u8::MAX))
+    }
+
+    /// Validate a single RAMM's specification, recording each of its problems in `errors`.
+    ///
+    /// Every check is run, even after one fails, so that all of the specification's problems are
+    /// reported at once.
+    pub(crate) fn validate_ramm_spec(&self, errors: &mut Vec<ConfigValidationError>) {
+        self.asset_count_matches_assets(errors);
+        self.asset_count_fits(errors);
+        self.asset_count_is_supported(errors);
+        self.fee_collection_address_is_nonzero(errors);
+        self.has_assets(errors);
+        self.decimal_places_are_in_range(errors);
+        self.minimum_trade_amounts_are_sane(errors);
+        self.aggregators_are_distinct(errors);
+        self.asset_types_are_distinct(errors);
+    }
+
+    /// Check that the RAMM has at least one asset.
+    fn has_assets(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.assets.is_empty() {
+            errors.push(ConfigValidationError::NoAssets);
+        }
+    }
+
+    /// If the config does not state an asset count, check that the number of assets given fits in
+    /// a `u8`, as the count it is read as must.
+    fn asset_count_fits(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.asset_count.is_none() && u8::try_from(self.assets.len()).is_err() {
+            errors.push(ConfigValidationError::TooManyAssets(self.assets.len()));
+        }
+    }
+
+    /// Check that the RAMM has one of the `SUPPORTED_ASSET_COUNTS`.
+    ///
+    /// A RAMM without assets is reported by `has_assets` instead, and one with too many assets
+    /// for their count to be a `u8` by `asset_count_fits`.
+    fn asset_count_is_supported(&self, errors: &mut Vec<ConfigValidationError>) {
+        let count = self.asset_count();
+        let counted = self.asset_count.is_some() || u8::try_from(self.assets.len()).is_ok();
+        if !self.assets.is_empty() && counted && !SUPPORTED_ASSET_COUNTS.contains(&count) {
+            errors.push(ConfigValidationError::UnsupportedAssetCount {
+                count,
+                supported: SUPPORTED_ASSET_COUNTS.to_vec(),
+            });
+        }
+    }
+
+    /// If the config states an asset count, check that it matches the number of assets given.
+    fn asset_count_matches_assets(&self, errors: &mut Vec<ConfigValidationError>) {
+        match self.asset_count {
+            Some(count) if usize::from(count) != self.assets.len() => {
+                errors.push(ConfigValidationError::AssetCountMismatch {
+                    count,
+                    assets: self.assets.len(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Check that the fee collection address is not the zero address, to which fees would be
+    /// irrecoverably sent.
+    ///
+    /// Being a `SuiAddress`, it is already known to be well-formed i.e. 32 bytes long; as it is
+    /// set when the RAMM is created, catching this here avoids a redeployment.
+    fn fee_collection_address_is_nonzero(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.fee_collection_address == SuiAddress::ZERO {
+            errors.push(ConfigValidationError::ZeroFeeCollectionAddress);
+        }
+    }
+
+    /// Check that every asset's decimal place count, if known, is between
+    /// `ASSET_MIN_DECIMAL_PLACES` and `ASSET_MAX_DECIMAL_PLACES`, inclusive.
+    fn decimal_places_are_in_range(&self, errors: &mut Vec<ConfigValidationError>) {
+        for asset in &self.assets {
+            let Some(decimal_places) = asset.decimal_places else {
+                continue;
+            };
+            if !(ASSET_MIN_DECIMAL_PLACES..=ASSET_MAX_DECIMAL_PLACES).contains(&decimal_places) {
+                errors.push(ConfigValidationError::DecimalPlacesOutOfRange {
+                    asset_type: asset.asset_type.to_string(),
+                    decimal_places,
+                    min: ASSET_MIN_DECIMAL_PLACES,
+                    max: ASSET_MAX_DECIMAL_PLACES,
+                });
+            }
+        }
+    }
+
+    /// Check that no asset has a minimum trade amount of `0`, which would disable the RAMM's
+    /// minimum trade protection for it.
+    ///
+    /// Amounts that are merely implausibly small, given the asset's decimal places, are accepted,
+    /// but a warning is logged for each.
+    fn minimum_trade_amounts_are_sane(&self, errors: &mut Vec<ConfigValidationError>) {
+        for asset in &self.assets {
+            if asset.minimum_trade_amount == 0 {
+                errors.push(ConfigValidationError::ZeroMinimumTradeAmount(
+                    asset.asset_type.to_string(),
+                ));
+                continue;
+            }
+
+            // Until they are read from the chain, the decimal places are unknown.
+            let Some(decimal_places) = asset.decimal_places else {
+                continue;
+            };
+            if asset.minimum_trade_amount < plausible_minimum_trade_amount(decimal_places) {
+                log::warn!(
+                    "Asset {} has a minimum trade amount of {}, which is less than 10^-{} of a \
+                    unit with {} decimal places. Is this intended?",
+                    asset.asset_type,
+                    asset.minimum_trade_amount,
+                    MIN_TRADE_WARNING_DECIMAL_PLACES,
+                    decimal_places
+                );
+            }
+        }
+    }
+
+    /// Check that no two assets share an aggregator address.
+    ///
+    /// Two different assets being priced by the same feed is almost certainly a copy-paste error
+    /// in the config, which would leave the RAMM mispriced, so it is treated as invalid.
+    fn aggregators_are_distinct(&self, errors: &mut Vec<ConfigValidationError>) {
+        let mut seen: HashSet<SuiAddress> = HashSet::new();
+        for asset in &self.assets {
+            if !seen.insert(asset.aggregator_address) {
+                errors.push(ConfigValidationError::DuplicatePriceFeed(
+                    asset.aggregator_address,
+                ));
+            }
+        }
+    }
+
+    /// Check that no asset type appears more than once in the RAMM.
+    ///
+    /// Types are compared as parsed `TypeTag`s, and thus independently of how their addresses were
+    /// written in the config, e.g. with or without leading zeros.
+    /// Adding the same asset twice would make `add_asset_to_ramm` abort mid-PTB.
+    fn asset_types_are_distinct(&self, errors: &mut Vec<ConfigValidationError>) {
+        let mut seen: HashSet<&TypeTag> = HashSet::new();
+        for asset in &self.assets {
+            if !seen.insert(&asset.asset_type) {
+                errors.push(ConfigValidationError::DuplicateAssetType(
+                    asset.asset_type.to_string(),
+                ));
+            }
+        }
+    }
+
+    /// Display a RAMM's specification in human readable format, with a variable number of
+    /// tabs as leftmost indentation.
+    pub(self) fn ramm_spec_fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        tab_count: usize,
+    ) -> std::fmt::Result {
+        let padding: String = '\t'.to_string().repeat(tab_count);
+
+        write!(
+            f,
+            "{}{}: {}\n",
+            padding,
+            "Fee collection address".green(),
+            self.fee_collection_address
+        )?;
+        write!(f, "{}{}:\n", padding, "List of assets".green())?;
+        write!(
+            f,
+            "{}{}: {}\n",
+            padding,
+            "Asset count".green(),
+            self.asset_count()
+        )?;
+        for asset in &self.assets {
+            asset.asset_cfg_fmt(f, tab_count + 2)?;
+        }
+        Ok(())
     }
 }
 
 /// Information that specifies how a given RAMM is to deployed to the network of choice.
 ///
-/// At the moment, it is hardcoded to deploy on the testnet, but this can be changed in the future,
-/// should the tool be used for mainnet deployment.
+/// The network is chosen by `target_env`, which must be one of `TARGET_ENVS`: `localnet`,
+/// `devnet`, `testnet`, `mainnet`, or `active`, i.e. the Suibase workdir currently in use.
 ///
 /// It contains:
 /// * the data of the faucet whose tokens the RAMM will use
-/// * the number of assets
-/// * the RAMM's initial fee collection address
-/// * a vector with each of the asset's data
-#[derive(Debug, Deserialize)]
+/// * the specification of each RAMM to be deployed i.e. its number of assets, initial fee
+///   collection address and a vector with each of the asset's data
+/// * optionally, the gas budgets to be used in each of the deployment's transactions
+/// * optionally, the policy with which to retry transaction submissions
+/// * optionally, the timeout applied to network calls
+///
+/// Two schemas are accepted to specify the RAMMs to be deployed:
+/// 1. the single-RAMM schema, with top-level `asset_count`, `fee_collection_address` and
+///    `[[assets]]` fields, and
+/// 2. the multi-RAMM schema, with a `[[ramms]]` table per RAMM, each with those same fields.
+///
+/// Both can be used in the same config; after parsing, `normalize_ramm_specs` moves a RAMM
+/// specified with the first schema into `ramms`, so the rest of the tool only needs to handle
+/// the second.
+///
+/// Unknown fields are rejected, in this and every nested table, so that a misspelt field is a
+/// parse error instead of being silently dropped.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct RAMMDeploymentConfig {
     /// The Sui network environment to be targeted. Acceptable values:
+    /// * localnet
+    /// * devnet
     /// * testnet
     /// * mainnet
-    /// * active (which is really just suibase shorthand for either of the two above)
+    /// * active (which is really just suibase shorthand for any of the above)
+    ///
+    /// The corresponding Suibase workdir must be set up; see `check_suibase_workdir`.
+    #[schemars(schema_with = "crate::config_schema::target_env_schema")]
     pub target_env: String,
-    /// See `RAMMPkgAddrSrc`.
+    /// See `RAMMPkgAddrSrc`. The package is published at most once, and shared by all RAMMs.
     #[serde(deserialize_with = "de_addr_or_path")]
+    #[schemars(with = "String")]
     pub ramm_pkg_addr_or_path: RAMMPkgAddrSrc,
-    /// Informal invariant: this field must always match `assets.len()`
-    pub asset_count: u8,
-    pub fee_collection_address: SuiAddress,
+    /// Single-RAMM schema: see `RAMMSpec`. Empty after `normalize_ramm_specs`.
+    #[serde(default)]
+    #[schemars(range(min = 2, max = 3))]
+    pub asset_count: Option<u8>,
+    /// Single-RAMM schema: see `RAMMSpec`. Empty after `normalize_ramm_specs`.
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    pub fee_collection_address: Option<SuiAddress>,
+    /// Single-RAMM schema: see `RAMMSpec`. Empty after `normalize_ramm_specs`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub assets: Vec<AssetConfig>,
+    /// The RAMMs to be deployed, in order.
+    #[serde(default)]
+    pub ramms: Vec<RAMMSpec>,
+    /// If present, the address to which each RAMM's admin capability is transferred once the
+    /// signer no longer needs it, i.e. once the RAMM is populated; otherwise, the signer keeps it.
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    pub admin_cap_recipient: Option<SuiAddress>,
+    /// If present, the address to which each RAMM's new asset capability is transferred, should
+    /// the RAMM be created without being populated; otherwise, the signer keeps it.
+    ///
+    /// Populating a RAMM destroys its new asset capability, so there is then nothing to transfer.
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    pub new_asset_cap_recipient: Option<SuiAddress>,
+    /// See `GasConfig`. If the `[gas]` table is absent, default budgets are used.
+    #[serde(default)]
+    pub gas: GasConfig,
+    /// See `RetryPolicy`. If the `[retry]` table is absent, the default policy is used.
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// See `NetworkConfig`. If the `[network]` table is absent, the default timeout is used.
+    #[serde(default)]
+    pub network: NetworkConfig,
+    /// See `OracleConfig`. If the `[oracle]` table is absent, the default staleness threshold is
+    /// used.
+    #[serde(default)]
+    pub oracle: OracleConfig,
+    /// See `FaucetData`. Only required for post-deployment smoke tests.
+    #[serde(default)]
+    pub faucet: Option<FaucetData>,
+    /// See `SponsorConfig`. If the `[sponsor]` table is absent, the signer pays for its own gas.
+    #[serde(default)]
+    pub sponsor: Option<SponsorConfig>,
+    /// Overrides of the RAMM library's named addresses, e.g. `ramm_sui`, applied when it is built
+    /// for publication or upgrade, so that one source tree can target several address layouts.
+    ///
+    /// Each name must be declared in the `[addresses]` of the library's `Move.toml`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(with = "BTreeMap<String, String>")]
+    pub named_addresses: BTreeMap<String, SuiAddress>,
+    /// See `InitialLiquidity`, keyed on asset types. If the `[initial_liquidity]` table is absent,
+    /// the RAMMs are deployed without liquidity.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub initial_liquidity: BTreeMap<String, InitialLiquidity>,
 }
 
 impl RAMMDeploymentConfig {
-    /// Validate a deployment configuration parsed from a well-formed TOML file.
+    /// The address that pays for the deployment's gas: the sponsor, if the config has one, or else
+    /// the signer.
+    pub fn gas_owner(&self, signer: SuiAddress) -> SuiAddress {
+        self.sponsor
+            .as_ref()
+            .map_or(signer, |sponsor| sponsor.address)
+    }
+
+    /// The coin with which each tx's gas is paid: the sponsor's, if the config has one, or else
+    /// `selected`, i.e. the one selected via `--gas-coin`, if any.
+    pub fn gas_coin(&self, selected: Option<ObjectID>) -> Option<ObjectID> {
+        self.sponsor
+            .as_ref()
+            .map(|sponsor| sponsor.gas_coin)
+            .or(selected)
+    }
+
+    /// The initial liquidity of the given asset, if the config's `[initial_liquidity]` has an entry
+    /// for its type.
+    pub fn initial_liquidity_of(&self, asset: &AssetConfig) -> Option<&InitialLiquidity> {
+        self.initial_liquidity
+            .iter()
+            .find(|(asset_type, _)| {
+                parse_coin_type(asset_type).is_ok_and(|asset_type| asset_type == asset.asset_type)
+            })
+            .map(|(_, liquidity)| liquidity)
+    }
+
+    /// Sum, in MIST, of the gas budgets of every transaction in a whole deployment of this config:
+    /// the package's publication or upgrade, if there is one, and each RAMM's creation and
+    /// population.
+    ///
+    /// Budgets are taken from the resolved gas config, so any CLI or per-network overrides are
+    /// reflected.
+    pub fn total_gas_budget(&self) -> u64 {
+        let publication = match self.ramm_pkg_addr_or_path {
+            RAMMPkgAddrSrc::FromPkgPublication(_) => self.gas.publication_budget(),
+            RAMMPkgAddrSrc::FromTomlConfig(_) => 0,
+        };
+        let per_ramm = self.gas.create_ramm_budget() + self.gas.populate_budget();
+
+        publication + per_ramm * self.ramms.len() as u64
+    }
+
+    /// Serialize the config into canonical TOML: every RAMM is written in the `[[ramms]]` schema,
+    /// and fields only known at runtime, such as oracle readings, are omitted.
+    pub fn to_toml(&self) -> Result<String, RAMMDeploymentError> {
+        toml::to_string(self).map_err(RAMMDeploymentError::TOMLSerializationError)
+    }
+
+    /// If the config specifies a RAMM using the top-level, single-RAMM schema, move it to the
+    /// front of `ramms`.
     ///
-    /// Returns `true` iff the config is valid per the informal specification below.
+    /// Returns `false` if the single-RAMM schema's fields are only partially present.
+    pub(crate) fn normalize_ramm_specs(&mut self) -> bool {
+        match (
+            self.asset_count.take(),
+            self.fee_collection_address.take(),
+            std::mem::take(&mut self.assets),
+        ) {
+            // The single-RAMM schema is not in use.
+            (None, None, assets) if assets.is_empty() => true,
+            (asset_count, Some(fee_collection_address), assets) if !assets.is_empty() => {
+                self.ramms.insert(
+                    0,
+                    RAMMSpec {
+                        asset_count,
+                        fee_collection_address,
+                        assets,
+                    },
+                );
+                true
+            }
+            _ => {
+                log::error!(
+                    "The top-level `fee_collection_address` and `[[assets]]` fields must either \
+                    both be present, or both be absent; `asset_count` may only accompany them."
+                );
+                false
+            }
+        }
+    }
+
+    /// Validate a deployment configuration parsed from a well-formed TOML file, after its
+    /// RAMM specifications have been normalized.
+    ///
+    /// Every check is run, even after one fails, so that all of the config's problems are
+    /// returned at once; problems with a RAMM's specification are wrapped in
+    /// `ConfigValidationError::InRAMM`, to point to the RAMM they were found in.
+    pub fn validate_detailed(&self) -> Result<(), Vec<ConfigValidationError>> {
+        let mut errors = Vec::new();
+        if self.ramms.is_empty() {
+            errors.push(ConfigValidationError::NoRAMMs);
+        }
+        if !TARGET_ENVS.contains(&self.target_env.as_str()) {
+            errors.push(ConfigValidationError::InvalidTargetEnv(
+                self.target_env.clone(),
+            ));
+        }
+        for (ix, ramm_spec) in self.ramms.iter().enumerate() {
+            let mut ramm_errors = Vec::new();
+            ramm_spec.validate_ramm_spec(&mut ramm_errors);
+            errors.extend(
+                ramm_errors
+                    .into_iter()
+                    .map(|error| ConfigValidationError::InRAMM {
+                        ramm: ix + 1,
+                        error: Box::new(error),
+                    }),
+            );
+        }
+        let recipients = [
+            ("admin_cap_recipient", self.admin_cap_recipient),
+            ("new_asset_cap_recipient", self.new_asset_cap_recipient),
+        ];
+        for (field, recipient) in recipients {
+            if recipient == Some(SuiAddress::ZERO) {
+                errors.push(ConfigValidationError::ZeroCapRecipient(field));
+            }
+        }
+        self.gas.validate_gas_cfg(&mut errors);
+        self.retry.validate_retry_policy(&mut errors);
+        self.network.validate_network_cfg(&mut errors);
+        self.oracle.validate_oracle_cfg(&mut errors);
+        self.validate_initial_liquidity(&mut errors);
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Check that each entry of the `[initial_liquidity]` table names the type of an asset of one of
+    /// the RAMMs, and deposits a non-zero amount of it, and that the config has a `[faucet]` if any
+    /// entry has no `coin`, and is thus minted from it.
+    fn validate_initial_liquidity(&self, errors: &mut Vec<ConfigValidationError>) {
+        for (asset_type, liquidity) in &self.initial_liquidity {
+            match parse_coin_type(asset_type) {
+                Err(reason) => errors.push(ConfigValidationError::InvalidAssetType {
+                    asset_type: asset_type.clone(),
+                    reason,
+                }),
+                Ok(type_tag)
+                    if !self
+                        .ramms
+                        .iter()
+                        .flat_map(|ramm_spec| &ramm_spec.assets)
+                        .any(|asset| asset.asset_type == type_tag) =>
+                {
+                    errors.push(ConfigValidationError::UnknownInitialLiquidityAsset(
+                        asset_type.clone(),
+                    ))
+                }
+                Ok(_) => {}
+            }
+            if liquidity.amount == 0 {
+                errors.push(ConfigValidationError::ZeroInitialLiquidity(
+                    asset_type.clone(),
+                ));
+            }
+        }
+        let minted = self
+            .initial_liquidity
+            .values()
+            .any(|liquidity| liquidity.coin.is_none());
+        if minted && self.faucet.is_none() {
+            errors.push(ConfigValidationError::InitialLiquidityWithoutFaucet);
+        }
+    }
+
+    /// Validate a deployment configuration, as `validate_detailed` does, logging each problem.
+    ///
+    /// Returns `true` iff the config is valid.
     pub(crate) fn validate_ramm_cfg(&self) -> bool {
-        self.asset_count == (self.assets.len() as u8)
-            && self.asset_count > 0
-            && ["active", "testnet", "mainnet"].contains(&self.target_env.as_str())
-            && self
-                .assets
-                .iter()
-                .all(|asset| asset.decimal_places >= ASSET_MIN_DECIMAL_PLACES)
+        match self.validate_detailed() {
+            Ok(()) => true,
+            Err(errors) => {
+                for error in errors {
+                    log::error!("{error}");
+                }
+                false
+            }
+        }
     }
 }
 
@@ -184,16 +1401,96 @@ impl Display for RAMMDeploymentConfig {
                 )?;
             }
         }
+        write!(f, "\t{}: {}\n", "RAMM count".green(), self.ramms.len())?;
+        for (ix, ramm_spec) in self.ramms.iter().enumerate() {
+            write!(f, "\t{} #{}:\n", "RAMM".purple(), ix + 1)?;
+            ramm_spec.ramm_spec_fmt(f, 2)?;
+        }
+        if let Some(recipient) = self.admin_cap_recipient {
+            write!(
+                f,
+                "\t{}: {}\n",
+                "Admin caps transferred to".green(),
+                recipient
+            )?;
+        }
+        if let Some(recipient) = self.new_asset_cap_recipient {
+            write!(
+                f,
+                "\t{}: {}\n",
+                "New asset caps of unpopulated RAMMs transferred to".green(),
+                recipient
+            )?;
+        }
+        if let Some(sponsor) = &self.sponsor {
+            write!(
+                f,
+                "\t{}: {}, with coin {}\n",
+                "Gas sponsored by".green(),
+                sponsor.address,
+                sponsor.gas_coin
+            )?;
+        }
+        if !self.named_addresses.is_empty() {
+            write!(f, "\t{}:\n", "Named address overrides".green())?;
+            for (name, address) in &self.named_addresses {
+                write!(f, "\t\t{name} = {address}\n")?;
+            }
+        }
+        if !self.initial_liquidity.is_empty() {
+            write!(f, "\t{}:\n", "Initial liquidity".green())?;
+            for (asset_type, liquidity) in &self.initial_liquidity {
+                match liquidity.coin {
+                    Some(coin) => write!(
+                        f,
+                        "\t\t{asset_type}: {}, split from coin {coin}\n",
+                        liquidity.amount
+                    )?,
+                    None => write!(
+                        f,
+                        "\t\t{asset_type}: {}, minted from the faucet\n",
+                        liquidity.amount
+                    )?,
+                }
+            }
+        }
+        write!(f, "\t{}:\n", "Gas budgets (MIST)".green())?;
         write!(
             f,
-            "\t{}: {}\n",
-            "Fee collection address".green(),
-            self.fee_collection_address
+            "\t\t{}: {}\n",
+            "publication".cyan(),
+            self.gas.publication_budget()
         )?;
-        write!(f, "\t{}:\n", "List of assets".green())?;
-        write!(f, "\t{}: {}\n", "Asset count".green(), self.asset_count)?;
-        for asset in &self.assets {
-            asset.asset_cfg_fmt(f, 3)?;
+        write!(
+            f,
+            "\t\t{}: {}\n",
+            "RAMM creation".cyan(),
+            self.gas.create_ramm_budget()
+        )?;
+        write!(
+            f,
+            "\t\t{}: {}\n",
+            "RAMM population".cyan(),
+            self.gas.populate_budget()
+        )?;
+        match self.gas.price {
+            Some(price) => write!(f, "\t{}: {}\n", "Gas price (MIST)".green(), price)?,
+            None => write!(
+                f,
+                "\t{}: {}\n",
+                "Gas price (MIST)".green(),
+                "reference price"
+            )?,
+        }
+        if let Some(faucet) = &self.faucet {
+            write!(
+                f,
+                "\t{}: {}::{}, object {}\n",
+                "Faucet".green(),
+                faucet.package_id,
+                faucet.module_name,
+                faucet.faucet_id
+            )?;
         }
         write!(
             f,
@@ -202,3 +1499,512 @@ impl Display for RAMMDeploymentConfig {
         )
     }
 }
+
+/// Format in which a deployment config is written.
+///
+/// Both formats deserialize into the same `RAMMDeploymentConfig`, with the same field names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Parse a format from its name, as given to the CLI's `--format` flag.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "toml" => Some(ConfigFormat::Toml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// Infer a config's format from its file's extension: `.json` files are JSON, and everything
+    /// else - including configs read from `STDIN` - is assumed to be TOML.
+    pub(crate) fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Toml,
+        }
+    }
+}
+
+/// Format in which the report of a successful deployment is printed; see `report_format`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The report's `Display` implementation, color-coded for a terminal.
+    #[default]
+    Human,
+    /// Pretty-printed JSON, for other tools to consume; see `report_format::JsonReport`.
+    Json,
+    /// A plain-text table of the deployment's objects and txs.
+    Table,
+    /// A Markdown table of the deployment's objects and txs, e.g. to paste into a PR.
+    Markdown,
+}
+
+impl OutputFormat {
+    /// Parse a format from its name, as given to the CLI's `--output-format` flag.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "human" => Some(OutputFormat::Human),
+            "json" => Some(OutputFormat::Json),
+            "table" => Some(OutputFormat::Table),
+            "markdown" => Some(OutputFormat::Markdown),
+            _ => None,
+        }
+    }
+}
+
+/// How the RAMM library is compiled before being published or upgraded.
+#[derive(Debug, Default)]
+pub struct PkgBuildOptions {
+    /// If `true`, the library is always compiled anew, instead of reusing a cached compilation of
+    /// the same sources.
+    pub no_cache: bool,
+    /// If present, the directory holding the output of `sui move build --dump-bytecode-as-base64`,
+    /// whose artifacts are used instead of compiling the library at all.
+    pub bytecode_dir: Option<PathBuf>,
+    /// If `true`, the library's unpublished dependencies are published as part of its package,
+    /// rather than failing the build; only meant for development and testing.
+    pub with_unpublished_deps: bool,
+    /// If `true`, as under `--strict`, the Move compiler's warnings fail the build, rather than
+    /// only being logged.
+    pub deny_warnings: bool,
+}
+
+/// The address selected with the CLI's `--address` or `--alias` to sign the deployment's txs,
+/// instead of the Suibase workdir's active address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignerSelection {
+    /// The address itself.
+    Address(SuiAddress),
+    /// The name under which Suibase knows the address, e.g. `sb-1-ed25519`.
+    Alias(String),
+}
+
+/// The stage, or stages, of a deployment to run, as selected by the CLI's subcommand.
+///
+/// Running stages separately lets a deployment be carried out piecemeal, e.g. to publish the
+/// package once and create many RAMMs from it, or to recover from a failure in a later stage.
+#[derive(Clone, Debug, Default)]
+pub enum DeploymentStage {
+    /// Publish (or upgrade) the RAMM package, then create and populate each of the config's RAMMs.
+    #[default]
+    Full,
+    /// Only publish (or upgrade) the RAMM package.
+    Publish,
+    /// Create and populate each of the config's RAMMs, from an already published package - or, if
+    /// `populate` is unset, only create them.
+    Create { populate: bool },
+    /// Only populate and initialize an already created RAMM, with the assets of the config's
+    /// `ramm_index`-th RAMM, counting from 1.
+    Populate {
+        ramm_index: usize,
+        ramm_ids: RAMMObjectIDs,
+    },
+    /// Only query the RAMM with the given ID; no tx is submitted.
+    Inspect(ObjectID),
+}
+
+/// Runtime options for a RAMM's deployment.
+///
+/// Unlike `RAMMDeploymentConfig`, these are not read from the TOML config, but from the flags
+/// passed to the deployment tool via the CLI; they control *how* the deployment is carried out,
+/// rather than *what* is deployed.
+#[derive(Debug, Default)]
+pub struct DeploymentOptions {
+    /// If `true`, the deployment config is still shown to the user, but it is treated as accepted
+    /// without prompting for confirmation.
+    ///
+    /// Useful for CI pipelines and scripts, where `STDIN` is unavailable.
+    pub assume_yes: bool,
+    /// If `true`, all transactions are built (and dry run, which only requires read access to the
+    /// network), but none is ever signed or submitted for execution.
+    ///
+    /// Can be combined with `assume_yes` to simulate a deployment unattended.
+    pub dry_run: bool,
+    /// If present, path of the file to which a JSON `DeploymentManifest` will be written after a
+    /// successful deployment.
+    pub manifest_path: Option<PathBuf>,
+    /// If present, path of the file to which a `TsSdkConfig` will be written after a successful
+    /// deployment.
+    pub ts_config_path: Option<PathBuf>,
+    /// If present, the ID of the SUI coin owned by the signer that will pay for the gas of every
+    /// transaction. Otherwise, a gas coin is selected automatically.
+    pub gas_coin: Option<ObjectID>,
+    /// If present, path of a `DeploymentCheckpoint` from which to resume a deployment.
+    pub resume_path: Option<PathBuf>,
+    /// If present, path of the file to which a `DeploymentCheckpoint` is written after each of the
+    /// deployment's stages.
+    pub checkpoint_path: Option<PathBuf>,
+    /// If present, path of the `.env` file to which to write the IDs of the deployed objects.
+    pub env_out_path: Option<PathBuf>,
+    /// Whether to append to the `.env` file at `env_out_path`, rather than overwrite it.
+    pub env_append: bool,
+    /// If present, the directory to which the deployment's next transactions are written, unsigned,
+    /// instead of being signed and executed.
+    pub unsigned_out_dir: Option<PathBuf>,
+    /// If present, the paths of an unsigned transaction file and of a file with a signature over
+    /// it: the signed transaction is submitted, and nothing else is done.
+    pub submit_signed: Option<(PathBuf, PathBuf)>,
+    /// If present, the ID of the `UpgradeCap` with which to upgrade the package it governs, using
+    /// the library at the config's path, instead of publishing that library as a new package.
+    pub upgrade_cap: Option<ObjectID>,
+    /// See `PkgBuildOptions`.
+    pub pkg_build: PkgBuildOptions,
+    /// If present, path of the file-based keystore with which to sign transactions. Otherwise,
+    /// the keystore of the Suibase workdir being targeted is used.
+    pub keystore_path: Option<PathBuf>,
+    /// Whether to sign transactions with the private key in the `RAMM_DEPLOY_KEY` env variable,
+    /// held in an in-memory keystore, rather than with a keystore file.
+    pub keystore_from_env: bool,
+    /// If present, the address to sign transactions with; otherwise, that of the key in
+    /// `RAMM_DEPLOY_KEY` with `keystore_from_env`, or the Suibase workdir's active address.
+    pub signer: Option<SignerSelection>,
+    /// If present, base URL of the block explorer that txs and objects are linked to; otherwise,
+    /// Suiscan's for the target network is used. See `Explorer`.
+    pub explorer_url: Option<String>,
+    /// Whether the preflight checks' warnings are errors, i.e. whether
+    /// * an asset whose configured decimal places differ from its on-chain `CoinMetadata`'s,
+    /// * a gas budget above `GAS_BUDGET_SANITY_CEILING`, and
+    /// * a stale or unreadable price feed, as with `require_fresh_oracles`,
+    /// abort the deployment. The Move compiler's warnings are escalated by `PkgBuildOptions`'s
+    /// `deny_warnings`, which `--strict` also sets.
+    pub strict: bool,
+    /// Whether a stale or unreadable price feed aborts the deployment, rather than only being
+    /// highlighted; see `oracle::check_oracles_fresh`.
+    pub require_fresh_oracles: bool,
+    /// Whether to deploy the config even if `lockfile_path` records a successful deployment of it.
+    pub force: bool,
+    /// Path of the `DeploymentLock` recording successful deployments, which is checked before a
+    /// deployment, and updated after it.
+    pub lockfile_path: PathBuf,
+    /// Whether to submit a smoke trade against each RAMM once it is deployed.
+    pub smoke_test: bool,
+    /// If present, the amount, in whole units, of each asset with which to seed each RAMM's
+    /// liquidity once it is deployed.
+    pub seed_liquidity: Option<u64>,
+    /// Format in which the report of a successful deployment is printed.
+    pub output_format: OutputFormat,
+    /// The stage, or stages, of the deployment to run.
+    pub stage: DeploymentStage,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKAGE_ID: &str = "0x0a31987c7298a1cf416f0ab7793fa9b519143e2032f472f407b295108390420a";
+    const FEE_ADDRESS: &str = "0x1fad963ac9311c5f99685bc430dc022a5b0d36f6860603495ca0a0e3a46dd120";
+    const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+    const BTC_AGGREGATOR: &str =
+        "0x7c30e48db7dfd6a2301795be6cb99d00c87782e2547cf0c63869de244cfc7e47";
+    const ETH_AGGREGATOR: &str =
+        "0x68ed81c5dd07d12c629e5cdad291ca004a5cd3708d5659cb0b6bfe983e14778c";
+    const SOL_AGGREGATOR: &str =
+        "0x35c7c241fa2d9c12cd2e3bcfa7d77192a58fd94e9d6f482465d5e3c8d91b4b43";
+    const BTC: &str =
+        "0x76a5ecf30b2cf49a342a9bd74a479702a1b321b0d45f06920618dbe7c2da52b1::test_coins::BTC";
+    const ETH: &str =
+        "0x76a5ecf30b2cf49a342a9bd74a479702a1b321b0d45f06920618dbe7c2da52b1::test_coins::ETH";
+    const SOL: &str =
+        "0x76a5ecf30b2cf49a342a9bd74a479702a1b321b0d45f06920618dbe7c2da52b1::test_coins::SOL";
+
+    /// An `[[assets]]` table, with the given decimal places unless they are `None`.
+    fn asset(
+        asset_type: &str,
+        aggregator: &str,
+        minimum_trade_amount: u64,
+        decimal_places: Option<u8>,
+    ) -> String {
+        let mut asset = format!(
+            "[[assets]]\n\
+            asset_type = \"{asset_type}\"\n\
+            aggregator_address = \"{aggregator}\"\n\
+            minimum_trade_amount = {minimum_trade_amount}\n"
+        );
+        if let Some(decimal_places) = decimal_places {
+            asset.push_str(&format!("decimal_places = {decimal_places}\n"));
+        }
+        asset
+    }
+
+    /// A single-RAMM config on the testnet, with the given top-level fields and assets, parsed and
+    /// normalized as `parse_ramm_cfg` does.
+    fn config(top_level: &str, assets: &[String]) -> RAMMDeploymentConfig {
+        let toml = format!(
+            "target_env = \"testnet\"\n\
+            ramm_pkg_addr_or_path = \"{PACKAGE_ID}\"\n\
+            {top_level}\n\
+            {}",
+            assets.join("\n")
+        );
+        let mut cfg: RAMMDeploymentConfig = toml::from_str(&toml).unwrap();
+        assert!(cfg.normalize_ramm_specs());
+        cfg
+    }
+
+    /// A single-RAMM config holding the given assets, with a valid fee collection address.
+    fn config_with_assets(assets: &[String]) -> RAMMDeploymentConfig {
+        config(
+            &format!("fee_collection_address = \"{FEE_ADDRESS}\""),
+            assets,
+        )
+    }
+
+    /// The problems `validate_detailed` finds with the config, unwrapped from the RAMM they were
+    /// found in.
+    fn validation_errors(cfg: &RAMMDeploymentConfig) -> Vec<ConfigValidationError> {
+        match cfg.validate_detailed() {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors
+                .into_iter()
+                .map(|error| match error {
+                    ConfigValidationError::InRAMM { error, .. } => *error,
+                    error => error,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn distinct_aggregators_are_accepted() {
+        let cfg = config_with_assets(&[
+            asset(BTC, BTC_AGGREGATOR, 10_000, Some(8)),
+            asset(ETH, ETH_AGGREGATOR, 100_000, Some(8)),
+        ]);
+        assert!(validation_errors(&cfg).is_empty());
+    }
+
+    #[test]
+    fn shared_aggregator_is_rejected() {
+        let cfg = config_with_assets(&[
+            asset(BTC, BTC_AGGREGATOR, 10_000, Some(8)),
+            asset(ETH, BTC_AGGREGATOR, 100_000, Some(8)),
+        ]);
+        let errors = validation_errors(&cfg);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ConfigValidationError::DuplicatePriceFeed(address)
+                if *address == SuiAddress::from_str(BTC_AGGREGATOR).unwrap()
+        ));
+    }
+
+    /// The problems found with a two-asset config whose first asset has the given decimal places.
+    fn decimal_places_errors(decimal_places: Option<u8>) -> Vec<ConfigValidationError> {
+        let cfg = config_with_assets(&[
+            asset(BTC, BTC_AGGREGATOR, 1_000_000, decimal_places),
+            asset(ETH, ETH_AGGREGATOR, 100_000, Some(8)),
+        ]);
+        validation_errors(&cfg)
+    }
+
+    #[test]
+    fn zero_decimal_places_are_rejected() {
+        let errors = decimal_places_errors(Some(0));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ConfigValidationError::DecimalPlacesOutOfRange {
+                decimal_places: 0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn min_decimal_places_are_accepted() {
+        assert!(decimal_places_errors(Some(ASSET_MIN_DECIMAL_PLACES)).is_empty());
+    }
+
+    #[test]
+    fn decimal_places_below_min_are_rejected() {
+        let errors = decimal_places_errors(Some(ASSET_MIN_DECIMAL_PLACES - 1));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ConfigValidationError::DecimalPlacesOutOfRange {
+                decimal_places,
+                min: ASSET_MIN_DECIMAL_PLACES,
+                ..
+            } if decimal_places == ASSET_MIN_DECIMAL_PLACES - 1
+        ));
+    }
+
+    #[test]
+    fn max_decimal_places_are_accepted() {
+        assert_eq!(ASSET_MAX_DECIMAL_PLACES, 12);
+        assert!(decimal_places_errors(Some(12)).is_empty());
+    }
+
+    #[test]
+    fn decimal_places_above_max_are_rejected() {
+        let errors = decimal_places_errors(Some(13));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ConfigValidationError::DecimalPlacesOutOfRange {
+                decimal_places: 13,
+                max: 12,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn absent_decimal_places_are_accepted() {
+        assert!(decimal_places_errors(None).is_empty());
+    }
+
+    #[test]
+    fn zero_minimum_trade_amount_is_rejected() {
+        let cfg = config_with_assets(&[
+            asset(BTC, BTC_AGGREGATOR, 0, Some(8)),
+            asset(ETH, ETH_AGGREGATOR, 100_000, Some(8)),
+        ]);
+        let errors = validation_errors(&cfg);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ConfigValidationError::ZeroMinimumTradeAmount(asset_type)
+                if TypeTag::from_str(asset_type).unwrap() == TypeTag::from_str(BTC).unwrap()
+        ));
+    }
+
+    /// The BTC, ETH and SOL assets, in that order.
+    fn btc_eth_sol() -> [String; 3] {
+        [
+            asset(BTC, BTC_AGGREGATOR, 10_000, Some(8)),
+            asset(ETH, ETH_AGGREGATOR, 100_000, Some(8)),
+            asset(SOL, SOL_AGGREGATOR, 1_000_000, Some(9)),
+        ]
+    }
+
+    #[test]
+    fn supported_asset_counts_are_accepted() {
+        assert_eq!(SUPPORTED_ASSET_COUNTS, [2, 3]);
+        let assets = btc_eth_sol();
+        assert!(validation_errors(&config_with_assets(&assets[..2])).is_empty());
+        assert!(validation_errors(&config_with_assets(&assets)).is_empty());
+    }
+
+    #[test]
+    fn single_asset_is_rejected() {
+        let errors = validation_errors(&config_with_assets(&btc_eth_sol()[..1]));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ConfigValidationError::UnsupportedAssetCount { count: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn four_assets_are_rejected() {
+        // No fourth test coin has a price feed of its own: ETH's is reused.
+        let mut assets = btc_eth_sol().to_vec();
+        assets.push(asset("0x2::sui::SUI", ETH_AGGREGATOR, 1_000_000, Some(9)));
+        let errors = validation_errors(&config_with_assets(&assets));
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            ConfigValidationError::UnsupportedAssetCount { count: 4, .. }
+        )));
+    }
+
+    #[test]
+    fn asset_count_does_not_wrap() {
+        let assets = vec![asset(BTC, BTC_AGGREGATOR, 10_000, Some(8)); 258];
+        let cfg = config_with_assets(&assets);
+        assert_eq!(cfg.ramms[0].asset_count(), u8::MAX);
+
+        let errors = validation_errors(&cfg);
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, ConfigValidationError::TooManyAssets(258))));
+        assert!(!errors
+            .iter()
+            .any(|error| matches!(error, ConfigValidationError::UnsupportedAssetCount { .. })));
+    }
+
+    #[test]
+    fn identical_asset_types_are_rejected() {
+        let cfg = config_with_assets(&[
+            asset("0x2::sui::SUI", BTC_AGGREGATOR, 10_000, Some(9)),
+            asset(
+                "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI",
+                ETH_AGGREGATOR,
+                10_000,
+                Some(9),
+            ),
+        ]);
+        let errors = validation_errors(&cfg);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ConfigValidationError::DuplicateAssetType(asset_type)
+                if TypeTag::from_str(asset_type).unwrap()
+                    == TypeTag::from_str("0x2::sui::SUI").unwrap()
+        ));
+    }
+
+    /// The problems found with a valid two-asset config, once given the extra top-level fields.
+    fn top_level_errors(top_level: &str) -> Vec<ConfigValidationError> {
+        let cfg = config(
+            top_level,
+            &[
+                asset(BTC, BTC_AGGREGATOR, 10_000, Some(8)),
+                asset(ETH, ETH_AGGREGATOR, 100_000, Some(8)),
+            ],
+        );
+        validation_errors(&cfg)
+    }
+
+    #[test]
+    fn zero_fee_collection_address_is_rejected() {
+        let errors = top_level_errors(&format!("fee_collection_address = \"{ZERO_ADDRESS}\""));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ConfigValidationError::ZeroFeeCollectionAddress
+        ));
+    }
+
+    #[test]
+    fn zero_admin_cap_recipient_is_rejected() {
+        let errors = top_level_errors(&format!(
+            "fee_collection_address = \"{FEE_ADDRESS}\"\n\
+            admin_cap_recipient = \"{ZERO_ADDRESS}\""
+        ));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ConfigValidationError::ZeroCapRecipient("admin_cap_recipient")
+        ));
+    }
+
+    #[test]
+    fn zero_new_asset_cap_recipient_is_rejected() {
+        let errors = top_level_errors(&format!(
+            "fee_collection_address = \"{FEE_ADDRESS}\"\n\
+            new_asset_cap_recipient = \"{ZERO_ADDRESS}\""
+        ));
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ConfigValidationError::ZeroCapRecipient("new_asset_cap_recipient")
+        ));
+    }
+
+    #[test]
+    fn example_config_round_trips_through_toml() {
+        let mut cfg: RAMMDeploymentConfig =
+            toml::from_str(include_str!("../../deploy_cfg.toml")).unwrap();
+        assert!(cfg.normalize_ramm_specs());
+        let toml = cfg.to_toml().unwrap();
+
+        let mut reparsed: RAMMDeploymentConfig = toml::from_str(&toml).unwrap();
+        assert!(reparsed.normalize_ramm_specs());
+        assert_eq!(reparsed.to_toml().unwrap(), toml);
+    }
+}
@@ -1,14 +1,28 @@
-use std::fs;
+use std::{error::Error, fs, future::Future};
 
-use log::SetLoggerError;
 use simplelog::{
     ColorChoice, CombinedLogger, ConfigBuilder, LevelFilter, SharedLogger, TermLogger, TerminalMode,
     WriteLogger,
 };
 
+/// Backend through which the messages of the `log` API are emitted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogBackend {
+    /// `simplelog`'s flat lines, to the terminal and optionally to a file.
+    #[default]
+    Simplelog,
+    /// `tracing-subscriber`'s lines, to `STDERR`: the deployment's phases are spans, each of which
+    /// is logged along with its duration when it closes. See `in_phase`.
+    #[cfg(feature = "tracing")]
+    Tracing,
+    /// As `Tracing`, but with each line a JSON object, e.g. to ship the logs to an aggregator.
+    #[cfg(feature = "tracing")]
+    TracingJson,
+}
+
 /// Function to initialize logging infrastructure.
 /// Logging relies on the façade provided by `simplelog`, with the `log` library
-/// providing the logging API.
+/// providing the logging API - unless another `LogBackend` is selected.
 ///
 /// The default logging configuration is used, which is then modified to allow
 /// source-code information on every log message, not just errors.
@@ -16,11 +30,22 @@ use simplelog::{
 /// # Arguments
 ///
 /// * `opt_log_file_name` - Name of the file to which logs will be written to. If `None`, terminal-only logging is used.
-/// * `log_level` - Set at which level and above the log messages will be displayed.
+///   Only `simplelog` writes log files.
+/// * `log_level` - Set at which level and above the log messages will be displayed, and written to the log file.
+/// * `backend` - See `LogBackend`.
 pub fn init_logging_infrastructure(
-    opt_log_file_name : Option<&str>,
-    log_level: LevelFilter
-    ) -> Result<(), SetLoggerError> {
+    opt_log_file_name: Option<&str>,
+    log_level: LevelFilter,
+    backend: LogBackend,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    match backend {
+        LogBackend::Simplelog => {}
+        #[cfg(feature = "tracing")]
+        LogBackend::Tracing => return init_tracing(log_level, false),
+        #[cfg(feature = "tracing")]
+        LogBackend::TracingJson => return init_tracing(log_level, true),
+    }
+
     let config = ConfigBuilder::new()
         // This enables source-code location in logging message of any level
         .set_location_level(LevelFilter::Error)
@@ -50,16 +75,57 @@ pub fn init_logging_infrastructure(
                     eprintln!("Terminal-only logging will be attempted.");
                 }
                 Ok(file) => {
-                    let file_logger = WriteLogger::new(
-                        LevelFilter::Info,
-                        config,
-                        file
-                    );
+                    let file_logger = WriteLogger::new(log_level, config, file);
                     logger_vec.push(file_logger);
                 }
             }
         }
     };
 
-    CombinedLogger::init(logger_vec)
-}
\ No newline at end of file
+    Ok(CombinedLogger::init(logger_vec)?)
+}
+
+/// Initialize a `tracing-subscriber` that also receives the messages of the `log` API, and logs
+/// each span's duration as it closes.
+#[cfg(feature = "tracing")]
+fn init_tracing(log_level: LevelFilter, json: bool) -> Result<(), Box<dyn Error + Send + Sync>> {
+    use tracing_subscriber::{filter::LevelFilter as TracingLevel, fmt::format::FmtSpan};
+
+    let max_level = match log_level {
+        LevelFilter::Off => TracingLevel::OFF,
+        LevelFilter::Error => TracingLevel::ERROR,
+        LevelFilter::Warn => TracingLevel::WARN,
+        LevelFilter::Info => TracingLevel::INFO,
+        LevelFilter::Debug => TracingLevel::DEBUG,
+        LevelFilter::Trace => TracingLevel::TRACE,
+    };
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(max_level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr);
+
+    match json {
+        true => subscriber.json().try_init(),
+        false => subscriber.try_init(),
+    }
+}
+
+/// Run one of the deployment's phases - `publish`, `create` or `populate` - within a `tracing`
+/// span named after it and, if given, the position of the RAMM it concerns, starting at 1.
+///
+/// The span's duration is logged when it closes, if a `tracing` `LogBackend` is in use; without
+/// the `tracing` feature, the phase is simply run.
+pub async fn in_phase<F: Future>(phase: &'static str, ramm: Option<usize>, fut: F) -> F::Output {
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+        return fut
+            .instrument(tracing::info_span!("deployment_phase", phase, ramm))
+            .await;
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (phase, ramm);
+        fut.await
+    }
+}